@@ -16,13 +16,25 @@
     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
-use std::{cell::RefCell, fmt::Debug, io, rc::Rc, str};
+use std::{
+    cell::RefCell,
+    cmp::max,
+    fmt::Debug,
+    io,
+    rc::Rc,
+    str,
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
 
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
+    MouseEventKind,
+};
 use ratatui::{
     buffer::Buffer,
     layout::{Margin, Rect},
-    style::Stylize,
+    style::{Color, Modifier, Style, Stylize},
     symbols::border,
     text::{Line, Span, Text},
     widgets::{Block, Clear, Paragraph, Widget, Wrap},
@@ -31,11 +43,19 @@ use ratatui::{
 
 use rslibrecell::{
     card::{Card, Suit},
-    config_repository::KeyConfig,
-    game::GameId,
-    game_handler::GameHandler,
+    config_repository::{ActionKeys, KeyBinding, KeyConfig, PersistKeyConfig, Theme},
+    game::{Game, GameId, MAX_GAME_ID},
+    game_handler::{
+        game_repository::{PersistGame, SavedGame},
+        Branch, Checkpoint, GameHandler, Hint, MAX_CHECKPOINTS,
+    },
     journey_handler::{journey_repository::PersistJourney, JourneyHandler},
-    r#move::{Location, Move},
+    r#move::{foundation_index_for, legal_moves, Location, Move, MoveError},
+    repository_error::RepositoryError,
+    stats_handler::{
+        stats_repository::{GameRecord, PersistStats},
+        StatsHandler, Summary,
+    },
 };
 
 /// The state of the app.
@@ -52,47 +72,191 @@ enum AppState {
     AboutModal { scroll: u16 },
     /// The app is displaying the modal "game selection by Id".
     /// `id` serves as a byte buffer for an UTF8 string.
-    SelectionIdModal { id: [u8; 5] },
+    SelectionIdModal { id: [u8; ID_LENGTH] },
     /// The app is displaying the modal "game selection from journey".
     SelectionJourneyModal,
+    /// The app is displaying the modal statistics.
+    StatsModal,
+    /// The app is displaying the modal settings, editing a copy of the key config.
+    /// `selected` is the index of the chosen entry, compare `KeyConfig::entries`,
+    /// and `capturing` indicates that the next key is assigned to it.
+    SettingsModal {
+        draft: Box<KeyConfig>,
+        selected: usize,
+        capturing: bool,
+    },
+    /// The app is displaying the modal history of the game.
+    /// `name` holds the name of a checkpoint being entered, if any,
+    /// and `page` the page of branches shown.
+    HistoryModal { name: Option<String>, page: usize },
+    /// The app is offering to resume the contained saved game.
+    ResumeModal { saved_game: SavedGame },
+    /// The app is displaying problems with the persisted data.
+    /// `next` is the state to continue with once the modal is dismissed.
+    ErrorModal {
+        messages: Vec<String>,
+        next: Box<AppState>,
+    },
 }
 
 /// The actual app.
 #[derive(Debug)]
-pub struct App<T>
+pub struct App<T, U, V, W>
 where
     T: PersistJourney,
     T: Debug,
+    U: PersistGame,
+    U: Debug,
+    V: PersistStats,
+    V: Debug,
+    W: PersistKeyConfig,
+    W: Debug,
 {
     /// The current state of the app.
     app_state: AppState,
     /// The key config.
     key_config: KeyConfig,
     /// An instance of a game handler.
-    game_handler: GameHandler<T>,
+    game_handler: GameHandler<T, V>,
     /// An instance of an implementation of `HandleJourney`
     journey_handler: Rc<RefCell<JourneyHandler<T>>>,
+    /// An instance of a stats handler.
+    stats_handler: Rc<RefCell<StatsHandler<V>>>,
+    /// An instance of an implementation of `PersistGame`
+    game_repository: U,
+    /// An instance of an implementation of `PersistKeyConfig`
+    key_config_repository: W,
     /// The first part of a move as entered by the user, if any.
     move_from: Option<Location>,
+    /// The hint requested by the user for the current position, if any.
+    hint: Option<Hint>,
+    /// The hint being computed on another thread and the game it is for, if any.
+    pending_hint: Option<(JoinHandle<Hint>, Game)>,
+    /// The reason the last move or revert was rejected, if any.
+    move_error: Option<MoveError>,
+    /// The time spent playing the held game, excluding time spent in modals.
+    elapsed: Duration,
+    /// The time of the last tick, compare `tick`.
+    last_tick: Instant,
+    /// The area the app was last rendered to, for hit-testing the mouse.
+    area: Rect,
+    /// The location a drag of the mouse started a move from, if any.
+    drag_from: Option<Location>,
 }
 
 const SPACE_ASCII_CODE: u8 = 0x20;
+const ID_LENGTH: usize = 10;
+const CHECKPOINT_NAME_MAX_LENGTH: usize = 12;
+/// The interval at which the board is redrawn in the absence of events.
+const TICK_RATE: Duration = Duration::from_millis(250);
+
+/// Converts a key event to the key binding it corresponds to, if any.
+fn key_binding(key_event: &KeyEvent) -> Option<KeyBinding> {
+    match key_event.code {
+        KeyCode::Char(char) if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            Some(KeyBinding::Ctrl(char))
+        }
+        KeyCode::Char(char) => Some(KeyBinding::Char(char)),
+        KeyCode::F(number) => Some(KeyBinding::F(number)),
+        _ => None,
+    }
+}
 
-impl<T> App<T>
+impl<T, U, V, W> App<T, U, V, W>
 where
     T: PersistJourney,
     T: Debug,
+    U: PersistGame,
+    U: Debug,
+    V: PersistStats,
+    V: Debug,
+    W: PersistKeyConfig,
+    W: Debug,
 {
-    /// Creates and initializes the app.
-    pub fn new(key_config: KeyConfig, journey_repository: T) -> App<T> {
+    /// Creates and initializes the app,
+    /// offering to resume a saved game if there is one.
+    ///
+    /// Problems with the persisted data are displayed first,
+    /// falling back to the default key config, a new journey and no statistics.
+    /// Keys of the config that conflict or cannot work are reported and reset to their defaults.
+    pub fn new(
+        key_config_repository: W,
+        journey_repository: T,
+        game_repository: U,
+        stats_repository: V,
+    ) -> App<T, U, V, W> {
+        let mut messages = vec![];
+
+        let key_config = key_config_repository.read().unwrap_or_else(|error| {
+            messages.push(format!("{}. The default keys are used.", error));
+            KeyConfig::default()
+        });
+
+        let (key_config, problems) = key_config.validated();
+
+        for (problem, reset) in problems {
+            messages.push(format!(
+                "{}. The default is used for {}.",
+                problem,
+                reset.join(", ")
+            ));
+        }
+
         let journey_handler = Rc::new(RefCell::new(JourneyHandler::new(journey_repository)));
 
+        match journey_handler.borrow_mut().take_error() {
+            Some(error @ RepositoryError::Locked { .. }) => messages.push(format!(
+                "{}. The journey progress and the statistics are not saved by this instance.",
+                error
+            )),
+            Some(error) => messages.push(format!(
+                "{}. A new journey is started, which is not saved.",
+                error
+            )),
+            None => {}
+        }
+
+        let stats_handler = Rc::new(RefCell::new(StatsHandler::new(stats_repository)));
+
+        if let Some(error) = stats_handler.borrow_mut().take_error() {
+            messages.push(format!(
+                "{}. The statistics are not available and not saved.",
+                error
+            ));
+        }
+
+        let mut app_state = match game_repository.read() {
+            Ok(Some(saved_game)) => AppState::ResumeModal { saved_game },
+            Ok(None) => AppState::Base,
+            Err(error) => {
+                messages.push(format!("{}. The saved game cannot be resumed.", error));
+                AppState::Base
+            }
+        };
+
+        if !messages.is_empty() {
+            app_state = AppState::ErrorModal {
+                messages,
+                next: Box::new(app_state),
+            };
+        }
+
         App {
-            app_state: AppState::Base,
+            app_state,
             key_config,
-            game_handler: GameHandler::new(journey_handler.clone()),
+            game_handler: GameHandler::new(journey_handler.clone(), stats_handler.clone()),
             journey_handler: journey_handler.clone(),
+            stats_handler,
+            game_repository,
+            key_config_repository,
             move_from: None,
+            hint: None,
+            pending_hint: None,
+            move_error: None,
+            elapsed: Duration::ZERO,
+            last_tick: Instant::now(),
+            area: Rect::default(),
+            drag_from: None,
         }
     }
 
@@ -103,7 +267,14 @@ where
                 AppState::Exit => break,
                 _ => {
                     terminal.draw(|frame| self.draw(frame))?;
-                    self.handle_events()?;
+
+                    // the clock is advanced before any event changes the state
+                    let has_event = event::poll(TICK_RATE)?;
+                    self.tick(Instant::now());
+
+                    if has_event {
+                        self.handle_events()?;
+                    }
                 }
             }
         }
@@ -114,6 +285,22 @@ where
         frame.render_widget(self, frame.area());
     }
 
+    /// Advances the clock of the held game to the given time,
+    /// unless the game is not in progress or a modal is displayed,
+    /// and takes the pending hint once it has been computed.
+    fn tick(&mut self, now: Instant) {
+        self.receive_hint();
+
+        let running = self.app_state == AppState::Base
+            && self.game_handler.game.as_ref().is_some_and(|x| !x.is_won());
+
+        if running {
+            self.elapsed += now.saturating_duration_since(self.last_tick);
+        }
+
+        self.last_tick = now;
+    }
+
     /// Entry point for the handling of events, such as keyboard user input.
     fn handle_events(&mut self) -> io::Result<()> {
         match event::read()? {
@@ -122,6 +309,7 @@ where
             Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
                 self.handle_key_event(key_event)
             }
+            Event::Mouse(mouse_event) => self.handle_mouse_event(mouse_event),
             _ => {}
         };
         Ok(())
@@ -140,22 +328,121 @@ where
             AppState::SelectionJourneyModal => {
                 self.handle_key_event_selection_journey_modal(key_event)
             }
+            AppState::StatsModal => self.handle_key_event_stats_modal(key_event),
+            AppState::SettingsModal { .. } => self.handle_key_event_settings_modal(key_event),
+            AppState::HistoryModal { name: None, .. } => {
+                self.handle_key_event_history_modal(key_event)
+            }
+            AppState::HistoryModal { name: Some(_), .. } => {
+                self.handle_key_event_history_modal_name(key_event)
+            }
+            AppState::ResumeModal { saved_game: _ } => {
+                self.handle_key_event_resume_modal(key_event)
+            }
+            AppState::ErrorModal { .. } => self.handle_key_event_error_modal(key_event),
         };
+
+        self.report_repository_errors();
+    }
+
+    /// Handles any mouse events, which only make moves in the base state.
+    ///
+    /// A move is made either by clicking its start and end,
+    /// or by dragging from its start to its end.
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) {
+        if self.app_state != AppState::Base {
+            return;
+        }
+
+        let location = render::location_at(self.area, mouse_event.column, mouse_event.row);
+
+        match (mouse_event.kind, location) {
+            (MouseEventKind::Down(MouseButton::Left), Some(location)) => {
+                self.drag_from = match self.move_from {
+                    None => Some(location.clone()),
+                    Some(_) => None,
+                };
+                self.register_partial_move(location);
+            }
+            // releasing elsewhere completes the move started by the drag
+            (MouseEventKind::Up(MouseButton::Left), Some(location))
+                if self
+                    .drag_from
+                    .take()
+                    .is_some_and(|x| x != location && self.move_from == Some(x)) =>
+            {
+                self.register_partial_move(location);
+            }
+            (MouseEventKind::Down(_), _) => {
+                self.drag_from = None;
+                self.clear_move();
+            }
+            _ => {}
+        }
+
+        self.report_repository_errors();
+    }
+
+    /// Determines whether the key event is the one configured to quit.
+    fn is_quit(&self, key_event: &KeyEvent) -> bool {
+        key_binding(key_event).is_some_and(|x| x == self.key_config.actions.quit)
+    }
+
+    /// Displays the errors the repositories reported since the last check, if any.
+    fn report_repository_errors(&mut self) {
+        let journey_error = self.journey_handler.borrow_mut().take_error();
+        match journey_error {
+            Some(error @ RepositoryError::Locked { .. }) => self.error_modal(format!(
+                "{}. The journey progress and the statistics are not saved by this instance.",
+                error
+            )),
+            Some(error) => {
+                self.error_modal(format!("{}. The journey progress was not saved.", error))
+            }
+            None => {}
+        }
+
+        let stats_error = self.stats_handler.borrow_mut().take_error();
+        if let Some(error) = stats_error {
+            self.error_modal(format!("{}. The statistics were not saved.", error));
+        }
     }
 
     /// Handles key events when in base state.
     fn handle_key_event_base(&mut self, key_event: KeyEvent) {
-        match key_event.code {
-            KeyCode::Char('q') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-                self.exit()
-            }
-            KeyCode::F(1) => self.help_modal(),
-            KeyCode::F(2) => self.random_game(),
-            KeyCode::F(3) => self.selection_id_modal(),
-            KeyCode::Char('!') => self.selection_journey_modal(),
-            KeyCode::F(12) => self.about_modal(),
-            KeyCode::Char(char) => self.handle_key_event_game(char),
-            _ => {}
+        let Some(key) = key_binding(&key_event) else {
+            return;
+        };
+        let actions = &self.key_config.actions;
+
+        if key == actions.quit {
+            self.exit()
+        } else if key == actions.help {
+            self.help_modal()
+        } else if key == actions.random_game {
+            self.random_game()
+        } else if key == actions.select_by_id {
+            self.selection_id_modal()
+        } else if key == actions.history {
+            self.history_modal()
+        } else if key == actions.statistics {
+            self.stats_modal()
+        } else if key == actions.settings {
+            self.settings_modal()
+        } else if key == actions.journey {
+            self.selection_journey_modal()
+        } else if key == actions.about {
+            self.about_modal()
+        } else if key == actions.clear {
+            self.clear_move();
+        } else if key == actions.revert {
+            self.revert();
+        } else if key == actions.redo {
+            self.redo();
+        } else if key == actions.hint {
+            self.hint();
+        } else if let KeyBinding::Char(char) = key {
+            self.handle_key_event_game(char)
         }
     }
 
@@ -191,20 +478,17 @@ where
             self.register_partial_move(Location::Column { i: 6 });
         } else if char == self.key_config.column8 {
             self.register_partial_move(Location::Column { i: 7 });
-        } else if char == ' ' {
-            self.clear_move();
-        } else if char == 'R' {
-            self.revert();
         }
     }
 
     /// Handles key events when the help modal is active.
     fn handle_key_event_help_modal(&mut self, key_event: KeyEvent) {
+        let key = key_binding(&key_event);
+        let actions = &self.key_config.actions;
+
         match key_event.code {
-            KeyCode::Char('q') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-                self.exit()
-            }
-            KeyCode::F(12) => {
+            _ if self.is_quit(&key_event) => self.exit(),
+            _ if key.as_ref() == Some(&actions.about) => {
                 self.about_modal();
             }
             KeyCode::Esc => {
@@ -216,11 +500,12 @@ where
 
     /// Handles key events when the about modal is active.
     fn handle_key_event_about_modal(&mut self, key_event: KeyEvent) {
+        let key = key_binding(&key_event);
+        let actions = &self.key_config.actions;
+
         match key_event.code {
-            KeyCode::Char('q') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-                self.exit()
-            }
-            KeyCode::F(1) => {
+            _ if self.is_quit(&key_event) => self.exit(),
+            _ if key.as_ref() == Some(&actions.help) => {
                 self.help_modal();
             }
             KeyCode::Up => {
@@ -238,11 +523,12 @@ where
 
     /// Handles key events when the "game selection by id" modal is active.
     fn handle_key_event_selection_id_modal(&mut self, key_event: KeyEvent) {
+        let key = key_binding(&key_event);
+        let actions = &self.key_config.actions;
+
         match key_event.code {
-            KeyCode::Char('q') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-                self.exit()
-            }
-            KeyCode::F(1) => {
+            _ if self.is_quit(&key_event) => self.exit(),
+            _ if key.as_ref() == Some(&actions.help) => {
                 self.help_modal();
             }
             KeyCode::Esc => {
@@ -274,38 +560,276 @@ where
 
     /// Handles key events when the "game selection from journey" modal is active.
     fn handle_key_event_selection_journey_modal(&mut self, key_event: KeyEvent) {
+        let key = key_binding(&key_event);
+        let actions = &self.key_config.actions;
+        let skipped_index = actions
+            .journey_skipped
+            .iter()
+            .position(|x| key.as_ref() == Some(x));
+
         match key_event.code {
-            KeyCode::Char('q') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
-                self.exit()
-            }
+            _ if self.is_quit(&key_event) => self.exit(),
             KeyCode::Esc => {
                 self.base();
             }
-            KeyCode::Char('1') => {
+            _ if key.as_ref() == Some(&actions.journey_next) => {
                 self.selection_through_journey_start_next();
             }
-            KeyCode::Char(number)
-                if number == '2'
-                    || number == '3'
-                    || number == '4'
-                    || number == '5'
-                    || number == '6'
-                    || number == '7'
-                    || number == '8'
-                    || number == '9' =>
-            {
-                self.selection_through_journey_start_skipped(number);
+            _ if skipped_index.is_some() => {
+                self.selection_through_journey_start_skipped(skipped_index.unwrap());
             }
-            KeyCode::Char('s') => {
+            _ if key.as_ref() == Some(&actions.journey_skip) => {
                 self.selection_through_journey_skip_next_game();
             }
             _ => {}
         }
     }
 
+    /// Handles key events when the stats modal is active.
+    fn handle_key_event_stats_modal(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            _ if self.is_quit(&key_event) => self.exit(),
+            KeyCode::Esc => {
+                self.base();
+            }
+            _ => {}
+        }
+    }
+
+    /// Handles key events when the settings modal is active.
+    fn handle_key_event_settings_modal(&mut self, key_event: KeyEvent) {
+        let quit = self.is_quit(&key_event);
+        let key = key_binding(&key_event);
+        let suits = key.as_ref() == Some(&self.key_config.actions.settings_suits);
+        let save = key.as_ref() == Some(&self.key_config.actions.settings_save);
+        let AppState::SettingsModal {
+            draft,
+            selected,
+            capturing,
+        } = &mut self.app_state
+        else {
+            return;
+        };
+
+        if *capturing {
+            // any key is assigned, unless it cannot be used for the entry
+            if key_event.code == KeyCode::Esc {
+                *capturing = false;
+            } else if let Some(key) = key {
+                *capturing = !draft.rebind(*selected, key);
+            }
+            return;
+        }
+
+        match key_event.code {
+            _ if quit => self.exit(),
+            KeyCode::Esc => {
+                self.base();
+            }
+            KeyCode::Up => {
+                *selected = selected.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                *selected = (*selected + 1).min(draft.entries().len() - 1);
+            }
+            KeyCode::Enter => {
+                *capturing = true;
+            }
+            KeyCode::Left => {
+                draft.theme = draft.theme.previous();
+            }
+            KeyCode::Right => {
+                draft.theme = draft.theme.next();
+            }
+            _ if suits => {
+                draft.ascii = !draft.ascii;
+            }
+            KeyCode::Backspace | KeyCode::Delete => {
+                let (_, key) = KeyConfig::default().entries().swap_remove(*selected);
+                draft.rebind(*selected, key);
+            }
+            _ if save => {
+                self.settings_save();
+            }
+            _ => {}
+        }
+    }
+
+    /// Handles key events when the history modal is active.
+    fn handle_key_event_history_modal(&mut self, key_event: KeyEvent) {
+        let AppState::HistoryModal { page, .. } = self.app_state else {
+            return;
+        };
+
+        let key = key_binding(&key_event);
+        let actions = &self.key_config.actions;
+        let per_page = actions.history_branches.len();
+        let branch_index = actions
+            .history_branches
+            .iter()
+            .position(|x| key.as_ref() == Some(x));
+        let checkpoint_index = actions
+            .history_checkpoints
+            .iter()
+            .position(|x| key.as_ref() == Some(x));
+
+        match key_event.code {
+            _ if self.is_quit(&key_event) => self.exit(),
+            KeyCode::Esc => {
+                self.base();
+            }
+            KeyCode::Tab => {
+                let next = page + 1;
+                self.app_state = AppState::HistoryModal {
+                    name: None,
+                    page: if next * per_page < self.game_handler.branches().len() {
+                        next
+                    } else {
+                        0
+                    },
+                };
+            }
+            _ if key.as_ref() == Some(&actions.history_checkpoint) => {
+                self.app_state = AppState::HistoryModal {
+                    name: Some(String::new()),
+                    page,
+                };
+            }
+            _ if branch_index.is_some() => {
+                let index = page * per_page + branch_index.unwrap();
+                self.history_navigate(|game_handler| game_handler.switch_to_branch(index));
+            }
+            _ if checkpoint_index.is_some() => {
+                let index = checkpoint_index.unwrap();
+                self.history_navigate(|game_handler| game_handler.jump_to_checkpoint(index));
+            }
+            _ => {}
+        }
+    }
+
+    /// Handles key events when a checkpoint name is entered in the history modal.
+    fn handle_key_event_history_modal_name(&mut self, key_event: KeyEvent) {
+        let quit = self.is_quit(&key_event);
+        let AppState::HistoryModal {
+            name: Some(name), ..
+        } = &mut self.app_state
+        else {
+            return;
+        };
+
+        match key_event.code {
+            _ if quit => self.exit(),
+            KeyCode::Esc => {
+                self.history_modal();
+            }
+            KeyCode::Enter => {
+                if !name.is_empty() {
+                    let name = name.clone();
+                    let _ = self.game_handler.set_checkpoint(&name);
+                }
+                self.history_modal();
+            }
+            KeyCode::Backspace => {
+                name.pop();
+            }
+            KeyCode::Char(char)
+                if (char.is_ascii_alphanumeric() || char == '-' || char == '_')
+                    && name.len() < CHECKPOINT_NAME_MAX_LENGTH =>
+            {
+                name.push(char);
+            }
+            _ => {}
+        }
+    }
+
+    /// Handles key events when the resume modal is active.
+    fn handle_key_event_resume_modal(&mut self, key_event: KeyEvent) {
+        let key = key_binding(&key_event);
+        let actions = &self.key_config.actions;
+        let yes = key_event.code == KeyCode::Enter || key.as_ref() == Some(&actions.resume_yes);
+        let no = key_event.code == KeyCode::Esc || key.as_ref() == Some(&actions.resume_no);
+
+        match key_event.code {
+            _ if self.is_quit(&key_event) => self.exit(),
+            _ if yes => {
+                self.resume_game();
+            }
+            _ if no => {
+                self.base();
+                if let Err(error) = self.game_repository.clear() {
+                    self.error_modal(format!("{}. The saved game was not discarded.", error));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Handles key events when the error modal is active.
+    fn handle_key_event_error_modal(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            _ if self.is_quit(&key_event) => {
+                self.dismiss_error_modal();
+                if self.app_state != AppState::Exit {
+                    self.exit();
+                }
+            }
+            KeyCode::Enter | KeyCode::Esc => self.dismiss_error_modal(),
+            _ => {}
+        }
+    }
+
+    /// Displays the error modal, continuing with the current state once dismissed.
+    fn error_modal(&mut self, message: String) {
+        let current = std::mem::replace(&mut self.app_state, AppState::Base);
+
+        self.app_state = match current {
+            AppState::ErrorModal { mut messages, next } => {
+                messages.push(message);
+                AppState::ErrorModal { messages, next }
+            }
+            current => AppState::ErrorModal {
+                messages: vec![message],
+                next: Box::new(current),
+            },
+        };
+    }
+
+    /// Dismisses the error modal, continuing with the state stored in it.
+    fn dismiss_error_modal(&mut self) {
+        let current = std::mem::replace(&mut self.app_state, AppState::Base);
+
+        if let AppState::ErrorModal { next, .. } = current {
+            self.app_state = *next;
+        }
+    }
+
+    /// Resumes the saved game offered by the resume modal.
+    ///
+    /// The clock continues from the time saved with the game.
+    /// An inconsistent saved game is dropped, leaving no game, and the problem is displayed.
+    fn resume_game(&mut self) {
+        let result = match &self.app_state {
+            AppState::ResumeModal { saved_game } => {
+                self.game_handler.resume(saved_game, self.elapsed)
+            }
+            _ => Ok(Duration::ZERO),
+        };
+
+        self.elapsed = *result.as_ref().unwrap_or(&Duration::ZERO);
+
+        self.base();
+
+        if let Err(error) = result {
+            self.error_modal(format!("{}. The saved game was not resumed.", error));
+        }
+    }
+
     /// Starts a random game.
     fn random_game(&mut self) {
-        self.game_handler.random_game();
+        self.hint = None;
+        self.move_error = None;
+        self.game_handler.random_game(self.elapsed);
+        self.elapsed = Duration::ZERO;
     }
 
     /// Switches to base state.
@@ -313,9 +837,25 @@ where
         self.app_state = AppState::Base;
     }
 
-    /// Begins exiting the app.
+    /// Begins exiting the app, saving the game in progress, if any.
+    ///
+    /// A saved game that has not yet been resumed or discarded is kept.
+    /// If saving fails, the problem is displayed before exiting.
     fn exit(&mut self) {
+        let result = if matches!(self.app_state, AppState::ResumeModal { saved_game: _ }) {
+            Ok(())
+        } else {
+            match self.game_handler.saved_game(self.elapsed) {
+                Some(saved_game) => self.game_repository.write(&saved_game),
+                None => self.game_repository.clear(),
+            }
+        };
+
         self.app_state = AppState::Exit;
+
+        if let Err(error) = result {
+            self.error_modal(format!("{}. The game was not saved.", error));
+        }
     }
 
     /// Switches to help modal.
@@ -346,7 +886,7 @@ where
     /// Switches to "game selection by id" modal.
     fn selection_id_modal(&mut self) {
         self.app_state = AppState::SelectionIdModal {
-            id: [SPACE_ASCII_CODE; 5],
+            id: [SPACE_ASCII_CODE; ID_LENGTH],
         }
     }
 
@@ -354,8 +894,9 @@ where
     fn selection_id_modal_delete_char(&mut self) {
         match &self.app_state {
             AppState::SelectionIdModal { id } => {
-                if id[4] != SPACE_ASCII_CODE {
-                    let new_id: [u8; 5] = [SPACE_ASCII_CODE, id[0], id[1], id[2], id[3]];
+                if id[ID_LENGTH - 1] != SPACE_ASCII_CODE {
+                    let mut new_id = [SPACE_ASCII_CODE; ID_LENGTH];
+                    new_id[1..].copy_from_slice(&id[..ID_LENGTH - 1]);
                     self.app_state = AppState::SelectionIdModal { id: new_id };
                 }
             }
@@ -368,7 +909,8 @@ where
         match self.app_state {
             AppState::SelectionIdModal { id } => {
                 if id[0] == SPACE_ASCII_CODE {
-                    let new_id: [u8; 5] = [id[1], id[2], id[3], id[4], 0x30 + new_digit as u8];
+                    let mut new_id = [0x30 + new_digit as u8; ID_LENGTH];
+                    new_id[..ID_LENGTH - 1].copy_from_slice(&id[1..]);
                     self.app_state = AppState::SelectionIdModal { id: new_id };
                 }
             }
@@ -378,13 +920,13 @@ where
 
     /// Attempts to select the game from the value
     /// entered in the "game selection by id" modal.
-    fn selection_id_try_start(&mut self) -> () {
+    fn selection_id_try_start(&mut self) {
         if let AppState::SelectionIdModal { id } = &self.app_state {
-            if id[4] != SPACE_ASCII_CODE {
+            if id[ID_LENGTH - 1] != SPACE_ASCII_CODE {
                 let id = str::from_utf8(id).unwrap().trim();
-                let id = u32::from_str_radix(id, 10).unwrap();
-                if 0 < id && id < 64001 {
-                    self.game_from_numeric_id(GameId(id as u16));
+                let id = id.parse::<u64>().unwrap();
+                if 0 < id && id <= MAX_GAME_ID {
+                    self.game_from_numeric_id(GameId(id));
                     self.base();
                 }
             }
@@ -403,11 +945,7 @@ where
     }
 
     /// Starts a selected skipped game within the journey.
-    fn selection_through_journey_start_skipped(&mut self, number: char) {
-        let number = number.to_digit(10).unwrap() as usize;
-
-        let index = if number == 0 { 8 } else { number - 2 };
-
+    fn selection_through_journey_start_skipped(&mut self, index: usize) {
         let items: Vec<GameId>;
         {
             items = self.journey_handler.borrow().next_game_ids().1.clone();
@@ -428,7 +966,80 @@ where
 
     /// Start a game from the given id.
     fn game_from_numeric_id(&mut self, id: GameId) {
-        self.game_handler.game_from_id(id);
+        self.hint = None;
+        self.move_error = None;
+        self.game_handler.game_from_id(id, self.elapsed);
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// Switches to the history modal.
+    fn history_modal(&mut self) {
+        self.app_state = AppState::HistoryModal {
+            name: None,
+            page: 0,
+        };
+    }
+
+    /// Moves within the history of the game,
+    /// returning to the base state if successful.
+    fn history_navigate<F>(&mut self, navigate: F)
+    where
+        F: FnOnce(&mut GameHandler<T, V>) -> Result<(), MoveError>,
+    {
+        if navigate(&mut self.game_handler).is_ok() {
+            self.move_from = None;
+            self.hint = None;
+            self.move_error = None;
+            self.base();
+        }
+    }
+
+    /// Switches to the stats modal.
+    fn stats_modal(&mut self) {
+        self.app_state = AppState::StatsModal;
+    }
+
+    /// Switches to the settings modal, editing a copy of the key config.
+    fn settings_modal(&mut self) {
+        self.app_state = AppState::SettingsModal {
+            draft: Box::new(self.key_config.clone()),
+            selected: 0,
+            capturing: false,
+        };
+    }
+
+    /// Uses and saves the keys edited in the settings modal,
+    /// unless they have problems, which are displayed in the modal.
+    fn settings_save(&mut self) {
+        let AppState::SettingsModal { draft, .. } = &self.app_state else {
+            return;
+        };
+
+        if !draft.problems().is_empty() {
+            return;
+        }
+
+        self.key_config = draft.as_ref().clone();
+        self.base();
+
+        if let Err(error) = self.key_config_repository.write(&self.key_config) {
+            self.error_modal(format!("{}. The keys are used, but were not saved.", error));
+        }
+    }
+
+    /// Provides the records of the deal to be shown in the stats modal,
+    /// which is the held game or else the most recently recorded one.
+    fn stats_history(&self) -> Option<(GameId, Vec<GameRecord>)> {
+        let stats_handler = self.stats_handler.borrow();
+
+        let id = match &self.game_handler.game {
+            Some(game) => game.id.clone(),
+            None => stats_handler.last_game_id()?,
+        };
+
+        let history = stats_handler.history(&id);
+
+        Some((id, history))
     }
 
     /// Switches to "game selection by id" modal.
@@ -441,14 +1052,23 @@ where
     fn register_partial_move(&mut self, location: Location) {
         match &self.move_from {
             Some(first) => {
-                let _ = self.game_handler.make_move(Move {
-                    from: first.clone(),
-                    to: location,
-                });
+                let result = self.game_handler.make_move(
+                    Move {
+                        from: first.clone(),
+                        to: location,
+                    },
+                    self.elapsed,
+                );
+
+                match result {
+                    Ok(()) => self.hint = None,
+                    Err(error) => self.move_error = Some(error),
+                }
 
                 self.move_from = None;
             }
             None => {
+                self.move_error = None;
                 self.move_from = Some(location);
             }
         }
@@ -462,66 +1082,333 @@ where
     /// Reverts the previous, completed move.
     fn revert(&mut self) {
         self.move_from = None;
-        let _ = self.game_handler.revert();
+        self.hint = None;
+        self.move_error = self.game_handler.revert().err();
     }
-}
 
-impl<T> Widget for &mut App<T>
-where
-    T: PersistJourney,
-    T: Debug,
-{
-    /// Entry point for the rendering.
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        if area.width < 32 || area.height < 24 {
-            panic!("RSLibreCell needs at least 32 columns and 24 lines in the terminal");
-        }
+    /// Redoes the previously reverted move.
+    fn redo(&mut self) {
+        self.move_from = None;
+        self.hint = None;
+        self.move_error = self.game_handler.redo().err();
+    }
 
-        let title = Line::from(" RSLibreCell ".bold());
-        let instructions = Line::from(vec![
-            " Help ".into(),
-            "<F1>".blue().bold(),
-            " Quit ".into(),
-            "<CTRL-q> ".blue().bold(),
-        ]);
-        let block = Block::bordered()
-            .title(title.centered())
-            .title_bottom(instructions.centered())
-            .border_set(border::THICK);
+    /// Requests a hint for the current position,
+    /// which is computed on another thread so the clock keeps running.
+    fn hint(&mut self) {
+        self.move_from = None;
+        self.move_error = None;
+        self.hint = None;
+        self.pending_hint = self
+            .game_handler
+            .hint_request()
+            .map(|request| (request.game.clone(), request))
+            .map(|(game, request)| (thread::spawn(move || request.solve()), game));
+    }
 
-        let mut lines: Vec<Line> = vec![];
+    /// Takes the pending hint if it has been computed,
+    /// dropping it if the game has changed in the meantime.
+    fn receive_hint(&mut self) {
+        if !self
+            .pending_hint
+            .as_ref()
+            .is_some_and(|(handle, _)| handle.is_finished())
+        {
+            return;
+        }
 
-        if let Some(game) = self.game_handler.game.as_ref() {
-            render::provide_game_lines(&mut lines, game);
+        let Some((handle, game)) = self.pending_hint.take() else {
+            return;
+        };
+
+        if self.game_handler.game.as_ref() == Some(&game) {
+            self.hint = handle.join().ok();
         }
+    }
 
-        let board_text = Text::from(lines);
+    /// Gets the key referring to the given location.
+    fn key_for(&self, location: &Location) -> char {
+        match location {
+            Location::Cell { i: 0 } => self.key_config.cell1,
+            Location::Cell { i: 1 } => self.key_config.cell2,
+            Location::Cell { i: 2 } => self.key_config.cell3,
+            Location::Cell { i: _ } => self.key_config.cell4,
+            Location::Foundation => self.key_config.foundation1,
+            Location::Column { i: 0 } => self.key_config.column1,
+            Location::Column { i: 1 } => self.key_config.column2,
+            Location::Column { i: 2 } => self.key_config.column3,
+            Location::Column { i: 3 } => self.key_config.column4,
+            Location::Column { i: 4 } => self.key_config.column5,
+            Location::Column { i: 5 } => self.key_config.column6,
+            Location::Column { i: 6 } => self.key_config.column7,
+            Location::Column { i: _ } => self.key_config.column8,
+        }
+    }
 
-        Paragraph::new(board_text)
-            .centered()
-            .block(block)
-            .render(area, buf);
+    /// Provides the message for the status line,
+    /// which is the clock and the move counter unless there is anything to report.
+    fn status(&self) -> String {
+        if let Some(error) = &self.move_error {
+            return error.to_string();
+        }
 
-        match self.app_state {
-            AppState::Base => {}
-            AppState::Exit => panic!("should never happen"),
-            AppState::HelpModal => render::render_help_modal(&self.key_config, area, buf),
-            AppState::AboutModal { scroll } => {
-                let mut new_scroll = scroll;
-                let mut set_scroll = |x: u16| -> () {
-                    new_scroll = x;
-                };
-                render::render_about_modal(area, buf, scroll, &mut set_scroll);
-                self.app_state = AppState::AboutModal { scroll: new_scroll };
+        match &self.hint {
+            None if self.pending_hint.is_some() => "Looking for a hint...".to_string(),
+            None => {
+                let (moves, automoves) = self.game_handler.move_counts();
+                render::progress_text(self.elapsed, moves, automoves)
             }
-            AppState::SelectionIdModal { id } => {
-                render::render_selection_id_modal(area, buf, id);
+            Some(Hint::Move(mv)) => format!(
+                "Hint: <{}> then <{}>",
+                self.key_for(&mv.from),
+                self.key_for(&mv.to)
+            ),
+            Some(Hint::NoSolution { proven, reverts }) => {
+                let prefix = if *proven { "Unwinnable" } else { "No solution" };
+                match reverts {
+                    Some(reverts) => format!("{prefix}: revert {reverts}x"),
+                    None => format!("{prefix} found"),
+                }
+            }
+        }
+    }
+
+    /// Provides the parts of the board to be highlighted,
+    /// which are the selected card or run and the move of the hint, if any.
+    fn highlights(&self) -> Vec<render::Highlight> {
+        let mut result = self.hint_highlights();
+
+        let (Some(game), Some(from)) = (&self.game_handler.game, &self.move_from) else {
+            return result;
+        };
+
+        match from {
+            Location::Cell { i } => result.push(render::Highlight::Cell { i: *i }),
+            Location::Foundation => {}
+            Location::Column { i } => {
+                // the largest run that can be moved anywhere
+                let count = legal_moves(game)
+                    .into_iter()
+                    .filter(|x| x.mv.from == *from)
+                    .map(|x| x.count)
+                    .max()
+                    .unwrap_or(1);
+
+                result.push(render::Highlight::Run { i: *i, count });
+            }
+        }
+
+        result
+    }
+
+    /// Provides the locations the selected card or run can legally be moved to.
+    fn targets(&self) -> Vec<render::Highlight> {
+        let (Some(game), Some(from)) = (&self.game_handler.game, &self.move_from) else {
+            return vec![];
+        };
+
+        let moved_card = match from {
+            Location::Cell { i } => game.cells[*i],
+            Location::Foundation => None,
+            Location::Column { i } => game.columns[*i].last().copied(),
+        };
+
+        let mut result = vec![];
+
+        for legal_move in legal_moves(game).into_iter().filter(|x| x.mv.from == *from) {
+            let target = match legal_move.mv.to {
+                Location::Cell { i } => render::Highlight::Cell { i },
+                Location::Foundation => match moved_card {
+                    Some(card) => render::Highlight::Foundation {
+                        i: foundation_index_for(card.suit),
+                    },
+                    None => continue,
+                },
+                Location::Column { i } => render::Highlight::Column { i },
+            };
+
+            // moves to empty columns are listed once for each number of cards
+            if !result.contains(&target) {
+                result.push(target);
+            }
+        }
+
+        result
+    }
+
+    /// Provides the parts of the board to be highlighted for the hint, if any.
+    fn hint_highlights(&self) -> Vec<render::Highlight> {
+        let (Some(game), Some(Hint::Move(mv))) = (&self.game_handler.game, &self.hint) else {
+            return vec![];
+        };
+
+        let moved_card = match mv.from {
+            Location::Cell { i } => game.cells[i],
+            Location::Foundation => None,
+            Location::Column { i } => game.columns[i].last().copied(),
+        };
+
+        [&mv.from, &mv.to]
+            .iter()
+            .filter_map(|location| match location {
+                Location::Cell { i } => Some(render::Highlight::Cell { i: *i }),
+                Location::Foundation => moved_card.map(|card| render::Highlight::Foundation {
+                    i: foundation_index_for(card.suit),
+                }),
+                Location::Column { i } => Some(render::Highlight::Column { i: *i }),
+            })
+            .collect()
+    }
+}
+
+impl<T, U, V, W> Widget for &mut App<T, U, V, W>
+where
+    T: PersistJourney,
+    T: Debug,
+    U: PersistGame,
+    U: Debug,
+    V: PersistStats,
+    V: Debug,
+    W: PersistKeyConfig,
+    W: Debug,
+{
+    /// Entry point for the rendering.
+    ///
+    /// The board adapts to the width of the area, compare `render::BoardLayout`.
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.area = area;
+
+        let Some(layout) = render::BoardLayout::for_area(area) else {
+            render::render_too_small(area, buf);
+            return;
+        };
+
+        // the theme and suits being chosen in the settings modal are previewed
+        let (theme, ascii) = match self.app_state {
+            AppState::SettingsModal { ref draft, .. } => (draft.theme, draft.ascii),
+            _ => (self.key_config.theme, self.key_config.ascii),
+        };
+        let palette = &render::Palette::of(theme);
+
+        let title = Line::from(" RSLibreCell ".bold());
+        let actions = &self.key_config.actions;
+        let instructions = Line::from(vec![
+            " Help ".into(),
+            format!("<{}>", actions.help).fg(palette.accent).bold(),
+            " Quit ".into(),
+            format!("<{}> ", actions.quit).fg(palette.accent).bold(),
+        ]);
+        let block = Block::bordered()
+            .title(title.centered())
+            .title_bottom(instructions.centered())
+            .border_set(border::THICK)
+            .border_style(palette.border);
+
+        let mut lines: Vec<Line> = vec![];
+
+        let status = self.status();
+        let highlights = self.highlights();
+        let targets = self.targets();
+
+        if let Some(game) = self.game_handler.game.as_ref() {
+            render::provide_game_lines(
+                &mut lines,
+                game,
+                &status,
+                &highlights,
+                &targets,
+                layout,
+                palette,
+                ascii,
+            );
+        }
+
+        let board_text = Text::from(lines);
+
+        Paragraph::new(board_text)
+            .centered()
+            .block(block)
+            .render(area, buf);
+
+        if layout == render::BoardLayout::Wide && self.game_handler.game.is_some() {
+            render::render_side_panels(
+                area,
+                buf,
+                &self.game_handler.moves(),
+                &self.stats_handler.borrow().summary(),
+                palette,
+            );
+        }
+
+        match self.app_state {
+            AppState::Base => {}
+            AppState::Exit => panic!("should never happen"),
+            AppState::HelpModal => render::render_help_modal(&self.key_config, area, buf, palette),
+            AppState::AboutModal { scroll } => {
+                let mut new_scroll = scroll;
+                let mut set_scroll = |x: u16| -> () {
+                    new_scroll = x;
+                };
+                render::render_about_modal(
+                    &self.key_config.actions,
+                    area,
+                    buf,
+                    palette,
+                    scroll,
+                    &mut set_scroll,
+                );
+                self.app_state = AppState::AboutModal { scroll: new_scroll };
+            }
+            AppState::SelectionIdModal { id } => {
+                render::render_selection_id_modal(area, buf, palette, id);
             }
             AppState::SelectionJourneyModal => render::render_selection_journey_modal(
+                &self.key_config.actions,
                 area,
                 buf,
+                palette,
                 self.journey_handler.borrow().next_game_ids(),
             ),
+            AppState::ResumeModal { ref saved_game } => render::render_resume_modal(
+                &self.key_config.actions,
+                area,
+                buf,
+                palette,
+                saved_game,
+            ),
+            AppState::ErrorModal { ref messages, .. } => {
+                render::render_error_modal(area, buf, palette, messages)
+            }
+            AppState::StatsModal => render::render_stats_modal(
+                area,
+                buf,
+                palette,
+                &self.stats_handler.borrow().summary(),
+                self.stats_history(),
+            ),
+            AppState::SettingsModal {
+                ref draft,
+                selected,
+                capturing,
+            } => render::render_settings_modal(
+                &self.key_config.actions,
+                area,
+                buf,
+                palette,
+                draft,
+                selected,
+                capturing,
+            ),
+            AppState::HistoryModal { ref name, page } => render::render_history_modal(
+                &self.key_config.actions,
+                area,
+                buf,
+                palette,
+                &self.game_handler.branches(),
+                &self.game_handler.checkpoints(),
+                name.as_deref(),
+                page,
+            ),
         }
     }
 }
@@ -530,42 +1417,319 @@ mod render {
     use super::*;
     use rslibrecell::game::Game;
 
+    /// A part of the board to be highlighted.
+    #[derive(Clone, Debug, PartialEq)]
+    pub(crate) enum Highlight {
+        /// The cell with the given index.
+        Cell { i: usize },
+        /// The foundation with the given index.
+        Foundation { i: usize },
+        /// The top card of the column with the given index, or its first row if empty.
+        Column { i: usize },
+        /// The given number of cards at the top of the column with the given index.
+        Run { i: usize, count: usize },
+    }
+
+    /// The colors and styles of a theme.
+    #[derive(Clone, Debug, PartialEq)]
+    pub(crate) struct Palette {
+        /// The color of the clubs.
+        pub(crate) clubs: Color,
+        /// The color of the diamonds.
+        pub(crate) diamonds: Color,
+        /// The color of the hearts.
+        pub(crate) hearts: Color,
+        /// The color of the spades.
+        pub(crate) spades: Color,
+        /// The style of the borders.
+        pub(crate) border: Style,
+        /// The style added to the selection and the hint.
+        pub(crate) highlight: Style,
+        /// The style added to the locations the selection can move to.
+        pub(crate) target: Style,
+        /// The color of the keys to press in the modals.
+        pub(crate) accent: Color,
+        /// The color of the keys listed in the help.
+        pub(crate) key: Color,
+        /// The color of warnings, such as invalid input.
+        pub(crate) warning: Color,
+    }
+
+    impl Palette {
+        /// Provides the palette of the given theme.
+        pub(crate) fn of(theme: Theme) -> Palette {
+            let dark = Palette {
+                clubs: Color::Reset,
+                diamonds: Color::Red,
+                hearts: Color::Red,
+                spades: Color::Reset,
+                border: Style::new(),
+                highlight: Style::new().add_modifier(Modifier::REVERSED),
+                target: Style::new().add_modifier(Modifier::UNDERLINED),
+                accent: Color::Blue,
+                key: Color::Cyan,
+                warning: Color::Red,
+            };
+
+            match theme {
+                Theme::Dark => dark,
+                Theme::Light => Palette {
+                    clubs: Color::Black,
+                    spades: Color::Black,
+                    border: Style::new().fg(Color::DarkGray),
+                    key: Color::Magenta,
+                    ..dark
+                },
+                Theme::HighContrast => Palette {
+                    clubs: Color::White,
+                    diamonds: Color::LightRed,
+                    hearts: Color::LightRed,
+                    spades: Color::White,
+                    border: Style::new().fg(Color::White).add_modifier(Modifier::BOLD),
+                    highlight: Style::new().add_modifier(Modifier::REVERSED | Modifier::BOLD),
+                    target: Style::new().add_modifier(Modifier::UNDERLINED | Modifier::BOLD),
+                    accent: Color::LightYellow,
+                    key: Color::LightCyan,
+                    warning: Color::LightRed,
+                },
+                // blue and yellow stay apart for red-green color blindness
+                Theme::FourColor => Palette {
+                    clubs: Color::LightBlue,
+                    diamonds: Color::Yellow,
+                    ..dark
+                },
+            }
+        }
+    }
+
+    /// The smallest width the app can be displayed in.
+    const MIN_WIDTH: u16 = 28;
+    /// The smallest height the app can be displayed in.
+    const MIN_HEIGHT: u16 = 24;
+    /// The smallest width for the regular board.
+    const REGULAR_WIDTH: u16 = 36;
+    /// The smallest width for the side panels.
+    const WIDE_WIDTH: u16 = 80;
+    /// The width of each side panel.
+    const PANEL_WIDTH: u16 = 20;
+
+    /// The layout of the board, depending on the width of the terminal.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub(crate) enum BoardLayout {
+        /// Narrower cards for narrow terminals.
+        Compact,
+        /// The regular board.
+        Regular,
+        /// The regular board, with side panels for the moves and the statistics.
+        Wide,
+    }
+
+    impl BoardLayout {
+        /// Determines the layout fitting into the given area, if any.
+        pub(crate) fn for_area(area: Rect) -> Option<BoardLayout> {
+            if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+                None
+            } else if area.width < REGULAR_WIDTH {
+                Some(BoardLayout::Compact)
+            } else if area.width < WIDE_WIDTH {
+                Some(BoardLayout::Regular)
+            } else {
+                Some(BoardLayout::Wide)
+            }
+        }
+
+        /// The width taken by a card, including the space in front of it.
+        fn slot(&self) -> u16 {
+            match self {
+                BoardLayout::Compact => 3,
+                BoardLayout::Regular | BoardLayout::Wide => 4,
+            }
+        }
+
+        /// The width of each line of the board.
+        fn width(&self) -> u16 {
+            8 * self.slot() + 2
+        }
+    }
+
+    /// Determines the location displayed at the given position of the terminal,
+    /// given the area the app was rendered to, compare `provide_game_lines`.
+    pub(crate) fn location_at(area: Rect, column: u16, row: u16) -> Option<Location> {
+        let layout = BoardLayout::for_area(area)?;
+        let (slot, width) = (layout.slot(), layout.width());
+
+        let inner_area = area.inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        });
+
+        // the lines are centered
+        let left = inner_area.x + inner_area.width.saturating_sub(width) / 2;
+
+        if column < left || row < inner_area.y {
+            return None;
+        }
+
+        let (x, y) = (column - left, row - inner_area.y);
+
+        match y {
+            1 if x < 4 * slot => Some(Location::Cell {
+                i: (x / slot) as usize,
+            }),
+            1 if (4 * slot + 2..width).contains(&x) => Some(Location::Foundation),
+            3..=21 if (1..width - 1).contains(&x) => Some(Location::Column {
+                i: ((x - 1) / slot) as usize,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Renders a note instead of the app, for an area that is too small.
+    pub(crate) fn render_too_small(area: Rect, buf: &mut Buffer) {
+        let lines: Vec<Line> = vec![
+            Line::from("Terminal too small".bold()),
+            Line::from(format!("Please resize to {}x{}", MIN_WIDTH, MIN_HEIGHT)),
+        ];
+
+        Paragraph::new(Text::from(lines))
+            .centered()
+            .wrap(Wrap { trim: true })
+            .render(area, buf);
+    }
+
+    /// Renders the panels next to the board, with the moves leading
+    /// to the current position on the left and the statistics on the right.
+    pub(crate) fn render_side_panels(
+        area: Rect,
+        buf: &mut Buffer,
+        moves: &[Move],
+        summary: &Summary,
+        palette: &Palette,
+    ) {
+        let inner_area = area.inner(Margin {
+            horizontal: 2,
+            vertical: 1,
+        });
+
+        let moves_area = Rect {
+            width: PANEL_WIDTH,
+            ..inner_area
+        };
+        let stats_area = Rect {
+            x: inner_area.right() - PANEL_WIDTH,
+            width: PANEL_WIDTH,
+            ..inner_area
+        };
+
+        // the most recent moves are shown
+        let visible = moves_area.height.saturating_sub(2) as usize;
+        let skipped = moves.len().saturating_sub(visible);
+
+        let mut move_lines: Vec<Line> = (skipped + 1..)
+            .zip(moves[skipped..].iter())
+            .map(|(number, mv)| Line::from(format!("{:>4}. {}", number, mv)))
+            .collect();
+
+        if move_lines.is_empty() {
+            move_lines.push(Line::from("No moves yet"));
+        }
+
+        Paragraph::new(Text::from(move_lines))
+            .block(
+                Block::bordered()
+                    .border_style(palette.border)
+                    .title(Line::from(" Moves ").centered()),
+            )
+            .render(moves_area, buf);
+
+        let win_rate = match summary.played {
+            0 => String::from("-"),
+            played => format!("{}%", summary.won * 100 / played),
+        };
+
+        let stats_lines: Vec<Line> = vec![
+            Line::from(format!(" Played:  {:>7}", summary.played)),
+            Line::from(format!(" Won:     {:>7}", summary.won)),
+            Line::from(format!(" Win rate:{:>7}", win_rate)),
+            Line::from(format!(" Streak:  {:>7}", summary.current_streak)),
+            Line::from(format!(" Best:    {:>7}", summary.longest_streak)),
+        ];
+
+        Paragraph::new(Text::from(stats_lines))
+            .block(
+                Block::bordered()
+                    .border_style(palette.border)
+                    .title(Line::from(" Statistics ").centered()),
+            )
+            .render(stats_area, buf);
+    }
+
     /// Provides the lines for the inner game board.
-    pub(crate) fn provide_game_lines<'a>(lines: &mut Vec<Line<'a>>, game: &'a Game) {
-        let mut title_line = String::from("                           ");
+    ///
+    /// The `highlights` are displayed reversed, the `targets` underlined.
+    /// With `ascii`, the suits are shown as letters.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn provide_game_lines<'a>(
+        lines: &mut Vec<Line<'a>>,
+        game: &'a Game,
+        status: &str,
+        highlights: &[Highlight],
+        targets: &[Highlight],
+        layout: BoardLayout,
+        palette: &Palette,
+        ascii: bool,
+    ) {
+        let slot = layout.slot() as usize;
+        let empty = " ".repeat(slot);
+        let placeholder = format!("{:<slot$}", " ..");
 
+        // long IDs take space from the status
         let id = format!("#{:}", game.id.0.to_string());
-        title_line += &format!("{:>6} ", id);
+        let id_width = max(6, id.len());
+        let status_width = (layout.width() as usize - 2).saturating_sub(id_width);
+        let status: String = status.chars().take(status_width).collect();
+        let title_line = format!(" {:<status_width$}{:>id_width$} ", status, id);
 
         lines.push(Line::from(title_line));
 
-        let mut cells_foundations_span: Vec<Span> = vec![];
-        for cell in &game.cells {
-            match cell {
-                Some(card) => {
-                    cells_foundations_span.push(get_colored_representation(card));
-                }
-                None => {
-                    cells_foundations_span.push(" .. ".into());
-                }
+        let highlight = |span: Span<'a>, highlight: &Highlight| -> Span<'a> {
+            let span = if highlights.contains(highlight) {
+                span.patch_style(palette.highlight)
+            } else {
+                span
+            };
+
+            if targets.contains(highlight) {
+                span.patch_style(palette.target)
+            } else {
+                span
             }
+        };
+
+        let mut cells_foundations_span: Vec<Span> = vec![];
+        for (i, cell) in game.cells.iter().enumerate() {
+            let span = match cell {
+                Some(card) => get_colored_representation(card, layout, palette, ascii),
+                None => placeholder.clone().into(),
+            };
+            cells_foundations_span.push(highlight(span, &Highlight::Cell { i }));
         }
 
-        cells_foundations_span.push("||".into());
+        cells_foundations_span.push(match layout {
+            BoardLayout::Compact => " |".into(),
+            BoardLayout::Regular | BoardLayout::Wide => "||".into(),
+        });
 
-        for foundation in &game.foundations {
-            match foundation.last() {
-                Some(card) => {
-                    cells_foundations_span.push(get_colored_representation(card));
-                }
-                None => {
-                    cells_foundations_span.push(" .. ".into());
-                }
-            }
+        for (i, foundation) in game.foundations.iter().enumerate() {
+            let span = match foundation.last() {
+                Some(card) => get_colored_representation(card, layout, palette, ascii),
+                None => placeholder.clone().into(),
+            };
+            cells_foundations_span.push(highlight(span, &Highlight::Foundation { i }));
         }
 
         lines.push(Line::from(cells_foundations_span));
-        lines.push(Line::from("----------------------------------"));
+        lines.push(Line::from("-".repeat(layout.width() as usize)));
 
         if game.is_won() {
             lines.push(Line::from(""));
@@ -574,16 +1738,29 @@ mod render {
             let mut column_spans: Vec<Vec<Span>> = vec![vec![Span::from(" ")]; 19];
 
             for i in 0..19 as usize {
-                for column in &game.columns {
+                for (j, column) in game.columns.iter().enumerate() {
                     let card = column.get(i);
-                    match card {
-                        Some(card) => {
-                            column_spans[i].push(get_colored_representation(card));
-                        }
-                        None => {
-                            column_spans[i].push("    ".into());
-                        }
-                    }
+                    let span = match card {
+                        Some(card) => get_colored_representation(card, layout, palette, ascii),
+                        None => empty.clone().into(),
+                    };
+                    let span = if i == column.len().saturating_sub(1) {
+                        highlight(span, &Highlight::Column { i: j })
+                    } else {
+                        span
+                    };
+
+                    // all cards of a selected run, including the top card
+                    let in_run = highlights.iter().any(|x| {
+                        matches!(x, Highlight::Run { i: run, count }
+                            if *run == j && i < column.len() && i + count >= column.len())
+                    });
+
+                    column_spans[i].push(if in_run {
+                        span.patch_style(palette.highlight)
+                    } else {
+                        span
+                    });
                 }
 
                 column_spans[i].push(" ".into());
@@ -600,37 +1777,57 @@ mod render {
     }
 
     /// Renders the help modal.
-    pub(crate) fn render_help_modal(key_config: &KeyConfig, area: Rect, buf: &mut Buffer) {
+    pub(crate) fn render_help_modal(
+        key_config: &KeyConfig,
+        area: Rect,
+        buf: &mut Buffer,
+        palette: &Palette,
+    ) {
         let title = Line::from(" Help ");
-        let instructions = Line::from(vec![" Close ".into(), "<Esc> ".blue().bold()]);
+        let instructions = Line::from(vec![" Close ".into(), "<Esc> ".fg(palette.accent).bold()]);
         let block = Block::bordered()
+            .border_style(palette.border)
             .title(title.centered())
             .title_bottom(instructions.centered());
 
+        let actions = &key_config.actions;
+
         let mut help_lines: Vec<Line> = vec![];
         help_lines.push(Line::from(vec![
-            "<F12>".blue(),
+            format!("<{}>", actions.about).fg(palette.accent),
             " to show the About box.".into(),
         ]));
         help_lines.push(Line::from(vec![
-            "<F2>".cyan(),
+            format!("<{}>", actions.random_game).fg(palette.key),
             " to start a new random game.".into(),
         ]));
         help_lines.push(Line::from(vec![
-            "<F3>".cyan(),
+            format!("<{}>", actions.select_by_id).fg(palette.key),
             " to choose a game to start.".into(),
         ]));
         help_lines.push(Line::from(vec![
-            "<!>".cyan(),
+            format!("<{}>", actions.journey).fg(palette.key),
             " to open the Journey box.".into(),
         ]));
+        help_lines.push(Line::from(vec![
+            format!("<{}>", actions.history).fg(palette.key),
+            " to open the History box.".into(),
+        ]));
+        help_lines.push(Line::from(vec![
+            format!("<{}>", actions.statistics).fg(palette.key),
+            " to open the Statistics box.".into(),
+        ]));
+        help_lines.push(Line::from(vec![
+            format!("<{}>", actions.settings).fg(palette.key),
+            " to open the Settings box.".into(),
+        ]));
         help_lines.push(Line::from("\n"));
         help_lines.push(Line::from(vec![
             format!(
                 "<{}> <{}> <{}> <{}>",
                 key_config.cell1, key_config.cell2, key_config.cell3, key_config.cell4
             )
-            .cyan(),
+            .fg(palette.key),
             " - cells ".into(),
         ]));
         help_lines.push(Line::from(vec![
@@ -641,7 +1838,7 @@ mod render {
                 key_config.foundation3,
                 key_config.foundation4
             )
-            .cyan(),
+            .fg(palette.key),
             " - foundations ".into(),
         ]));
         help_lines.push(Line::from(vec![
@@ -649,7 +1846,7 @@ mod render {
                 "<{}> <{}> <{}> <{}>",
                 key_config.column1, key_config.column2, key_config.column3, key_config.column4
             )
-            .cyan(),
+            .fg(palette.key),
             " - left columns ".into(),
         ]));
         help_lines.push(Line::from(vec![
@@ -657,16 +1854,20 @@ mod render {
                 "<{}> <{}> <{}> <{}>",
                 key_config.column5, key_config.column6, key_config.column7, key_config.column8
             )
-            .cyan(),
+            .fg(palette.key),
             " - right columns ".into(),
         ]));
         help_lines.push(Line::from("\n"));
         help_lines.push(Line::from(vec![
             "Make a move by choosing the start and end of a move. ".into(),
-            "<Space>".cyan(),
+            format!("<{}>", actions.clear).fg(palette.key),
             " to abort a move. ".into(),
-            "<R>".cyan(),
-            " to revert the last move.".into(),
+            format!("<{}>", actions.revert).fg(palette.key),
+            " to revert the last move. ".into(),
+            format!("<{}>", actions.redo).fg(palette.key),
+            " to redo it. ".into(),
+            format!("<{}>", actions.hint).fg(palette.key),
+            " to get a hint.".into(),
         ]));
 
         let help_text = Text::from(help_lines);
@@ -688,8 +1889,10 @@ mod render {
 
     /// Renders the about modal.
     pub(crate) fn render_about_modal<F>(
+        actions: &ActionKeys,
         area: Rect,
         buf: &mut Buffer,
+        palette: &Palette,
         scroll: u16,
         set_scroll: &mut F,
     ) where
@@ -698,15 +1901,16 @@ mod render {
         let title = Line::from(" About ");
         let instructions = Line::from(vec![
             " Scroll ".into(),
-            "<Up><Down>".blue().bold(),
+            "<Up><Down>".fg(palette.accent).bold(),
             " Close ".into(),
-            "<Esc> ".blue().bold(),
+            "<Esc> ".fg(palette.accent).bold(),
         ]);
         let block = Block::bordered()
+            .border_style(palette.border)
             .title(title.centered())
             .title_bottom(instructions.centered());
 
-        let about_lines = create_about_text();
+        let about_lines = create_about_text(actions, palette);
 
         let about_text = Text::from(about_lines);
 
@@ -736,15 +1940,21 @@ mod render {
     }
 
     /// Renders the "game selection by id" modal.
-    pub(crate) fn render_selection_id_modal(area: Rect, buf: &mut Buffer, id: [u8; 5]) {
+    pub(crate) fn render_selection_id_modal(
+        area: Rect,
+        buf: &mut Buffer,
+        palette: &Palette,
+        id: [u8; ID_LENGTH],
+    ) {
         let title = Line::from(" Choose game by ID ");
         let instructions = Line::from(vec![
             " Accept ".into(),
-            "<Enter>".blue().bold(),
+            "<Enter>".fg(palette.accent).bold(),
             " Abort ".into(),
-            "<Esc> ".blue().bold(),
+            "<Esc> ".fg(palette.accent).bold(),
         ]);
         let block = Block::bordered()
+            .border_style(palette.border)
             .title(title.centered())
             .title_bottom(instructions.centered());
 
@@ -752,10 +1962,10 @@ mod render {
 
         let mut entry = id_representation.underlined();
 
-        if id[4] != SPACE_ASCII_CODE {
-            let check_id = u32::from_str_radix(id_representation.trim(), 10).unwrap();
-            if 0 == check_id || check_id > 64000 {
-                entry = entry.red();
+        if id[ID_LENGTH - 1] != SPACE_ASCII_CODE {
+            let check_id = id_representation.trim().parse::<u64>().unwrap();
+            if 0 == check_id || check_id > MAX_GAME_ID {
+                entry = entry.fg(palette.warning);
             }
         }
 
@@ -780,13 +1990,16 @@ mod render {
 
     /// Renders the "game selection from journey" modal.
     pub(crate) fn render_selection_journey_modal(
+        actions: &ActionKeys,
         area: Rect,
         buf: &mut Buffer,
+        palette: &Palette,
         next_game_ids: (GameId, Vec<GameId>),
     ) {
         let title = Line::from(" Journey ");
-        let instructions = Line::from(vec![" Close ".into(), "<Esc> ".blue().bold()]);
+        let instructions = Line::from(vec![" Close ".into(), "<Esc> ".fg(palette.accent).bold()]);
         let block = Block::bordered()
+            .border_style(palette.border)
             .title(title.centered())
             .title_bottom(instructions.centered());
 
@@ -804,12 +2017,16 @@ mod render {
 
             if next_game_exists {
                 selection_lines.push(Line::from(vec![
-                    "<1>".blue().bold(),
+                    format!("<{}>", actions.journey_next)
+                        .fg(palette.accent)
+                        .bold(),
                     " (next game) : ".into(),
                     format!("{:>5}", next_game_ids.0 .0).into(),
                 ]));
                 selection_lines.push(Line::from(vec![
-                    "<s>".blue().bold(),
+                    format!("<{}>", actions.journey_skip)
+                        .fg(palette.accent)
+                        .bold(),
                     " to skip for now".into(),
                 ]));
                 selection_lines.push(Line::from(vec!["".into()]));
@@ -818,17 +2035,14 @@ mod render {
             if skipped_games_exist {
                 selection_lines.push(Line::from(vec!["Previously skipped games".into()]));
 
-                let mut key = 2;
-
-                for skipped in next_game_ids.1.iter().take(8) {
+                for (key, skipped) in actions.journey_skipped.iter().zip(next_game_ids.1.iter()) {
                     selection_lines.push(Line::from(vec![
-                        format!("<{}>", key).blue().bold(),
+                        format!("<{}>", key).fg(palette.accent).bold(),
                         format!(" : {:>5}", skipped.0).into(),
                     ]));
-                    key += 1;
                 }
 
-                if next_game_ids.1.len() > 8 {
+                if next_game_ids.1.len() > actions.journey_skipped.len() {
                     selection_lines.push(Line::from(vec!["".into()]));
                     selection_lines.push(Line::from(vec!["... more skipped games".into()]));
                 }
@@ -856,6 +2070,467 @@ mod render {
         paragraph.render(inner_area, buf);
     }
 
+    /// Renders the settings modal, scrolling the entries to keep the selected one visible.
+    pub(crate) fn render_settings_modal(
+        actions: &ActionKeys,
+        area: Rect,
+        buf: &mut Buffer,
+        palette: &Palette,
+        draft: &KeyConfig,
+        selected: usize,
+        capturing: bool,
+    ) {
+        let title = Line::from(" Settings ");
+        let instructions = Line::from(vec![
+            " Save ".into(),
+            format!("<{}>", actions.settings_save)
+                .fg(palette.accent)
+                .bold(),
+            " Close ".into(),
+            "<Esc> ".fg(palette.accent).bold(),
+        ]);
+        let block = Block::bordered()
+            .border_style(palette.border)
+            .title(title.centered())
+            .title_bottom(instructions.centered());
+
+        let entries = draft.entries();
+        let problems = draft.problems();
+
+        let area = popup_area(area);
+        Clear.render(area, buf);
+
+        block.render(area, buf);
+
+        let inner_area = area.inner(Margin {
+            horizontal: 2,
+            vertical: 1,
+        });
+
+        // the suits preview the colors of the theme
+        let suits = if draft.ascii { "CDHS" } else { "♣♦♥♠" };
+        let suits: Vec<String> = suits.chars().map(String::from).collect();
+        let theme_line = Line::from(vec![
+            "<Left><Right>".fg(palette.accent).bold(),
+            " theme: ".into(),
+            draft.theme.to_string().bold(),
+            " ".into(),
+            suits[0].clone().fg(palette.clubs),
+            suits[1].clone().fg(palette.diamonds),
+            suits[2].clone().fg(palette.hearts),
+            suits[3].clone().fg(palette.spades),
+        ]);
+        let suits_line = Line::from(vec![
+            format!("<{}>", actions.settings_suits)
+                .fg(palette.accent)
+                .bold(),
+            " suits: ".into(),
+            if draft.ascii { "letters" } else { "symbols" }.bold(),
+        ]);
+
+        let header = if capturing {
+            Line::from(vec![
+                "Press the new key, ".into(),
+                "<Esc>".fg(palette.accent).bold(),
+                " to cancel".into(),
+            ])
+        } else {
+            Line::from(vec![
+                "<Enter>".fg(palette.accent).bold(),
+                " to rebind, ".into(),
+                "<Del>".fg(palette.accent).bold(),
+                " for default".into(),
+            ])
+        };
+
+        // theme, suits, header and blank line above, blank line and two lines for a problem below
+        let rows = inner_area.height.saturating_sub(7) as usize;
+        let start = selected
+            .saturating_sub(rows / 2)
+            .min(entries.len().saturating_sub(rows));
+
+        let mut lines: Vec<Line> = vec![theme_line, suits_line, header, Line::from("")];
+
+        for (i, (name, key)) in entries.iter().enumerate().skip(start).take(rows) {
+            let key = if capturing && i == selected {
+                String::from("<...>")
+            } else {
+                format!("<{}>", key)
+            };
+
+            let mut line = Line::from(format!("{:<20}{}", name, key));
+
+            if problems.iter().any(|x| x.names().contains(name)) {
+                line = line.fg(palette.warning);
+            }
+            if i == selected {
+                line = line.patch_style(palette.highlight);
+            }
+
+            lines.push(line);
+        }
+
+        Paragraph::new(Text::from(lines)).render(inner_area, buf);
+
+        // the problem of the selected entry is preferred
+        let selected_name = &entries[selected].0;
+        let problem = problems
+            .iter()
+            .find(|x| x.names().contains(selected_name))
+            .or(problems.first());
+
+        if let Some(problem) = problem {
+            let problem_area = Rect {
+                x: inner_area.x,
+                y: inner_area.y + inner_area.height.saturating_sub(2),
+                width: inner_area.width,
+                height: 2.min(inner_area.height),
+            };
+
+            Paragraph::new(format!("{}.", problem).fg(palette.warning))
+                .wrap(Wrap { trim: true })
+                .render(problem_area, buf);
+        }
+    }
+
+    /// Renders the history modal.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn render_history_modal(
+        actions: &ActionKeys,
+        area: Rect,
+        buf: &mut Buffer,
+        palette: &Palette,
+        branches: &[Branch],
+        checkpoints: &[Checkpoint],
+        name: Option<&str>,
+        page: usize,
+    ) {
+        let title = Line::from(" History ");
+        let instructions = Line::from(vec![" Close ".into(), "<Esc> ".fg(palette.accent).bold()]);
+        let block = Block::bordered()
+            .border_style(palette.border)
+            .title(title.centered())
+            .title_bottom(instructions.centered());
+
+        let mut history_lines: Vec<Line> = vec![];
+
+        history_lines.push(Line::from(vec!["Switch to branch:".into()]));
+
+        let per_page = actions.history_branches.len();
+        let shown = branches.iter().skip(page * per_page).take(per_page);
+
+        for (key, branch) in actions.history_branches.iter().zip(shown) {
+            let description = match &branch.last_move {
+                None => "initial position".to_string(),
+                Some(mv) => format!("{:>9}, {}", moves_text(branch.length), mv),
+            };
+
+            let mut spans = vec![
+                format!("<{}>", key).fg(palette.accent).bold(),
+                format!(" : {}", description).into(),
+            ];
+
+            // the branch followed by redo is marked
+            if branch.active {
+                spans.push(" *".bold());
+            }
+
+            history_lines.push(Line::from(spans));
+        }
+
+        if branches.len() > per_page {
+            let pages = branches.len().div_ceil(per_page);
+            history_lines.push(Line::from(vec![
+                "<Tab>".fg(palette.accent).bold(),
+                format!(" for more branches ({}/{})", page + 1, pages).into(),
+            ]));
+        }
+
+        history_lines.push(Line::from(vec!["".into()]));
+
+        if checkpoints.is_empty() {
+            history_lines.push(Line::from(vec!["No checkpoints yet".into()]));
+        } else {
+            history_lines.push(Line::from(vec!["Jump back to:".into()]));
+
+            for (key, checkpoint) in actions.history_checkpoints.iter().zip(checkpoints) {
+                history_lines.push(Line::from(vec![
+                    format!("<{}>", key).fg(palette.accent).bold(),
+                    format!(" : {:>3} {}", checkpoint.length, checkpoint.name).into(),
+                ]));
+            }
+        }
+
+        history_lines.push(Line::from(vec!["".into()]));
+
+        match name {
+            None if checkpoints.len() >= MAX_CHECKPOINTS => history_lines.push(Line::from(vec![
+                format!("<{}>", actions.history_checkpoint)
+                    .fg(palette.accent)
+                    .bold(),
+                " to move a checkpoint by name".into(),
+            ])),
+            None => history_lines.push(Line::from(vec![
+                format!("<{}>", actions.history_checkpoint)
+                    .fg(palette.accent)
+                    .bold(),
+                " to set a checkpoint".into(),
+            ])),
+            Some(name) => {
+                history_lines.push(Line::from(vec![
+                    "Name: ".into(),
+                    name.to_string().bold(),
+                    "_".into(),
+                ]));
+                history_lines.push(Line::from(vec![
+                    "<Enter>".fg(palette.accent).bold(),
+                    " to set".into(),
+                ]));
+                history_lines.push(Line::from(vec![
+                    "<Esc>".fg(palette.accent).bold(),
+                    " to cancel".into(),
+                ]));
+            }
+        }
+
+        let text = Text::from(history_lines);
+
+        let area = popup_area(area);
+        Clear.render(area, buf);
+
+        block.render(area, buf);
+
+        let inner_area = area.inner(Margin {
+            horizontal: 2,
+            vertical: 1,
+        });
+
+        Paragraph::new(text).render(inner_area, buf);
+    }
+
+    /// Renders the stats modal, with the records of a deal, if any.
+    pub(crate) fn render_stats_modal(
+        area: Rect,
+        buf: &mut Buffer,
+        palette: &Palette,
+        summary: &Summary,
+        history: Option<(GameId, Vec<GameRecord>)>,
+    ) {
+        let title = Line::from(" Statistics ");
+        let instructions = Line::from(vec![" Close ".into(), "<Esc> ".fg(palette.accent).bold()]);
+        let block = Block::bordered()
+            .border_style(palette.border)
+            .title(title.centered())
+            .title_bottom(instructions.centered());
+
+        let win_rate = match summary.played {
+            0 => String::from("-"),
+            played => format!("{}%", summary.won * 100 / played),
+        };
+
+        let mut stats_lines: Vec<Line> = vec![
+            Line::from(format!("Games played:   {:>8}", summary.played)),
+            Line::from(format!("Games won:      {:>8}", summary.won)),
+            Line::from(format!("Win rate:       {:>8}", win_rate)),
+            Line::from(format!("Current streak: {:>8}", summary.current_streak)),
+            Line::from(format!("Longest streak: {:>8}", summary.longest_streak)),
+        ];
+
+        if let Some((id, records)) = history {
+            stats_lines.push(Line::from(""));
+            stats_lines.push(Line::from(vec![format!("#{}", id.0).bold(), ":".into()]));
+
+            if records.is_empty() {
+                stats_lines.push(Line::from("No results yet"));
+            }
+
+            for record in records {
+                let result = if record.won {
+                    format!("won in {}", duration_text(record.seconds))
+                } else {
+                    String::from("abandoned")
+                };
+
+                stats_lines.push(Line::from(format!(
+                    "{}  {}",
+                    date_text(record.ended),
+                    result
+                )));
+                stats_lines.push(Line::from(format!(
+                    "  {}, {}",
+                    moves_text(record.moves),
+                    reverts_text(record.reverts)
+                )));
+            }
+        }
+
+        let text = Text::from(stats_lines);
+
+        let area = popup_area(area);
+        Clear.render(area, buf);
+
+        block.render(area, buf);
+
+        let inner_area = area.inner(Margin {
+            horizontal: 2,
+            vertical: 1,
+        });
+
+        Paragraph::new(text).render(inner_area, buf);
+    }
+
+    /// Renders the resume modal.
+    pub(crate) fn render_resume_modal(
+        actions: &ActionKeys,
+        area: Rect,
+        buf: &mut Buffer,
+        palette: &Palette,
+        saved_game: &SavedGame,
+    ) {
+        let title = Line::from(" Resume ");
+        let instructions = Line::from(vec![
+            " Quit ".into(),
+            format!("<{}> ", actions.quit).fg(palette.accent).bold(),
+        ]);
+        let block = Block::bordered()
+            .border_style(palette.border)
+            .title(title.centered())
+            .title_bottom(instructions.centered());
+
+        let resume_lines: Vec<Line> = vec![
+            Line::from(vec!["Resume the saved game".into()]),
+            Line::from(vec![
+                format!("#{}", saved_game.id.0).bold(),
+                format!(" after {}?", moves_text(saved_game.length())).into(),
+            ]),
+            Line::from(vec!["".into()]),
+            Line::from(vec![
+                format!("<{}>", actions.resume_yes)
+                    .fg(palette.accent)
+                    .bold(),
+                " to resume".into(),
+            ]),
+            Line::from(vec![
+                format!("<{}>", actions.resume_no).fg(palette.accent).bold(),
+                " to discard it".into(),
+            ]),
+        ];
+
+        let text = Text::from(resume_lines);
+
+        let area = popup_area(area);
+        Clear.render(area, buf);
+
+        block.render(area, buf);
+
+        let inner_area = area.inner(Margin {
+            horizontal: 2,
+            vertical: 1,
+        });
+
+        Paragraph::new(text).render(inner_area, buf);
+    }
+
+    /// Renders the error modal.
+    pub(crate) fn render_error_modal(
+        area: Rect,
+        buf: &mut Buffer,
+        palette: &Palette,
+        messages: &[String],
+    ) {
+        let title = Line::from(" Error ");
+        let instructions = Line::from(vec![
+            " Continue ".into(),
+            "<Enter> ".fg(palette.accent).bold(),
+        ]);
+        let block = Block::bordered()
+            .border_style(palette.border)
+            .title(title.centered())
+            .title_bottom(instructions.centered());
+
+        let mut error_lines: Vec<Line> = vec![];
+        for message in messages {
+            if !error_lines.is_empty() {
+                error_lines.push(Line::from(""));
+            }
+            error_lines.push(Line::from(message.as_str()));
+        }
+
+        let text = Text::from(error_lines);
+
+        let area = popup_area(area);
+        Clear.render(area, buf);
+
+        block.render(area, buf);
+
+        let inner_area = area.inner(Margin {
+            horizontal: 2,
+            vertical: 1,
+        });
+
+        Paragraph::new(text)
+            .wrap(Wrap { trim: false })
+            .render(inner_area, buf);
+    }
+
+    /// Helper function to describe a number of moves.
+    fn moves_text(count: usize) -> String {
+        match count {
+            1 => String::from("1 move"),
+            _ => format!("{} moves", count),
+        }
+    }
+
+    /// Describes the time played and the moves made, as in `4:12  87 moves, 30 auto`.
+    pub(crate) fn progress_text(elapsed: Duration, moves: usize, automoves: usize) -> String {
+        format!(
+            "{}  {}, {} auto",
+            duration_text(elapsed.as_secs()),
+            moves_text(moves),
+            automoves
+        )
+    }
+
+    /// Helper function to describe a number of reverts.
+    fn reverts_text(count: usize) -> String {
+        match count {
+            1 => String::from("1 revert"),
+            _ => format!("{} reverts", count),
+        }
+    }
+
+    /// Helper function to describe a duration given in seconds, as in `1:05:09` or `5:09`.
+    pub(crate) fn duration_text(seconds: u64) -> String {
+        let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+
+        match hours {
+            0 => format!("{}:{:02}", minutes, seconds),
+            _ => format!("{}:{:02}:{:02}", hours, minutes, seconds),
+        }
+    }
+
+    /// Helper function to describe the (UTC) date of a time given in seconds
+    /// since the Unix epoch, as in `2025-03-14`.
+    pub(crate) fn date_text(seconds: u64) -> String {
+        // compare Howard Hinnant's `civil_from_days`, shifted to years starting in March
+        let days = seconds / 86_400 + 719_468;
+        let era = days / 146_097;
+        let day_of_era = days % 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = era * 400 + year_of_era + if month <= 2 { 1 } else { 0 };
+
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+
     /// Helper function to create a centered rect with a fixed margin.
     fn popup_area(area: Rect) -> Rect {
         Rect {
@@ -866,24 +2541,41 @@ mod render {
         }
     }
 
-    /// Gets the colored representation of a card.
-    fn get_colored_representation(card: &Card) -> Span<'_> {
-        let unstyled_span = <Span<'_>>::from(format!(" {c} ", c = card.to_string()));
-        match card.suit {
-            Suit::Clubs | Suit::Spades => return unstyled_span.into(),
-            Suit::Diamonds | Suit::Hearts => return unstyled_span.red(),
-        }
+    /// Gets the colored representation of a card, with letters for the suits if `ascii`.
+    fn get_colored_representation<'a>(
+        card: &Card,
+        layout: BoardLayout,
+        palette: &Palette,
+        ascii: bool,
+    ) -> Span<'a> {
+        let text = if ascii {
+            format!("{:#}", card)
+        } else {
+            card.to_string()
+        };
+        let unstyled_span = <Span<'_>>::from(format!(
+            " {c:<width$}",
+            c = text,
+            width = layout.slot() as usize - 1
+        ));
+        let color = match card.suit {
+            Suit::Clubs => palette.clubs,
+            Suit::Diamonds => palette.diamonds,
+            Suit::Hearts => palette.hearts,
+            Suit::Spades => palette.spades,
+        };
+        unstyled_span.fg(color)
     }
 
     /// Creates the about text.
-    fn create_about_text<'a>() -> Vec<Line<'a>> {
+    fn create_about_text<'a>(actions: &ActionKeys, palette: &Palette) -> Vec<Line<'a>> {
         vec![
                 Line::from(vec!["RSLibreCell - a FreeCell implementation".bold()]),
                 Line::from(vec!["".into()]),
                 Line::from(vec!["Copyright (c) tristhaus 2025 and later".into()]),
                 Line::from(vec!["https://www.github.com/tristhaus/rslibrecell".into()]),
                 Line::from(vec!["".into()]),
-                Line::from(vec!["For help, press ".into(), "<F1>".blue(), ".".into()]),
+                Line::from(vec!["For help, press ".into(), format!("<{}>", actions.help).fg(palette.accent), ".".into()]),
                 Line::from(vec!["".into()]),
                 Line::from(vec!["RSLibreCell is free, libre, open-source software. License text below:".into()]),
                 Line::from(vec!["".into()]),
@@ -19,8 +19,15 @@
 use super::*;
 use crossterm::event::KeyModifiers;
 use mockall::predicate;
-use ratatui::style::Style;
-use rslibrecell::journey_handler::journey_repository::MockPersistJourney;
+use ratatui::style::{Color, Modifier, Style};
+use rslibrecell::{
+    config_repository::{ActionKeys, MockPersistKeyConfig, Theme},
+    game::Game,
+    game_handler::game_repository::MockPersistGame,
+    journey_handler::journey_repository::MockPersistJourney,
+    stats_handler::stats_repository::MockPersistStats,
+};
+use std::{io::ErrorKind, path::PathBuf};
 
 #[test]
 fn render_startup() {
@@ -67,21 +74,148 @@ fn render_startup() {
 }
 
 #[test]
-#[should_panic]
-fn render_too_narrow_should_panic() {
+fn render_too_narrow_shows_note() {
     let mut app = helper::setup_app();
-    let mut buf = Buffer::empty(Rect::new(0, 0, 31, 24));
+    let mut buf = Buffer::empty(Rect::new(0, 0, 27, 24));
 
     app.render(buf.area, &mut buf);
+
+    let mut expected = Buffer::with_lines(vec![
+        "    Terminal too small     ",
+        "  Please resize to 28x24   ",
+    ]);
+    expected.resize(buf.area);
+    expected.set_style(Rect::new(4, 0, 18, 1), Style::new().bold());
+
+    assert_eq!(buf, expected);
+    assert_eq!(None, render::location_at(buf.area, 4, 4));
 }
 
 #[test]
-#[should_panic]
-fn render_too_flat_should_panic() {
+fn render_too_flat_shows_note() {
     let mut app = helper::setup_app();
+    app.game_from_numeric_id(GameId(1));
     let mut buf = Buffer::empty(Rect::new(0, 0, 32, 23));
 
     app.render(buf.area, &mut buf);
+
+    let mut expected = Buffer::with_lines(vec![
+        "       Terminal too small       ",
+        "     Please resize to 28x24     ",
+    ]);
+    expected.resize(buf.area);
+    expected.set_style(Rect::new(7, 0, 18, 1), Style::new().bold());
+
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn render_compact_game() {
+    let mut app = helper::setup_app();
+    app.game_from_numeric_id(GameId(1));
+    app.handle_key_event(KeyCode::Char('a').into());
+    app.handle_key_event(KeyCode::Char('q').into());
+    let mut buf = Buffer::empty(Rect::new(0, 0, 28, 24));
+
+    app.render(buf.area, &mut buf);
+
+    assert_eq!(
+        vec![
+            "┏━━━━━━ RSLibreCell ━━━━━━━┓",
+            "┃ 0:00  1 move, 0 au    #1 ┃",
+            "┃ 6♠ .. .. .. | .. .. .. ..┃",
+            "┃--------------------------┃",
+            "┃  J♦ 2♦ 9♥ J♣ 5♦ 7♥ 7♣ 5♥ ┃",
+            "┃  K♦ K♣ 9♠ 5♠ A♦ Q♣ K♥ 3♥ ┃",
+            "┃  2♠ K♠ 9♦ Q♦ J♠ A♠ A♥ 3♣ ┃",
+            "┃  4♣ 5♣ T♠ Q♥ 4♥ A♣ 4♦ 7♠ ┃",
+            "┃  3♠ T♦ 4♠ T♥ 8♥ 2♣ J♥ 7♦ ┃",
+            "┃  6♦ 8♠ 8♦ Q♠ 6♣ 3♦ 8♣ T♣ ┃",
+            "┃     9♣ 2♥ 6♥             ┃",
+        ],
+        helper::lines(&buf)[..11]
+    );
+    assert!(buf[(2, 4)].fg == Color::Red);
+    assert!(buf[(5, 5)].fg == Color::Reset);
+
+    // the compact board is hit-tested as well
+    helper::click(&mut app, 5, 10);
+    helper::click(&mut app, 5, 2);
+    assert!(app.game_handler.game.as_ref().unwrap().cells[1].is_some());
+}
+
+#[test]
+fn render_wide_game_with_side_panels() {
+    let mut app = helper::setup_app();
+    app.game_from_numeric_id(GameId(1));
+    app.handle_key_event(KeyCode::Char('a').into());
+    app.handle_key_event(KeyCode::Char('q').into());
+    let mut buf = Buffer::empty(Rect::new(0, 0, 80, 24));
+
+    app.render(buf.area, &mut buf);
+
+    assert_eq!(
+        vec![
+            "┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━ RSLibreCell ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓",
+            "┃ ┌───── Moves ──────┐  0:00  1 move, 0 auto          #1  ┌─── Statistics ───┐ ┃",
+            "┃ │   1. 1a          │  6♠  ..  ..  .. || ..  ..  ..  ..  │ Played:        0 │ ┃",
+            "┃ │                  │ ---------------------------------- │ Won:           0 │ ┃",
+            "┃ │                  │   J♦  2♦  9♥  J♣  5♦  7♥  7♣  5♥   │ Win rate:      - │ ┃",
+            "┃ │                  │   K♦  K♣  9♠  5♠  A♦  Q♣  K♥  3♥   │ Streak:        0 │ ┃",
+            "┃ │                  │   2♠  K♠  9♦  Q♦  J♠  A♠  A♥  3♣   │ Best:          0 │ ┃",
+        ],
+        helper::lines(&buf)[..7]
+    );
+    assert_eq!(
+        "┃ └──────────────────┘                                    └──────────────────┘ ┃",
+        helper::lines(&buf)[22]
+    );
+
+    // without a game, there are no side panels
+    let mut app = helper::setup_app();
+    let mut buf = Buffer::empty(Rect::new(0, 0, 80, 24));
+    app.render(buf.area, &mut buf);
+
+    assert_eq!(
+        "┃                                                                              ┃",
+        helper::lines(&buf)[1]
+    );
+}
+
+#[test]
+fn render_side_panels_show_most_recent_moves() {
+    let moves: Vec<Move> = ["1a", "2b", "3c", "4d"]
+        .iter()
+        .map(|x| Move::try_from(*x).unwrap())
+        .collect();
+    let summary = Summary {
+        played: 3,
+        won: 2,
+        current_streak: 1,
+        longest_streak: 2,
+    };
+    let mut buf = Buffer::empty(Rect::new(0, 0, 80, 6));
+
+    render::render_side_panels(
+        buf.area,
+        &mut buf,
+        &moves,
+        &summary,
+        &render::Palette::of(Theme::Dark),
+    );
+
+    // only the two most recent moves fit
+    assert_eq!(
+        vec![
+            "                                                                                ",
+            "  ┌───── Moves ──────┐                                    ┌─── Statistics ───┐  ",
+            "  │   3. 3c          │                                    │ Played:        3 │  ",
+            "  │   4. 4d          │                                    │ Won:           2 │  ",
+            "  └──────────────────┘                                    └──────────────────┘  ",
+            "                                                                                ",
+        ],
+        helper::lines(&buf)
+    );
 }
 
 #[test]
@@ -159,7 +293,7 @@ fn render_fixed_game() {
 
     let mut expected = Buffer::with_lines(vec![
         "┏━━━━━━━━━━━━━━━━━ RSLibreCell ━━━━━━━━━━━━━━━━━━┓",
-        "┃                                      #1        ┃",
+        "┃        0:00  0 moves, 0 auto         #1        ┃",
         "┃        ..  ..  ..  .. || ..  ..  ..  ..        ┃",
         "┃       ----------------------------------       ┃",
         "┃         J♦  2♦  9♥  J♣  5♦  7♥  7♣  5♥         ┃",
@@ -245,7 +379,7 @@ fn render_fixed_game_use_game_keys() {
 
     let mut expected = Buffer::with_lines(vec![
         "┏━━━━━━━━━━━━━━━━━ RSLibreCell ━━━━━━━━━━━━━━━━━━┓",
-        "┃                                      #1        ┃",
+        "┃        0:00  2 moves, 3 auto         #1        ┃",
         "┃        3♦  ..  ..  .. || 2♣  A♠  ..  ..        ┃",
         "┃       ----------------------------------       ┃",
         "┃         J♦  2♦  9♥  J♣  5♦  7♥  7♣  5♥         ┃",
@@ -319,7 +453,7 @@ fn render_fixed_game_use_game_keys() {
 
     let mut expected = Buffer::with_lines(vec![
         "┏━━━━━━━━━━━━━━━━━ RSLibreCell ━━━━━━━━━━━━━━━━━━┓",
-        "┃                                      #1        ┃",
+        "┃        0:00  3 moves, 3 auto         #1        ┃",
         "┃        3♦  6♠  ..  .. || 2♣  A♠  ..  ..        ┃",
         "┃       ----------------------------------       ┃",
         "┃         J♦  2♦  9♥  J♣  5♦  7♥  7♣  5♥         ┃",
@@ -390,7 +524,7 @@ fn render_fixed_game_use_game_keys() {
 
     let mut expected = Buffer::with_lines(vec![
         "┏━━━━━━━━━━━━━━━━━ RSLibreCell ━━━━━━━━━━━━━━━━━━┓",
-        "┃                                      #1        ┃",
+        "┃        0:00  2 moves, 3 auto         #1        ┃",
         "┃        3♦  ..  ..  .. || 2♣  A♠  ..  ..        ┃",
         "┃       ----------------------------------       ┃",
         "┃         J♦  2♦  9♥  J♣  5♦  7♥  7♣  5♥         ┃",
@@ -453,6 +587,112 @@ fn render_fixed_game_use_game_keys() {
     assert_eq!(buf, expected);
 }
 
+#[test]
+fn render_fixed_game_with_hint() {
+    let mut app = helper::setup_app();
+    let mut buf = Buffer::empty(Rect::new(0, 0, 50, 24));
+    app.game_from_numeric_id(GameId(1));
+
+    app.handle_key_event(KeyCode::Char('h').into());
+    helper::wait_for_hint(&mut app);
+
+    app.render(buf.area, &mut buf);
+
+    let mut expected = Buffer::with_lines(vec![
+        "┏━━━━━━━━━━━━━━━━━ RSLibreCell ━━━━━━━━━━━━━━━━━━┓",
+        "┃        Hint: <k> then <q>            #1        ┃",
+        "┃        ..  ..  ..  .. || ..  ..  ..  ..        ┃",
+        "┃       ----------------------------------       ┃",
+        "┃         J♦  2♦  9♥  J♣  5♦  7♥  7♣  5♥         ┃",
+        "┃         K♦  K♣  9♠  5♠  A♦  Q♣  K♥  3♥         ┃",
+        "┃         2♠  K♠  9♦  Q♦  J♠  A♠  A♥  3♣         ┃",
+        "┃         4♣  5♣  T♠  Q♥  4♥  A♣  4♦  7♠         ┃",
+        "┃         3♠  T♦  4♠  T♥  8♥  2♣  J♥  7♦         ┃",
+        "┃         6♦  8♠  8♦  Q♠  6♣  3♦  8♣  T♣         ┃",
+        "┃         6♠  9♣  2♥  6♥                         ┃",
+        "┃                                                ┃",
+        "┃                                                ┃",
+        "┃                                                ┃",
+        "┃                                                ┃",
+        "┃                                                ┃",
+        "┃                                                ┃",
+        "┃                                                ┃",
+        "┃                                                ┃",
+        "┃                                                ┃",
+        "┃                                                ┃",
+        "┃                                                ┃",
+        "┃                                                ┃",
+        "┗━━━━━━━━━━━ Help <F1> Quit <CTRL-q> ━━━━━━━━━━━━┛",
+    ]);
+    {
+        let title_style = Style::new().bold();
+        let key_style = Style::new().blue().bold();
+        expected.set_style(Rect::new(18, 0, 13, 1), title_style);
+        expected.set_style(Rect::new(18, 23, 4, 1), key_style);
+        expected.set_style(Rect::new(28, 23, 9, 1), key_style);
+
+        let red_style = Style::new().red();
+        expected.set_style(Rect::new(9, 4, 4, 1), red_style);
+        expected.set_style(Rect::new(13, 4, 4, 1), red_style);
+        expected.set_style(Rect::new(17, 4, 4, 1), red_style);
+        expected.set_style(Rect::new(25, 4, 4, 1), red_style);
+        expected.set_style(Rect::new(29, 4, 4, 1), red_style);
+        expected.set_style(Rect::new(37, 4, 4, 1), red_style);
+        expected.set_style(Rect::new(9, 5, 4, 1), red_style);
+        expected.set_style(Rect::new(25, 5, 4, 1), red_style);
+        expected.set_style(Rect::new(33, 5, 4, 1), red_style);
+        expected.set_style(Rect::new(37, 5, 4, 1), red_style);
+        expected.set_style(Rect::new(17, 6, 4, 1), red_style);
+        expected.set_style(Rect::new(21, 6, 4, 1), red_style);
+        expected.set_style(Rect::new(33, 6, 4, 1), red_style);
+        expected.set_style(Rect::new(21, 7, 4, 1), red_style);
+        expected.set_style(Rect::new(25, 7, 4, 1), red_style);
+        expected.set_style(Rect::new(33, 7, 4, 1), red_style);
+        expected.set_style(Rect::new(13, 8, 4, 1), red_style);
+        expected.set_style(Rect::new(21, 8, 4, 1), red_style);
+        expected.set_style(Rect::new(25, 8, 4, 1), red_style);
+        expected.set_style(Rect::new(33, 8, 4, 1), red_style);
+        expected.set_style(Rect::new(37, 8, 4, 1), red_style);
+        expected.set_style(Rect::new(9, 9, 4, 1), red_style);
+        expected.set_style(Rect::new(17, 9, 4, 1), red_style);
+        expected.set_style(Rect::new(29, 9, 4, 1), red_style);
+        expected.set_style(Rect::new(17, 10, 4, 1), red_style);
+        expected.set_style(Rect::new(21, 10, 4, 1), red_style);
+
+        let highlight_style = Style::new().reversed();
+        expected.set_style(Rect::new(8, 2, 4, 1), highlight_style);
+        expected.set_style(Rect::new(29, 9, 4, 1), highlight_style);
+    }
+
+    assert_eq!(buf, expected);
+
+    app.handle_key_event(KeyCode::Char('k').into());
+    app.handle_key_event(KeyCode::Char('q').into());
+
+    assert!(app.hint.is_none());
+}
+
+#[test]
+fn rejected_move_is_explained_in_status() {
+    let mut app = helper::setup_app();
+    app.game_from_numeric_id(GameId(1));
+
+    app.handle_key_event(KeyCode::Char('a').into());
+    app.handle_key_event(KeyCode::Char('s').into());
+
+    assert_eq!(Some(MoveError::RankMismatch), app.move_error);
+    assert_eq!("Ranks do not match", app.status());
+
+    app.handle_key_event(KeyCode::Char('R').into());
+
+    assert_eq!("Nothing to revert", app.status());
+
+    app.handle_key_event(KeyCode::Char('a').into());
+
+    assert!(app.move_error.is_none());
+    assert_eq!("0:00  0 moves, 0 auto", app.status());
+}
+
 #[test]
 fn render_fixed_won_game() {
     let mut app = helper::setup_app();
@@ -562,7 +802,7 @@ fn render_fixed_won_game() {
 
     let mut expected = Buffer::with_lines(vec![
         "┏━━━━━━━━━━━━━━━━━ RSLibreCell ━━━━━━━━━━━━━━━━━━┓",
-        "┃                                    #100        ┃",
+        "┃        0:00  31 moves, 51 auto     #100        ┃",
         "┃        ..  ..  ..  .. || K♣  K♠  K♥  K♦        ┃",
         "┃       ----------------------------------       ┃",
         "┃                                                ┃",
@@ -646,13 +886,22 @@ fn render_help_modal() {
         column6: ',',
         column7: '.',
         column8: '-',
+        actions: ActionKeys::default(),
+        theme: Theme::Dark,
+        ascii: false,
     };
 
     let mut mock = MockPersistJourney::new();
+    mock.expect_check_lock().return_const(Ok(()));
     mock.expect_read()
-        .return_const((GameId(123), vec![GameId(117), GameId(118)]));
-    mock.expect_write().return_const(());
-    let mut app = App::new(custom_key_config, mock);
+        .return_const(Ok((GameId(123), vec![GameId(117), GameId(118)])));
+    mock.expect_write().return_const(Ok(()));
+    let mut app = App::new(
+        helper::key_config_repository(Ok(custom_key_config)),
+        mock,
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
 
     let mut buf = Buffer::empty(Rect::new(0, 0, 50, 24));
     app.handle_key_event(KeyCode::F(1).into());
@@ -666,6 +915,9 @@ fn render_help_modal() {
         "┃ │ <F2> to start a new random game.           │ ┃",
         "┃ │ <F3> to choose a game to start.            │ ┃",
         "┃ │ <!> to open the Journey box.               │ ┃",
+        "┃ │ <F4> to open the History box.              │ ┃",
+        "┃ │ <F5> to open the Statistics box.           │ ┃",
+        "┃ │ <F6> to open the Settings box.             │ ┃",
         "┃ │                                            │ ┃",
         "┃ │ <a> <s> <d> <f> - cells                    │ ┃",
         "┃ │ <j> <k> <l> <ö> - foundations              │ ┃",
@@ -674,11 +926,8 @@ fn render_help_modal() {
         "┃ │                                            │ ┃",
         "┃ │ Make a move by choosing the start and end  │ ┃",
         "┃ │ of a move. <Space> to abort a move. <R> to │ ┃",
-        "┃ │ revert the last move.                      │ ┃",
-        "┃ │                                            │ ┃",
-        "┃ │                                            │ ┃",
-        "┃ │                                            │ ┃",
-        "┃ │                                            │ ┃",
+        "┃ │ revert the last move. <Y> to redo it. <h>  │ ┃",
+        "┃ │ to get a hint.                             │ ┃",
         "┃ │                                            │ ┃",
         "┃ │                                            │ ┃",
         "┃ │                                            │ ┃",
@@ -695,14 +944,19 @@ fn render_help_modal() {
         expected.set_style(Rect::new(4, 3, 4, 1), inactive_key_style);
         expected.set_style(Rect::new(4, 4, 4, 1), inactive_key_style);
         expected.set_style(Rect::new(4, 5, 3, 1), inactive_key_style);
+        expected.set_style(Rect::new(4, 6, 4, 1), inactive_key_style);
+        expected.set_style(Rect::new(4, 7, 4, 1), inactive_key_style);
+        expected.set_style(Rect::new(4, 8, 4, 1), inactive_key_style);
 
-        expected.set_style(Rect::new(4, 7, 15, 1), inactive_key_style);
-        expected.set_style(Rect::new(4, 8, 15, 1), inactive_key_style);
-        expected.set_style(Rect::new(4, 9, 15, 1), inactive_key_style);
         expected.set_style(Rect::new(4, 10, 15, 1), inactive_key_style);
+        expected.set_style(Rect::new(4, 11, 15, 1), inactive_key_style);
+        expected.set_style(Rect::new(4, 12, 15, 1), inactive_key_style);
+        expected.set_style(Rect::new(4, 13, 15, 1), inactive_key_style);
 
-        expected.set_style(Rect::new(15, 13, 7, 1), inactive_key_style);
-        expected.set_style(Rect::new(40, 13, 3, 1), inactive_key_style);
+        expected.set_style(Rect::new(15, 16, 7, 1), inactive_key_style);
+        expected.set_style(Rect::new(40, 16, 3, 1), inactive_key_style);
+        expected.set_style(Rect::new(26, 17, 3, 1), inactive_key_style);
+        expected.set_style(Rect::new(42, 17, 3, 1), inactive_key_style);
 
         expected.set_style(Rect::new(25, 22, 6, 1), key_style_bold);
         expected.set_style(Rect::new(18, 23, 4, 1), key_style_bold);
@@ -927,7 +1181,7 @@ fn render_about_modal() {
 #[test]
 fn handle_key_event_selection_id_modal_valid() {
     const EMPTY_ENTRY_STATE: AppState = AppState::SelectionIdModal {
-        id: [SPACE_ASCII_CODE; 5],
+        id: [SPACE_ASCII_CODE; ID_LENGTH],
     };
 
     let mut app = helper::setup_app();
@@ -943,13 +1197,7 @@ fn handle_key_event_selection_id_modal_valid() {
     assert_eq!(
         app.app_state,
         AppState::SelectionIdModal {
-            id: [
-                SPACE_ASCII_CODE,
-                SPACE_ASCII_CODE,
-                SPACE_ASCII_CODE,
-                0x31,
-                0x39
-            ]
+            id: helper::id_entry("19")
         }
     );
 
@@ -957,13 +1205,7 @@ fn handle_key_event_selection_id_modal_valid() {
     assert_eq!(
         app.app_state,
         AppState::SelectionIdModal {
-            id: [
-                SPACE_ASCII_CODE,
-                SPACE_ASCII_CODE,
-                SPACE_ASCII_CODE,
-                SPACE_ASCII_CODE,
-                0x31
-            ]
+            id: helper::id_entry("1")
         }
     );
 
@@ -972,7 +1214,7 @@ fn handle_key_event_selection_id_modal_valid() {
     assert_eq!(
         app.app_state,
         AppState::SelectionIdModal {
-            id: [SPACE_ASCII_CODE, SPACE_ASCII_CODE, 0x31, 0x32, 0x33]
+            id: helper::id_entry("123")
         }
     );
 
@@ -980,6 +1222,18 @@ fn handle_key_event_selection_id_modal_valid() {
     assert_eq!(app.app_state, AppState::Base);
     assert_eq!(GameId(123), app.game_handler.game.as_ref().unwrap().id);
 
+    // ids beyond the original 64000 games are valid
+    app.handle_key_event(KeyCode::F(3).into());
+    for key in "8589934591".chars() {
+        app.handle_key_event(KeyCode::Char(key).into());
+    }
+    app.handle_key_event(KeyCode::Enter.into());
+    assert_eq!(app.app_state, AppState::Base);
+    assert_eq!(
+        GameId(8_589_934_591),
+        app.game_handler.game.as_ref().unwrap().id
+    );
+
     app.handle_key_event(KeyCode::F(3).into());
     assert_eq!(app.app_state, EMPTY_ENTRY_STATE);
 
@@ -993,7 +1247,7 @@ fn handle_key_event_selection_id_modal_valid() {
 #[test]
 fn handle_key_event_selection_id_modal_invalid() {
     const EMPTY_ENTRY_STATE: AppState = AppState::SelectionIdModal {
-        id: [SPACE_ASCII_CODE; 5],
+        id: [SPACE_ASCII_CODE; ID_LENGTH],
     };
 
     let mut app = helper::setup_app();
@@ -1015,26 +1269,14 @@ fn handle_key_event_selection_id_modal_invalid() {
     assert_eq!(
         app.app_state,
         AppState::SelectionIdModal {
-            id: [
-                SPACE_ASCII_CODE,
-                SPACE_ASCII_CODE,
-                SPACE_ASCII_CODE,
-                SPACE_ASCII_CODE,
-                0x30
-            ]
+            id: helper::id_entry("0")
         }
     );
     app.handle_key_event(KeyCode::Enter.into());
     assert_eq!(
         app.app_state,
         AppState::SelectionIdModal {
-            id: [
-                SPACE_ASCII_CODE,
-                SPACE_ASCII_CODE,
-                SPACE_ASCII_CODE,
-                SPACE_ASCII_CODE,
-                0x30
-            ]
+            id: helper::id_entry("0")
         }
     );
 
@@ -1042,23 +1284,21 @@ fn handle_key_event_selection_id_modal_invalid() {
     app.handle_key_event(KeyCode::Esc.into());
     app.handle_key_event(KeyCode::F(3).into());
 
-    // id 64001 is invalid
-    app.handle_key_event(KeyCode::Char('6').into());
-    app.handle_key_event(KeyCode::Char('4').into());
-    app.handle_key_event(KeyCode::Char('0').into());
-    app.handle_key_event(KeyCode::Char('0').into());
-    app.handle_key_event(KeyCode::Char('1').into());
+    // id 8589934592 is invalid
+    for key in "8589934592".chars() {
+        app.handle_key_event(KeyCode::Char(key).into());
+    }
     assert_eq!(
         app.app_state,
         AppState::SelectionIdModal {
-            id: [0x36, 0x34, 0x30, 0x30, 0x31]
+            id: helper::id_entry("8589934592")
         }
     );
     app.handle_key_event(KeyCode::Enter.into());
     assert_eq!(
         app.app_state,
         AppState::SelectionIdModal {
-            id: [0x36, 0x34, 0x30, 0x30, 0x31]
+            id: helper::id_entry("8589934592")
         }
     );
 
@@ -1066,23 +1306,21 @@ fn handle_key_event_selection_id_modal_invalid() {
     app.handle_key_event(KeyCode::Esc.into());
     app.handle_key_event(KeyCode::F(3).into());
 
-    // id outside of u16 does not panic
-    app.handle_key_event(KeyCode::Char('7').into());
-    app.handle_key_event(KeyCode::Char('0').into());
-    app.handle_key_event(KeyCode::Char('7').into());
-    app.handle_key_event(KeyCode::Char('8').into());
-    app.handle_key_event(KeyCode::Char('9').into());
+    // id with all digits entered does not panic, further digits are ignored
+    for key in "99999999999".chars() {
+        app.handle_key_event(KeyCode::Char(key).into());
+    }
     assert_eq!(
         app.app_state,
         AppState::SelectionIdModal {
-            id: [0x37, 0x30, 0x37, 0x38, 0x39]
+            id: helper::id_entry("9999999999")
         }
     );
     app.handle_key_event(KeyCode::Enter.into());
     assert_eq!(
         app.app_state,
         AppState::SelectionIdModal {
-            id: [0x37, 0x30, 0x37, 0x38, 0x39]
+            id: helper::id_entry("9999999999")
         }
     );
 }
@@ -1127,7 +1365,7 @@ fn render_selection_id_modal_valid() {
         let input_style = Style::new().underlined();
         expected.set_style(Rect::new(9, 0, 13, 1), title_style);
 
-        expected.set_style(Rect::new(14, 4, 5, 1), input_style);
+        expected.set_style(Rect::new(11, 4, 10, 1), input_style);
 
         expected.set_style(Rect::new(10, 22, 7, 1), key_style_bold);
         expected.set_style(Rect::new(24, 22, 5, 1), key_style_bold);
@@ -1148,7 +1386,7 @@ fn render_selection_id_modal_valid() {
         "┃ ┌─── Choose game by ID ────┐ ┃",
         "┃ │                          │ ┃",
         "┃ │         Enter ID:        │ ┃",
-        "┃ │             123          │ ┃",
+        "┃ │               123        │ ┃",
         "┃ │                          │ ┃",
         "┃ │                          │ ┃",
         "┃ │                          │ ┃",
@@ -1175,7 +1413,7 @@ fn render_selection_id_modal_valid() {
         let input_style = Style::new().underlined();
         expected.set_style(Rect::new(9, 0, 13, 1), title_style);
 
-        expected.set_style(Rect::new(14, 4, 5, 1), input_style);
+        expected.set_style(Rect::new(11, 4, 10, 1), input_style);
 
         expected.set_style(Rect::new(10, 22, 7, 1), key_style_bold);
         expected.set_style(Rect::new(24, 22, 5, 1), key_style_bold);
@@ -1196,7 +1434,7 @@ fn render_selection_id_modal_valid() {
         "┃ ┌─── Choose game by ID ────┐ ┃",
         "┃ │                          │ ┃",
         "┃ │         Enter ID:        │ ┃",
-        "┃ │           12398          │ ┃",
+        "┃ │            123987        │ ┃",
         "┃ │                          │ ┃",
         "┃ │                          │ ┃",
         "┃ │                          │ ┃",
@@ -1223,7 +1461,7 @@ fn render_selection_id_modal_valid() {
         let input_style = Style::new().underlined();
         expected.set_style(Rect::new(9, 0, 13, 1), title_style);
 
-        expected.set_style(Rect::new(14, 4, 5, 1), input_style);
+        expected.set_style(Rect::new(11, 4, 10, 1), input_style);
 
         expected.set_style(Rect::new(10, 22, 7, 1), key_style_bold);
         expected.set_style(Rect::new(24, 22, 5, 1), key_style_bold);
@@ -1248,7 +1486,7 @@ fn render_selection_id_modal_invalid() {
         "┃ ┌─── Choose game by ID ────┐ ┃",
         "┃ │                          │ ┃",
         "┃ │         Enter ID:        │ ┃",
-        "┃ │               0          │ ┃",
+        "┃ │                 0        │ ┃",
         "┃ │                          │ ┃",
         "┃ │                          │ ┃",
         "┃ │                          │ ┃",
@@ -1275,7 +1513,7 @@ fn render_selection_id_modal_invalid() {
         let input_style_invalud = Style::new().underlined().red();
         expected.set_style(Rect::new(9, 0, 13, 1), title_style);
 
-        expected.set_style(Rect::new(14, 4, 5, 1), input_style_invalud);
+        expected.set_style(Rect::new(11, 4, 10, 1), input_style_invalud);
 
         expected.set_style(Rect::new(10, 22, 7, 1), key_style_bold);
         expected.set_style(Rect::new(24, 22, 5, 1), key_style_bold);
@@ -1286,11 +1524,9 @@ fn render_selection_id_modal_invalid() {
     assert_eq!(buf, expected);
 
     app.handle_key_event(KeyCode::Backspace.into());
-    app.handle_key_event(KeyCode::Char('6').into());
-    app.handle_key_event(KeyCode::Char('4').into());
-    app.handle_key_event(KeyCode::Char('0').into());
-    app.handle_key_event(KeyCode::Char('0').into());
-    app.handle_key_event(KeyCode::Char('1').into());
+    for key in "8589934592".chars() {
+        app.handle_key_event(KeyCode::Char(key).into());
+    }
 
     app.render(buf.area, &mut buf);
 
@@ -1299,7 +1535,7 @@ fn render_selection_id_modal_invalid() {
         "┃ ┌─── Choose game by ID ────┐ ┃",
         "┃ │                          │ ┃",
         "┃ │         Enter ID:        │ ┃",
-        "┃ │           64001          │ ┃",
+        "┃ │        8589934592        │ ┃",
         "┃ │                          │ ┃",
         "┃ │                          │ ┃",
         "┃ │                          │ ┃",
@@ -1326,7 +1562,7 @@ fn render_selection_id_modal_invalid() {
         let input_style_invalud = Style::new().underlined().red();
         expected.set_style(Rect::new(9, 0, 13, 1), title_style);
 
-        expected.set_style(Rect::new(14, 4, 5, 1), input_style_invalud);
+        expected.set_style(Rect::new(11, 4, 10, 1), input_style_invalud);
 
         expected.set_style(Rect::new(10, 22, 7, 1), key_style_bold);
         expected.set_style(Rect::new(24, 22, 5, 1), key_style_bold);
@@ -1340,10 +1576,16 @@ fn render_selection_id_modal_invalid() {
 #[test]
 fn handle_key_event_selection_journey_modal() {
     let mut mock = MockPersistJourney::new();
+    mock.expect_check_lock().return_const(Ok(()));
     mock.expect_read()
-        .return_const((GameId(123), vec![GameId(117), GameId(118)]));
-    mock.expect_write().return_const(());
-    let mut app = App::new(helper::get_default_key_config(), mock);
+        .return_const(Ok((GameId(123), vec![GameId(117), GameId(118)])));
+    mock.expect_write().return_const(Ok(()));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        mock,
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
 
     // open modal
     app.handle_key_event(KeyCode::Char('!').into());
@@ -1395,20 +1637,168 @@ fn handle_key_event_selection_journey_modal() {
     assert_eq!(AppState::SelectionJourneyModal, app.app_state);
 }
 
+#[test]
+fn handle_key_event_custom_action_keys() {
+    let key_config = KeyConfig {
+        actions: ActionKeys {
+            quit: KeyBinding::Ctrl('x'),
+            help: KeyBinding::Char('?'),
+            journey: KeyBinding::F(8),
+            random_game: KeyBinding::Char('n'),
+            revert: KeyBinding::Char('z'),
+            journey_next: KeyBinding::Char('N'),
+            journey_skip: KeyBinding::Char('S'),
+            journey_skipped: ['1', '2', '3', '4', '5', '6', '7', '8'].map(KeyBinding::Char),
+            ..ActionKeys::default()
+        },
+        ..helper::get_default_key_config()
+    };
+    let mut app = App::new(
+        helper::key_config_repository(Ok(key_config)),
+        helper::get_journey_repository(),
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
+
+    // the former keys are without effect
+    app.handle_key_event(KeyCode::F(1).into());
+    app.handle_key_event(KeyCode::Char('!').into());
+    app.handle_key_event(KeyCode::F(2).into());
+    assert_eq!(AppState::Base, app.app_state);
+    assert!(app.game_handler.game.is_none());
+
+    app.handle_key_event(KeyCode::Char('?').into());
+    assert_eq!(AppState::HelpModal, app.app_state);
+    app.handle_key_event(KeyCode::Esc.into());
+
+    // journey modal
+    app.handle_key_event(KeyCode::F(8).into());
+    assert_eq!(AppState::SelectionJourneyModal, app.app_state);
+    app.handle_key_event(KeyCode::Char('1').into());
+    assert_eq!(AppState::Base, app.app_state);
+    assert!(app
+        .game_handler
+        .game
+        .as_ref()
+        .is_some_and(|x| x.id == GameId(117)));
+
+    app.handle_key_event(KeyCode::F(8).into());
+    app.handle_key_event(KeyCode::Char('S').into());
+    app.handle_key_event(KeyCode::Char('N').into());
+    assert_eq!(AppState::Base, app.app_state);
+    assert!(app
+        .game_handler
+        .game
+        .as_ref()
+        .is_some_and(|x| x.id == GameId(124)));
+
+    // revert, the former key is now a plain character
+    app.handle_key_event(KeyCode::Char('a').into());
+    app.handle_key_event(KeyCode::Char('q').into());
+    assert_eq!(1, app.game_handler.move_counts().0);
+    app.handle_key_event(KeyCode::Char('R').into());
+    assert_eq!(1, app.game_handler.move_counts().0);
+    app.handle_key_event(KeyCode::Char('z').into());
+    assert_eq!(0, app.game_handler.move_counts().0);
+
+    // quit, also from a modal
+    let mut key: KeyEvent = KeyCode::Char('q').into();
+    key.modifiers = KeyModifiers::CONTROL;
+    app.handle_key_event(key);
+    assert_eq!(AppState::Base, app.app_state);
+
+    app.handle_key_event(KeyCode::Char('?').into());
+    let mut key: KeyEvent = KeyCode::Char('x').into();
+    key.modifiers = KeyModifiers::CONTROL;
+    app.handle_key_event(key);
+    assert_eq!(AppState::Exit, app.app_state);
+}
+
+#[test]
+fn render_custom_action_keys() {
+    let key_config = KeyConfig {
+        actions: ActionKeys {
+            quit: KeyBinding::Ctrl('x'),
+            help: KeyBinding::Char('?'),
+            about: KeyBinding::F(11),
+            journey: KeyBinding::F(8),
+            clear: KeyBinding::Char('c'),
+            revert: KeyBinding::Char(' '),
+            journey_next: KeyBinding::Char('N'),
+            journey_skip: KeyBinding::Char('S'),
+            ..ActionKeys::default()
+        },
+        ..helper::get_default_key_config()
+    };
+    let mut app = App::new(
+        helper::key_config_repository(Ok(key_config)),
+        helper::get_journey_repository(),
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 50, 24));
+    app.handle_key_event(KeyCode::Char('?').into());
+    app.render(buf.area, &mut buf);
+
+    let lines = helper::lines(&buf);
+    assert_eq!(
+        "┃ │ <F11> to show the About box.               │ ┃",
+        lines[2]
+    );
+    assert_eq!(
+        "┃ │ <F8> to open the Journey box.              │ ┃",
+        lines[5]
+    );
+    assert_eq!(
+        "┃ │ of a move. <c> to abort a move. <Space> to │ ┃",
+        lines[16]
+    );
+    assert_eq!(
+        "┗━━━━━━━━━━━━ Help <?> Quit <CTRL-x> ━━━━━━━━━━━━┛",
+        lines[23]
+    );
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 50, 24));
+    app.handle_key_event(KeyCode::Esc.into());
+    app.handle_key_event(KeyCode::F(8).into());
+    app.render(buf.area, &mut buf);
+
+    let lines = helper::lines(&buf);
+    assert_eq!(
+        "┃ │ <N> (next game) :   123                    │ ┃",
+        lines[3]
+    );
+    assert_eq!(
+        "┃ │ <S> to skip for now                        │ ┃",
+        lines[4]
+    );
+    assert_eq!(
+        "┃ │ <2> :   117                                │ ┃",
+        lines[7]
+    );
+}
+
 #[test]
 fn make_journey_handle_next_won_game() {
     let mut mock = MockPersistJourney::new();
+    mock.expect_check_lock().return_const(Ok(()));
     mock.expect_read()
         .once()
-        .return_const((GameId(100), vec![GameId(55), GameId(66)]));
+        .return_const(Ok((GameId(100), vec![GameId(55), GameId(66)])));
     mock.expect_write()
         .with(
             predicate::eq(GameId(101)),
             predicate::eq(vec![GameId(55), GameId(66)]),
         )
         .once()
-        .return_const(());
-    let mut app = App::new(helper::get_default_key_config(), mock);
+        .return_const(Ok(()));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        mock,
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
 
     app.selection_through_journey_start_next();
 
@@ -1515,16 +1905,22 @@ fn make_journey_handle_next_won_game() {
 #[test]
 fn make_journey_handle_skipped_won_game() {
     let mut mock = MockPersistJourney::new();
+    mock.expect_check_lock().return_const(Ok(()));
     mock.expect_read()
         .once()
-        .return_const((GameId(121), vec![GameId(44), GameId(100)]));
+        .return_const(Ok((GameId(121), vec![GameId(44), GameId(100)])));
     mock.expect_write()
         .with(predicate::eq(GameId(121)), predicate::eq(vec![GameId(44)]))
         .once()
-        .return_const(());
-    let mut app = App::new(helper::get_default_key_config(), mock);
+        .return_const(Ok(()));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        mock,
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
 
-    app.selection_through_journey_start_skipped('3');
+    app.selection_through_journey_start_skipped(1);
 
     let mut punch_key = |key: char| {
         app.handle_key_event(KeyCode::Char(key).into());
@@ -1627,11 +2023,474 @@ fn make_journey_handle_skipped_won_game() {
 }
 
 #[test]
-fn render_selection_journey_modal_no_skipped() {
-    let mut mock = MockPersistJourney::new();
-    mock.expect_read().return_const((GameId(1), vec![]));
-    mock.expect_write().return_const(());
-    let mut app = App::new(helper::get_default_key_config(), mock);
+fn handle_key_event_history_modal() {
+    let mut app = helper::setup_app();
+    app.game_from_numeric_id(GameId(123));
+
+    let mut punch_key = |key: KeyCode| {
+        app.handle_key_event(key.into());
+    };
+
+    // name a checkpoint at the initial position
+    punch_key(KeyCode::F(4));
+    punch_key(KeyCode::Char('+'));
+    for key in "startx".chars() {
+        punch_key(KeyCode::Char(key));
+    }
+    punch_key(KeyCode::Backspace);
+    punch_key(KeyCode::Char('!'));
+    punch_key(KeyCode::Enter);
+    punch_key(KeyCode::Esc);
+
+    // first branch
+    punch_key(KeyCode::Char('l'));
+    punch_key(KeyCode::Char('a'));
+    punch_key(KeyCode::Char('ö'));
+    punch_key(KeyCode::Char('w'));
+
+    // second branch
+    punch_key(KeyCode::Char('R'));
+    punch_key(KeyCode::Char('ö'));
+    punch_key(KeyCode::Char('q'));
+
+    assert_eq!(
+        vec![Checkpoint {
+            name: "start".to_string(),
+            length: 0
+        }],
+        app.game_handler.checkpoints()
+    );
+    assert_eq!(2, app.game_handler.branches().len());
+    let second_branch = app.game_handler.game.clone();
+
+    app.handle_key_event(KeyCode::F(4).into());
+    app.handle_key_event(KeyCode::Char('1').into());
+
+    assert_eq!(app.app_state, AppState::Base);
+    assert!(app.game_handler.branches()[0].active);
+
+    app.handle_key_event(KeyCode::F(4).into());
+    app.handle_key_event(KeyCode::Char('a').into());
+
+    assert_eq!(app.app_state, AppState::Base);
+    assert_eq!(Err(MoveError::NothingToRevert), app.game_handler.revert());
+
+    app.handle_key_event(KeyCode::F(4).into());
+    app.handle_key_event(KeyCode::Char('3').into());
+
+    assert_eq!(
+        app.app_state,
+        AppState::HistoryModal {
+            name: None,
+            page: 0
+        }
+    );
+
+    app.handle_key_event(KeyCode::Char('2').into());
+
+    assert_eq!(app.app_state, AppState::Base);
+    assert_eq!(second_branch, app.game_handler.game);
+}
+
+#[test]
+fn render_history_modal() {
+    let mut app = helper::setup_app();
+    app.game_from_numeric_id(GameId(123));
+
+    for key in "laöwRöq".chars() {
+        app.handle_key_event(KeyCode::Char(key).into());
+    }
+    assert!(app.game_handler.set_checkpoint("tricky").is_ok());
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 50, 24));
+    app.handle_key_event(KeyCode::F(4).into());
+    app.handle_key_event(KeyCode::Char('+').into());
+    app.handle_key_event(KeyCode::Char('x').into());
+
+    app.render(buf.area, &mut buf);
+
+    let mut expected = Buffer::with_lines(vec![
+        "┏━━━━━━━━━━━━━━━━━ RSLibreCell ━━━━━━━━━━━━━━━━━━┓",
+        "┃ ┌───────────────── History ──────────────────┐ ┃",
+        "┃ │ Switch to branch:                          │ ┃",
+        "┃ │ <1> :   2 moves, 8b                        │ ┃",
+        "┃ │ <2> :   2 moves, 8a *                      │ ┃",
+        "┃ │                                            │ ┃",
+        "┃ │ Jump back to:                              │ ┃",
+        "┃ │ <a> :   2 tricky                           │ ┃",
+        "┃ │                                            │ ┃",
+        "┃ │ Name: x_                                   │ ┃",
+        "┃ │ <Enter> to set                             │ ┃",
+        "┃ │ <Esc> to cancel                            │ ┃",
+        "┃ │                                            │ ┃",
+        "┃ │                                            │ ┃",
+        "┃ │                                            │ ┃",
+        "┃ │                                            │ ┃",
+        "┃ │                                            │ ┃",
+        "┃ │                                            │ ┃",
+        "┃ │                                            │ ┃",
+        "┃ │                                            │ ┃",
+        "┃ │                                            │ ┃",
+        "┃ │                                            │ ┃",
+        "┃ └─────────────── Close <Esc> ────────────────┘ ┃",
+        "┗━━━━━━━━━━━ Help <F1> Quit <CTRL-q> ━━━━━━━━━━━━┛",
+    ]);
+    {
+        let title_style = Style::new().bold();
+        let key_style_bold = Style::new().blue().bold();
+        expected.set_style(Rect::new(18, 0, 13, 1), title_style);
+
+        expected.set_style(Rect::new(4, 3, 3, 1), key_style_bold);
+        expected.set_style(Rect::new(4, 4, 3, 1), key_style_bold);
+        expected.set_style(Rect::new(23, 4, 2, 1), title_style);
+        expected.set_style(Rect::new(4, 7, 3, 1), key_style_bold);
+        expected.set_style(Rect::new(10, 9, 1, 1), title_style);
+        expected.set_style(Rect::new(4, 10, 7, 1), key_style_bold);
+        expected.set_style(Rect::new(4, 11, 5, 1), key_style_bold);
+
+        expected.set_style(Rect::new(25, 22, 6, 1), key_style_bold);
+        expected.set_style(Rect::new(18, 23, 4, 1), key_style_bold);
+        expected.set_style(Rect::new(28, 23, 9, 1), key_style_bold);
+    }
+
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn handle_key_event_history_modal_pages_branches() {
+    let mut app = helper::setup_app();
+    app.game_from_numeric_id(GameId(123));
+
+    let game = app.game_handler.game.clone().unwrap();
+    for legal_move in legal_moves(&game).into_iter().take(12) {
+        assert!(app
+            .game_handler
+            .make_move(legal_move.mv, Duration::ZERO)
+            .is_ok());
+        assert!(app.game_handler.revert().is_ok());
+    }
+    let branches = app.game_handler.branches();
+    assert_eq!(12, branches.len());
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 50, 24));
+    app.handle_key_event(KeyCode::F(4).into());
+    app.render(buf.area, &mut buf);
+    assert!(helper::lines(&buf)
+        .iter()
+        .any(|x| x.contains("<Tab> for more branches (1/2)")));
+
+    app.handle_key_event(KeyCode::Tab.into());
+    app.render(buf.area, &mut buf);
+    assert!(helper::lines(&buf)
+        .iter()
+        .any(|x| x.contains("<Tab> for more branches (2/2)")));
+
+    // the first key refers to the first branch on the page
+    app.handle_key_event(KeyCode::Char('2').into());
+
+    assert_eq!(app.app_state, AppState::Base);
+    assert_eq!(Err(MoveError::NothingToRedo), app.game_handler.redo());
+    assert_eq!(
+        branches[10].last_move,
+        app.game_handler.moves().last().cloned()
+    );
+
+    // paging wraps around
+    app.handle_key_event(KeyCode::F(4).into());
+    app.handle_key_event(KeyCode::Tab.into());
+    app.handle_key_event(KeyCode::Tab.into());
+
+    assert_eq!(
+        app.app_state,
+        AppState::HistoryModal {
+            name: None,
+            page: 0
+        }
+    );
+}
+
+#[test]
+fn exit_saves_game_in_progress() {
+    let mut journey_mock = MockPersistJourney::new();
+    journey_mock.expect_check_lock().return_const(Ok(()));
+    journey_mock
+        .expect_read()
+        .return_const(Ok((GameId(123), vec![])));
+    let mut game_mock = MockPersistGame::new();
+    game_mock.expect_read().return_const(Ok(None));
+    game_mock
+        .expect_write()
+        .with(predicate::eq(SavedGame {
+            id: GameId(123),
+            moves: vec![(0, Move::try_from("71").unwrap())],
+            current: 1,
+            checkpoints: vec![],
+            elapsed: Duration::from_secs(7),
+        }))
+        .times(1)
+        .return_const(Ok(()));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        journey_mock,
+        game_mock,
+        helper::get_stats_repository(),
+    );
+
+    app.game_from_numeric_id(GameId(123));
+    app.handle_key_event(KeyCode::Char('l').into());
+    app.handle_key_event(KeyCode::Char('a').into());
+    app.elapsed = Duration::from_secs(7);
+
+    app.handle_key_event(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL));
+
+    assert_eq!(app.app_state, AppState::Exit);
+}
+
+#[test]
+fn exit_without_game_clears_saved_game() {
+    let mut journey_mock = MockPersistJourney::new();
+    journey_mock.expect_check_lock().return_const(Ok(()));
+    journey_mock
+        .expect_read()
+        .return_const(Ok((GameId(123), vec![])));
+    let mut game_mock = MockPersistGame::new();
+    game_mock.expect_read().return_const(Ok(None));
+    game_mock.expect_write().never();
+    game_mock.expect_clear().times(1).return_const(Ok(()));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        journey_mock,
+        game_mock,
+        helper::get_stats_repository(),
+    );
+
+    app.handle_key_event(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL));
+
+    assert_eq!(app.app_state, AppState::Exit);
+}
+
+#[test]
+fn handle_key_event_resume_modal_resume() {
+    let saved_game = SavedGame {
+        id: GameId(123),
+        moves: vec![(0, Move::try_from("71").unwrap())],
+        current: 1,
+        checkpoints: vec![("start".to_string(), 0)],
+        elapsed: Duration::from_secs(42),
+    };
+
+    let mut journey_mock = MockPersistJourney::new();
+    journey_mock.expect_check_lock().return_const(Ok(()));
+    journey_mock
+        .expect_read()
+        .return_const(Ok((GameId(123), vec![])));
+    let mut game_mock = MockPersistGame::new();
+    game_mock
+        .expect_read()
+        .return_const(Ok(Some(saved_game.clone())));
+    game_mock.expect_clear().never();
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        journey_mock,
+        game_mock,
+        helper::get_stats_repository(),
+    );
+
+    assert_eq!(
+        app.app_state,
+        AppState::ResumeModal {
+            saved_game: saved_game.clone()
+        }
+    );
+
+    app.handle_key_event(KeyCode::Char('y').into());
+
+    assert_eq!(app.app_state, AppState::Base);
+    assert_eq!(Duration::from_secs(42), app.elapsed);
+    assert_eq!(Some(saved_game), app.game_handler.saved_game(app.elapsed));
+}
+
+#[test]
+fn handle_key_event_resume_modal_resume_inconsistent_game_shows_error_modal() {
+    let saved_game = SavedGame {
+        id: GameId(123),
+        moves: vec![
+            (0, Move::try_from("71").unwrap()),
+            (1, Move::try_from("17").unwrap()),
+        ],
+        current: 2,
+        checkpoints: vec![],
+        elapsed: Duration::ZERO,
+    };
+
+    let mut journey_mock = MockPersistJourney::new();
+    journey_mock.expect_check_lock().return_const(Ok(()));
+    journey_mock
+        .expect_read()
+        .return_const(Ok((GameId(123), vec![])));
+    let mut game_mock = MockPersistGame::new();
+    game_mock.expect_read().return_const(Ok(Some(saved_game)));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        journey_mock,
+        game_mock,
+        helper::get_stats_repository(),
+    );
+
+    app.handle_key_event(KeyCode::Char('y').into());
+
+    assert_eq!(
+        app.app_state,
+        AppState::ErrorModal {
+            messages: vec![format!(
+                "{}. The saved game was not resumed.",
+                MoveError::RankMismatch
+            )],
+            next: Box::new(AppState::Base),
+        }
+    );
+    assert!(app.game_handler.game.is_none());
+}
+
+#[test]
+fn handle_key_event_resume_modal_discard() {
+    let saved_game = SavedGame {
+        id: GameId(123),
+        moves: vec![(0, Move::try_from("71").unwrap())],
+        current: 1,
+        checkpoints: vec![],
+        elapsed: Duration::ZERO,
+    };
+
+    let mut journey_mock = MockPersistJourney::new();
+    journey_mock.expect_check_lock().return_const(Ok(()));
+    journey_mock
+        .expect_read()
+        .return_const(Ok((GameId(123), vec![])));
+    let mut game_mock = MockPersistGame::new();
+    game_mock.expect_read().return_const(Ok(Some(saved_game)));
+    game_mock.expect_clear().times(1).return_const(Ok(()));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        journey_mock,
+        game_mock,
+        helper::get_stats_repository(),
+    );
+
+    app.handle_key_event(KeyCode::Char('n').into());
+
+    assert_eq!(app.app_state, AppState::Base);
+    assert!(app.game_handler.game.is_none());
+}
+
+#[test]
+fn render_resume_modal() {
+    let mut journey_mock = MockPersistJourney::new();
+    journey_mock.expect_check_lock().return_const(Ok(()));
+    journey_mock
+        .expect_read()
+        .return_const(Ok((GameId(123), vec![])));
+    let mut game_mock = MockPersistGame::new();
+    game_mock.expect_read().return_const(Ok(Some(SavedGame {
+        id: GameId(123),
+        moves: vec![(0, Move::try_from("71").unwrap())],
+        current: 1,
+        checkpoints: vec![],
+        elapsed: Duration::ZERO,
+    })));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        journey_mock,
+        game_mock,
+        helper::get_stats_repository(),
+    );
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 32, 24));
+
+    app.render(buf.area, &mut buf);
+
+    let mut expected = Buffer::with_lines(vec![
+        "┏━━━━━━━━ RSLibreCell ━━━━━━━━━┓",
+        "┃ ┌───────── Resume ─────────┐ ┃",
+        "┃ │ Resume the saved game    │ ┃",
+        "┃ │ #123 after 1 move?       │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │ <y> to resume            │ ┃",
+        "┃ │ <n> to discard it        │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ └───── Quit <CTRL-q> ──────┘ ┃",
+        "┗━━ Help <F1> Quit <CTRL-q> ━━━┛",
+    ]);
+    {
+        let title_style = Style::new().bold();
+        let key_style_bold = Style::new().blue().bold();
+        expected.set_style(Rect::new(9, 0, 13, 1), title_style);
+
+        expected.set_style(Rect::new(4, 3, 4, 1), title_style);
+        expected.set_style(Rect::new(4, 5, 3, 1), key_style_bold);
+        expected.set_style(Rect::new(4, 6, 3, 1), key_style_bold);
+
+        expected.set_style(Rect::new(14, 22, 9, 1), key_style_bold);
+        expected.set_style(Rect::new(9, 23, 4, 1), key_style_bold);
+        expected.set_style(Rect::new(19, 23, 9, 1), key_style_bold);
+    }
+
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn render_resume_modal_shows_configured_quit_key() {
+    let mut key_config = helper::get_default_key_config();
+    key_config.actions.quit = KeyBinding::Ctrl('x');
+    let mut game_mock = MockPersistGame::new();
+    game_mock.expect_read().return_const(Ok(Some(SavedGame {
+        id: GameId(123),
+        moves: vec![],
+        current: 0,
+        checkpoints: vec![],
+        elapsed: Duration::ZERO,
+    })));
+    let mut app = App::new(
+        helper::key_config_repository(Ok(key_config)),
+        helper::get_journey_repository(),
+        game_mock,
+        helper::get_stats_repository(),
+    );
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 32, 24));
+    app.render(buf.area, &mut buf);
+
+    let lines = helper::lines(&buf);
+    assert_eq!("┃ └───── Quit <CTRL-x> ──────┘ ┃", lines[22]);
+    assert_eq!("┗━━ Help <F1> Quit <CTRL-x> ━━━┛", lines[23]);
+}
+
+#[test]
+fn render_selection_journey_modal_no_skipped() {
+    let mut mock = MockPersistJourney::new();
+    mock.expect_check_lock().return_const(Ok(()));
+    mock.expect_read().return_const(Ok((GameId(1), vec![])));
+    mock.expect_write().return_const(Ok(()));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        mock,
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
 
     let mut buf = Buffer::empty(Rect::new(0, 0, 32, 24));
     app.handle_key_event(KeyCode::Char('!').into());
@@ -1683,10 +2542,18 @@ fn render_selection_journey_modal_no_skipped() {
 #[test]
 fn render_selection_journey_modal_no_next_game() {
     let mut mock = MockPersistJourney::new();
-    mock.expect_read()
-        .return_const((GameId(64001), vec![GameId(111), GameId(222), GameId(12345)]));
-    mock.expect_write().return_const(());
-    let mut app = App::new(helper::get_default_key_config(), mock);
+    mock.expect_check_lock().return_const(Ok(()));
+    mock.expect_read().return_const(Ok((
+        GameId(64001),
+        vec![GameId(111), GameId(222), GameId(12345)],
+    )));
+    mock.expect_write().return_const(Ok(()));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        mock,
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
 
     let mut buf = Buffer::empty(Rect::new(0, 0, 32, 24));
     app.handle_key_event(KeyCode::Char('!').into());
@@ -1739,7 +2606,8 @@ fn render_selection_journey_modal_no_next_game() {
 #[test]
 fn render_selection_journey_modal_next_game_and_eight_skipped_games() {
     let mut mock = MockPersistJourney::new();
-    mock.expect_read().return_const((
+    mock.expect_check_lock().return_const(Ok(()));
+    mock.expect_read().return_const(Ok((
         GameId(23442),
         vec![
             GameId(111),
@@ -1751,9 +2619,14 @@ fn render_selection_journey_modal_next_game_and_eight_skipped_games() {
             GameId(777),
             GameId(888),
         ],
-    ));
-    mock.expect_write().return_const(());
-    let mut app = App::new(helper::get_default_key_config(), mock);
+    )));
+    mock.expect_write().return_const(Ok(()));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        mock,
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
 
     let mut buf = Buffer::empty(Rect::new(0, 0, 32, 24));
     app.handle_key_event(KeyCode::Char('!').into());
@@ -1814,7 +2687,8 @@ fn render_selection_journey_modal_next_game_and_eight_skipped_games() {
 #[test]
 fn render_selection_journey_modal_next_game_and_many_skipped_games() {
     let mut mock = MockPersistJourney::new();
-    mock.expect_read().return_const((
+    mock.expect_check_lock().return_const(Ok(()));
+    mock.expect_read().return_const(Ok((
         GameId(23442),
         vec![
             GameId(111),
@@ -1827,9 +2701,14 @@ fn render_selection_journey_modal_next_game_and_many_skipped_games() {
             GameId(888),
             GameId(999),
         ],
-    ));
-    mock.expect_write().return_const(());
-    let mut app = App::new(helper::get_default_key_config(), mock);
+    )));
+    mock.expect_write().return_const(Ok(()));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        mock,
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
 
     let mut buf = Buffer::empty(Rect::new(0, 0, 32, 24));
     app.handle_key_event(KeyCode::Char('!').into());
@@ -1890,9 +2769,15 @@ fn render_selection_journey_modal_next_game_and_many_skipped_games() {
 #[test]
 fn render_selection_journey_modal_completed() {
     let mut mock = MockPersistJourney::new();
-    mock.expect_read().return_const((GameId(64001), vec![]));
-    mock.expect_write().return_const(());
-    let mut app = App::new(helper::get_default_key_config(), mock);
+    mock.expect_check_lock().return_const(Ok(()));
+    mock.expect_read().return_const(Ok((GameId(64001), vec![])));
+    mock.expect_write().return_const(Ok(()));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        mock,
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
 
     let mut buf = Buffer::empty(Rect::new(0, 0, 32, 24));
     app.handle_key_event(KeyCode::Char('!').into());
@@ -1940,16 +2825,1527 @@ fn render_selection_journey_modal_completed() {
     assert_eq!(buf, expected);
 }
 
-mod helper {
-    use super::*;
+#[test]
+fn new_with_errors_shows_error_modal_before_resume_modal() {
+    let saved_game = SavedGame {
+        id: GameId(123),
+        moves: vec![],
+        current: 0,
+        checkpoints: vec![],
+        elapsed: Duration::ZERO,
+    };
 
-    pub fn setup_app() -> App<MockPersistJourney> {
-        let key_config = get_default_key_config();
-        let mut mock = MockPersistJourney::new();
-        mock.expect_read()
-            .return_const((GameId(123), vec![GameId(117), GameId(118)]));
-        mock.expect_write().return_const(());
-        App::new(key_config, mock)
+    let mut journey_mock = MockPersistJourney::new();
+    journey_mock.expect_check_lock().return_const(Ok(()));
+    journey_mock
+        .expect_read()
+        .return_const(Err(RepositoryError::Malformed {
+            path: PathBuf::from("journey.bin"),
+        }));
+    journey_mock.expect_write().never();
+    let mut game_mock = MockPersistGame::new();
+    game_mock
+        .expect_read()
+        .return_const(Ok(Some(saved_game.clone())));
+    game_mock.expect_clear().times(1).return_const(Ok(()));
+    let mut app = App::new(
+        helper::key_config_repository(Err(RepositoryError::Read {
+            path: PathBuf::from("key_config.json"),
+            kind: ErrorKind::PermissionDenied,
+        })),
+        journey_mock,
+        game_mock,
+        helper::get_stats_repository(),
+    );
+
+    assert_eq!(
+        app.app_state,
+        AppState::ErrorModal {
+            messages: vec![
+                "Unable to read key_config.json: permission denied. The default keys are used."
+                    .to_string(),
+                "Malformed data in journey.bin. A new journey is started, which is not saved."
+                    .to_string(),
+            ],
+            next: Box::new(AppState::ResumeModal {
+                saved_game: saved_game.clone()
+            }),
+        }
+    );
+    assert_eq!('q', app.key_config.cell1);
+    assert_eq!(
+        (GameId(1), vec![]),
+        app.journey_handler.borrow().next_game_ids()
+    );
+
+    app.handle_key_event(KeyCode::Enter.into());
+
+    assert_eq!(app.app_state, AppState::ResumeModal { saved_game });
+
+    // the new journey is not saved
+    app.handle_key_event(KeyCode::Esc.into());
+    app.handle_key_event(KeyCode::Char('!').into());
+    app.handle_key_event(KeyCode::Char('s').into());
+
+    assert_eq!(app.app_state, AppState::SelectionJourneyModal);
+}
+
+#[test]
+fn new_with_key_conflicts_shows_error_modal() {
+    let key_config = KeyConfig {
+        column1: 'R',
+        column2: 'x',
+        actions: ActionKeys {
+            hint: KeyBinding::Char('x'),
+            ..ActionKeys::default()
+        },
+        ..helper::get_default_key_config()
+    };
+    let mut app = App::new(
+        helper::key_config_repository(Ok(key_config)),
+        helper::get_journey_repository(),
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
+
+    assert_eq!(
+        app.app_state,
+        AppState::ErrorModal {
+            messages: vec![
+                "The key <R> is used by column1 and revert. The default is used for column1."
+                    .to_string(),
+                "The key <x> is used by column2 and hint. The default is used for column2, hint."
+                    .to_string(),
+            ],
+            next: Box::new(AppState::Base),
+        }
+    );
+    assert_eq!('a', app.key_config.column1);
+    assert_eq!('s', app.key_config.column2);
+    assert_eq!(KeyBinding::Char('h'), app.key_config.actions.hint);
+
+    app.handle_key_event(KeyCode::Enter.into());
+
+    assert_eq!(app.app_state, AppState::Base);
+}
+
+#[test]
+fn new_with_plain_quit_key_keeps_typing_and_box_keys() {
+    let key_config = KeyConfig {
+        actions: ActionKeys {
+            quit: KeyBinding::Char('x'),
+            ..ActionKeys::default()
+        },
+        ..helper::get_default_key_config()
+    };
+    let mut app = App::new(
+        helper::key_config_repository(Ok(key_config)),
+        helper::get_journey_repository(),
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
+
+    assert_eq!(
+        app.app_state,
+        AppState::ErrorModal {
+            messages: vec![
+                "The key <x> cannot be used for quit, as quit needs a function key or a key held with control. The default is used for quit."
+                    .to_string(),
+            ],
+            next: Box::new(AppState::Base),
+        }
+    );
+    assert_eq!(KeyBinding::Ctrl('q'), app.key_config.actions.quit);
+
+    app.handle_key_event(KeyCode::Enter.into());
+    app.game_from_numeric_id(GameId(123));
+
+    // the letter is typed into the name of a checkpoint
+    app.handle_key_event(KeyCode::F(4).into());
+    app.handle_key_event(KeyCode::Char('+').into());
+    app.handle_key_event(KeyCode::Char('x').into());
+
+    assert_eq!(
+        app.app_state,
+        AppState::HistoryModal {
+            name: Some(String::from("x")),
+            page: 0
+        }
+    );
+}
+
+#[test]
+fn new_with_plain_quit_key_keeps_resume_keys() {
+    let key_config = KeyConfig {
+        actions: ActionKeys {
+            quit: KeyBinding::Char('y'),
+            ..ActionKeys::default()
+        },
+        ..helper::get_default_key_config()
+    };
+    let mut game_mock = MockPersistGame::new();
+    game_mock.expect_read().return_const(Ok(Some(SavedGame {
+        id: GameId(123),
+        moves: vec![(0, Move::try_from("71").unwrap())],
+        current: 1,
+        checkpoints: vec![],
+        elapsed: Duration::ZERO,
+    })));
+    let mut app = App::new(
+        helper::key_config_repository(Ok(key_config)),
+        helper::get_journey_repository(),
+        game_mock,
+        helper::get_stats_repository(),
+    );
+
+    app.handle_key_event(KeyCode::Enter.into());
+    assert!(matches!(app.app_state, AppState::ResumeModal { .. }));
+
+    // the letter resumes the game rather than quitting
+    app.handle_key_event(KeyCode::Char('y').into());
+
+    assert_eq!(app.app_state, AppState::Base);
+    assert!(app.game_handler.game.is_some());
+}
+
+#[test]
+fn handle_key_event_resume_modal_uses_configured_keys() {
+    let key_config = KeyConfig {
+        actions: ActionKeys {
+            resume_yes: KeyBinding::Char('j'),
+            resume_no: KeyBinding::Char('k'),
+            ..ActionKeys::default()
+        },
+        ..helper::get_default_key_config()
+    };
+    let mut game_mock = MockPersistGame::new();
+    game_mock.expect_read().return_const(Ok(Some(SavedGame {
+        id: GameId(123),
+        moves: vec![(0, Move::try_from("71").unwrap())],
+        current: 1,
+        checkpoints: vec![],
+        elapsed: Duration::ZERO,
+    })));
+    game_mock.expect_clear().never();
+    let mut app = App::new(
+        helper::key_config_repository(Ok(key_config)),
+        helper::get_journey_repository(),
+        game_mock,
+        helper::get_stats_repository(),
+    );
+
+    app.handle_key_event(KeyCode::Char('y').into());
+    app.handle_key_event(KeyCode::Char('n').into());
+    assert!(matches!(app.app_state, AppState::ResumeModal { .. }));
+
+    app.handle_key_event(KeyCode::Char('j').into());
+
+    assert_eq!(app.app_state, AppState::Base);
+    assert!(app.game_handler.game.is_some());
+}
+
+#[test]
+fn handle_key_event_history_modal_uses_configured_keys() {
+    let mut history_checkpoints = ActionKeys::default().history_checkpoints;
+    history_checkpoints[0] = KeyBinding::Char('Z');
+    let key_config = KeyConfig {
+        actions: ActionKeys {
+            history_checkpoint: KeyBinding::Char('*'),
+            history_checkpoints,
+            ..ActionKeys::default()
+        },
+        ..helper::get_default_key_config()
+    };
+    let mut app = App::new(
+        helper::key_config_repository(Ok(key_config)),
+        helper::get_journey_repository(),
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
+    app.game_from_numeric_id(GameId(123));
+    let initial = app.game_handler.game.clone();
+
+    app.handle_key_event(KeyCode::F(4).into());
+    app.handle_key_event(KeyCode::Char('+').into());
+    assert_eq!(
+        app.app_state,
+        AppState::HistoryModal {
+            name: None,
+            page: 0
+        }
+    );
+
+    app.handle_key_event(KeyCode::Char('*').into());
+    app.handle_key_event(KeyCode::Char('x').into());
+    app.handle_key_event(KeyCode::Enter.into());
+    app.handle_key_event(KeyCode::Esc.into());
+
+    app.handle_key_event(KeyCode::Char('l').into());
+    app.handle_key_event(KeyCode::Char('a').into());
+
+    app.handle_key_event(KeyCode::F(4).into());
+    app.handle_key_event(KeyCode::Char('a').into());
+    assert_ne!(initial, app.game_handler.game);
+
+    app.handle_key_event(KeyCode::Char('Z').into());
+
+    assert_eq!(app.app_state, AppState::Base);
+    assert_eq!(initial, app.game_handler.game);
+}
+
+#[test]
+fn handle_key_event_settings_modal_uses_configured_keys() {
+    let key_config = KeyConfig {
+        actions: ActionKeys {
+            settings_suits: KeyBinding::Char('x'),
+            settings_save: KeyBinding::F(9),
+            ..ActionKeys::default()
+        },
+        ..helper::get_default_key_config()
+    };
+    let mut app = App::new(
+        helper::key_config_repository(Ok(key_config)),
+        helper::get_journey_repository(),
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
+
+    app.handle_key_event(KeyCode::F(6).into());
+    app.handle_key_event(KeyCode::Char('a').into());
+    app.handle_key_event(KeyCode::Char('x').into());
+    app.handle_key_event(KeyCode::Char('s').into());
+    assert!(matches!(app.app_state, AppState::SettingsModal { .. }));
+
+    app.handle_key_event(KeyCode::F(9).into());
+
+    assert_eq!(app.app_state, AppState::Base);
+    assert!(app.key_config.ascii);
+}
+
+#[test]
+fn new_with_saved_game_error_shows_error_modal() {
+    let mut journey_mock = MockPersistJourney::new();
+    journey_mock.expect_check_lock().return_const(Ok(()));
+    journey_mock
+        .expect_read()
+        .return_const(Ok((GameId(123), vec![])));
+    let mut game_mock = MockPersistGame::new();
+    game_mock
+        .expect_read()
+        .return_const(Err(RepositoryError::Malformed {
+            path: PathBuf::from("game.json"),
+        }));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        journey_mock,
+        game_mock,
+        helper::get_stats_repository(),
+    );
+
+    assert_eq!(
+        app.app_state,
+        AppState::ErrorModal {
+            messages: vec![
+                "Malformed data in game.json. The saved game cannot be resumed.".to_string()
+            ],
+            next: Box::new(AppState::Base),
+        }
+    );
+
+    app.handle_key_event(KeyCode::Esc.into());
+
+    assert_eq!(app.app_state, AppState::Base);
+}
+
+#[test]
+fn journey_write_error_shows_error_modal() {
+    let mut journey_mock = MockPersistJourney::new();
+    journey_mock.expect_check_lock().return_const(Ok(()));
+    journey_mock
+        .expect_read()
+        .return_const(Ok((GameId(123), vec![])));
+    journey_mock
+        .expect_write()
+        .times(1)
+        .return_const(Err(RepositoryError::NoDataDirectory));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        journey_mock,
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
+
+    app.handle_key_event(KeyCode::Char('!').into());
+    app.handle_key_event(KeyCode::Char('s').into());
+
+    assert_eq!(
+        app.app_state,
+        AppState::ErrorModal {
+            messages: vec![
+                "Unable to find the data directory. The journey progress was not saved."
+                    .to_string()
+            ],
+            next: Box::new(AppState::SelectionJourneyModal),
+        }
+    );
+
+    app.handle_key_event(KeyCode::Enter.into());
+
+    assert_eq!(app.app_state, AppState::SelectionJourneyModal);
+}
+
+#[test]
+fn exit_with_write_error_shows_error_modal_before_exiting() {
+    let mut journey_mock = MockPersistJourney::new();
+    journey_mock.expect_check_lock().return_const(Ok(()));
+    journey_mock
+        .expect_read()
+        .return_const(Ok((GameId(123), vec![])));
+    let mut game_mock = MockPersistGame::new();
+    game_mock.expect_read().return_const(Ok(None));
+    game_mock
+        .expect_write()
+        .times(1)
+        .return_const(Err(RepositoryError::Write {
+            path: PathBuf::from("game.json"),
+            kind: ErrorKind::PermissionDenied,
+        }));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        journey_mock,
+        game_mock,
+        helper::get_stats_repository(),
+    );
+
+    app.game_from_numeric_id(GameId(123));
+    app.handle_key_event(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL));
+
+    assert_eq!(
+        app.app_state,
+        AppState::ErrorModal {
+            messages: vec![
+                "Unable to write game.json: permission denied. The game was not saved.".to_string()
+            ],
+            next: Box::new(AppState::Exit),
+        }
+    );
+
+    app.handle_key_event(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL));
+
+    assert_eq!(app.app_state, AppState::Exit);
+}
+
+#[test]
+fn render_error_modal() {
+    let mut journey_mock = MockPersistJourney::new();
+    journey_mock.expect_check_lock().return_const(Ok(()));
+    journey_mock
+        .expect_read()
+        .return_const(Ok((GameId(123), vec![])));
+    let mut game_mock = MockPersistGame::new();
+    game_mock
+        .expect_read()
+        .return_const(Err(RepositoryError::Malformed {
+            path: PathBuf::from("game.json"),
+        }));
+    let mut app = App::new(
+        helper::key_config_repository(Err(RepositoryError::NoDataDirectory)),
+        journey_mock,
+        game_mock,
+        helper::get_stats_repository(),
+    );
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 32, 24));
+
+    app.render(buf.area, &mut buf);
+
+    let mut expected = Buffer::with_lines(vec![
+        "┏━━━━━━━━ RSLibreCell ━━━━━━━━━┓",
+        "┃ ┌───────── Error ──────────┐ ┃",
+        "┃ │ Unable to find the data  │ ┃",
+        "┃ │ directory. The default   │ ┃",
+        "┃ │ keys are used.           │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │ Malformed data in        │ ┃",
+        "┃ │ game.json. The saved     │ ┃",
+        "┃ │ game cannot be resumed.  │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ └──── Continue <Enter> ────┘ ┃",
+        "┗━━ Help <F1> Quit <CTRL-q> ━━━┛",
+    ]);
+    {
+        let title_style = Style::new().bold();
+        let key_style = Style::new().blue().bold();
+        expected.set_style(Rect::new(9, 0, 13, 1), title_style);
+        expected.set_style(Rect::new(17, 22, 8, 1), key_style);
+        expected.set_style(Rect::new(9, 23, 4, 1), key_style);
+        expected.set_style(Rect::new(19, 23, 9, 1), key_style);
+    }
+
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn journey_locked_by_other_instance_shows_error_modal() {
+    let mut journey_mock = MockPersistJourney::new();
+    journey_mock
+        .expect_check_lock()
+        .return_const(Err(RepositoryError::Locked {
+            path: PathBuf::from("journey.lock"),
+        }));
+    journey_mock
+        .expect_read()
+        .return_const(Ok((GameId(123), vec![])));
+    journey_mock.expect_write().never();
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        journey_mock,
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
+
+    // the journey is read, but not written
+    assert_eq!(
+        app.app_state,
+        AppState::ErrorModal {
+            messages: vec![
+                "Another instance of RSLibreCell holds the lock journey.lock. The journey progress and the statistics are not saved by this instance."
+                    .to_string()
+            ],
+            next: Box::new(AppState::Base),
+        }
+    );
+    assert_eq!(
+        (GameId(123), vec![]),
+        app.journey_handler.borrow().next_game_ids()
+    );
+
+    app.handle_key_event(KeyCode::Enter.into());
+    app.handle_key_event(KeyCode::Char('!').into());
+    app.handle_key_event(KeyCode::Char('s').into());
+
+    assert_eq!(app.app_state, AppState::SelectionJourneyModal);
+}
+
+#[test]
+fn handle_key_event_stats_modal() {
+    let mut app = helper::setup_app();
+    app.handle_key_event(KeyCode::F(5).into());
+    assert_eq!(app.app_state, AppState::StatsModal);
+
+    app.handle_key_event(KeyCode::Esc.into());
+    assert_eq!(app.app_state, AppState::Base);
+
+    app.handle_key_event(KeyCode::F(5).into());
+    let mut key: KeyEvent = KeyCode::Char('q').into();
+    key.modifiers = KeyModifiers::CONTROL;
+    app.handle_key_event(key);
+    assert_eq!(app.app_state, AppState::Exit);
+}
+
+#[test]
+fn render_stats_modal_shows_most_recent_deal() {
+    let record = |id: u64, won: bool, moves: usize, ended: u64| GameRecord {
+        id: GameId(id),
+        won,
+        moves,
+        reverts: 1,
+        seconds: 252,
+        ended,
+    };
+    let mut stats_mock = MockPersistStats::new();
+    stats_mock.expect_read().return_const(Ok(vec![
+        record(617, false, 1, 951_782_400),
+        record(1, true, 90, 1_741_910_400),
+        record(617, true, 87, 1_741_996_799),
+    ]));
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        helper::get_journey_repository(),
+        helper::get_game_repository(),
+        stats_mock,
+    );
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 32, 24));
+    app.handle_key_event(KeyCode::F(5).into());
+
+    app.render(buf.area, &mut buf);
+
+    let mut expected = Buffer::with_lines(vec![
+        "┏━━━━━━━━ RSLibreCell ━━━━━━━━━┓",
+        "┃ ┌─────── Statistics ───────┐ ┃",
+        "┃ │ Games played:          3 │ ┃",
+        "┃ │ Games won:             2 │ ┃",
+        "┃ │ Win rate:            66% │ ┃",
+        "┃ │ Current streak:        2 │ ┃",
+        "┃ │ Longest streak:        2 │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │ #617:                    │ ┃",
+        "┃ │ 2025-03-14  won in 4:12  │ ┃",
+        "┃ │   87 moves, 1 revert     │ ┃",
+        "┃ │ 2000-02-29  abandoned    │ ┃",
+        "┃ │   1 move, 1 revert       │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ │                          │ ┃",
+        "┃ └────── Close <Esc> ───────┘ ┃",
+        "┗━━ Help <F1> Quit <CTRL-q> ━━━┛",
+    ]);
+    {
+        let title_style = Style::new().bold();
+        let key_style_bold = Style::new().blue().bold();
+        expected.set_style(Rect::new(9, 0, 13, 1), title_style);
+
+        expected.set_style(Rect::new(4, 8, 4, 1), title_style);
+
+        expected.set_style(Rect::new(16, 22, 6, 1), key_style_bold);
+        expected.set_style(Rect::new(9, 23, 4, 1), key_style_bold);
+        expected.set_style(Rect::new(19, 23, 9, 1), key_style_bold);
+    }
+
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn render_stats_modal_shows_held_deal() {
+    let mut app = helper::setup_app();
+    app.handle_key_event(KeyCode::F(3).into());
+    for digit in ['6', '1', '7'] {
+        app.handle_key_event(KeyCode::Char(digit).into());
+    }
+    app.handle_key_event(KeyCode::Enter.into());
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 32, 24));
+    app.handle_key_event(KeyCode::F(5).into());
+
+    app.render(buf.area, &mut buf);
+
+    let lines: Vec<String> = (2..11)
+        .map(|y| (4..28).map(|x| buf[(x, y)].symbol()).collect::<String>())
+        .collect();
+
+    assert_eq!("Games played:          0", lines[0]);
+    assert_eq!("Win rate:              -", lines[2]);
+    assert_eq!("#617:                   ", lines[6]);
+    assert_eq!("No results yet          ", lines[7]);
+}
+
+#[test]
+fn abandoning_game_updates_stats() {
+    let mut app = helper::setup_app();
+    app.handle_key_event(KeyCode::F(3).into());
+    app.handle_key_event(KeyCode::Char('1').into());
+    app.handle_key_event(KeyCode::Enter.into());
+
+    app.handle_key_event(KeyCode::Char('a').into());
+    app.handle_key_event(KeyCode::Char('q').into());
+    app.handle_key_event(KeyCode::F(2).into());
+
+    let summary = app.stats_handler.borrow().summary();
+    assert_eq!(1, summary.played);
+    assert_eq!(0, summary.won);
+}
+
+#[test]
+fn failing_stats_read_shows_error_modal() {
+    let mut stats_mock = MockPersistStats::new();
+    stats_mock
+        .expect_read()
+        .return_const(Err(RepositoryError::NoDataDirectory));
+    stats_mock.expect_write().never().return_const(Ok(()));
+
+    let app = App::new(
+        helper::get_key_config_repository(),
+        helper::get_journey_repository(),
+        helper::get_game_repository(),
+        stats_mock,
+    );
+
+    assert_eq!(
+        app.app_state,
+        AppState::ErrorModal {
+            messages: vec![format!(
+                "{}. The statistics are not available and not saved.",
+                RepositoryError::NoDataDirectory
+            )],
+            next: Box::new(AppState::Base),
+        }
+    );
+}
+
+#[test]
+fn failing_stats_write_shows_error_modal() {
+    let mut stats_mock = MockPersistStats::new();
+    stats_mock.expect_read().return_const(Ok(vec![]));
+    stats_mock
+        .expect_write()
+        .once()
+        .return_const(Err(RepositoryError::NoDataDirectory));
+
+    let mut app = App::new(
+        helper::get_key_config_repository(),
+        helper::get_journey_repository(),
+        helper::get_game_repository(),
+        stats_mock,
+    );
+
+    app.handle_key_event(KeyCode::F(3).into());
+    app.handle_key_event(KeyCode::Char('1').into());
+    app.handle_key_event(KeyCode::Enter.into());
+    app.handle_key_event(KeyCode::Char('a').into());
+    app.handle_key_event(KeyCode::Char('q').into());
+    assert_eq!(app.app_state, AppState::Base);
+
+    app.handle_key_event(KeyCode::F(2).into());
+
+    assert_eq!(
+        app.app_state,
+        AppState::ErrorModal {
+            messages: vec![format!(
+                "{}. The statistics were not saved.",
+                RepositoryError::NoDataDirectory
+            )],
+            next: Box::new(AppState::Base),
+        }
+    );
+}
+
+#[test]
+fn stats_texts_describe_dates_and_durations() {
+    assert_eq!("1970-01-01", render::date_text(0));
+    assert_eq!("2000-02-29", render::date_text(951_782_400));
+    assert_eq!("2025-03-14", render::date_text(1_741_996_799));
+    assert_eq!("2100-03-01", render::date_text(4_107_542_400));
+
+    assert_eq!("0:05", render::duration_text(5));
+    assert_eq!("4:12", render::duration_text(252));
+    assert_eq!("1:00:00", render::duration_text(3_600));
+    assert_eq!("26:05:09", render::duration_text(93_909));
+}
+
+#[test]
+fn clock_runs_only_while_playing() {
+    let mut app = helper::setup_app();
+    let start = app.last_tick;
+
+    // no game yet
+    app.tick(start + Duration::from_secs(5));
+    assert_eq!(Duration::ZERO, app.elapsed);
+
+    app.game_from_numeric_id(GameId(1));
+    app.tick(start + Duration::from_secs(65));
+    assert_eq!(Duration::from_secs(60), app.elapsed);
+    assert_eq!("1:00  0 moves, 0 auto", app.status());
+
+    // paused while a modal is displayed
+    app.handle_key_event(KeyCode::F(1).into());
+    app.tick(start + Duration::from_secs(125));
+    assert_eq!(Duration::from_secs(60), app.elapsed);
+
+    app.handle_key_event(KeyCode::Esc.into());
+    app.tick(start + Duration::from_secs(130));
+    assert_eq!(Duration::from_secs(65), app.elapsed);
+
+    // reset by a new game
+    app.handle_key_event(KeyCode::F(2).into());
+    assert_eq!(Duration::ZERO, app.elapsed);
+    app.tick(start + Duration::from_secs(131));
+    assert_eq!(Duration::from_secs(1), app.elapsed);
+}
+
+#[test]
+fn clock_stops_when_game_is_won() {
+    let mut app = helper::setup_app();
+    app.game_from_numeric_id(GameId(100));
+    app.game_handler.game = Some(
+        Game::try_from(
+            "RustLibreCell                     #100 \n\
+             \n\
+             \x20..  ..  ..  K♦ || Q♣  K♠  K♥  Q♦ \n\
+             --------------------------------- \n\
+             \x20K♣                                \n",
+        )
+        .unwrap(),
+    );
+    let start = app.last_tick;
+
+    app.tick(start + Duration::from_secs(10));
+    assert_eq!(Duration::from_secs(10), app.elapsed);
+
+    // the time in a modal is neither shown nor recorded
+    app.handle_key_event(KeyCode::F(1).into());
+    app.tick(start + Duration::from_secs(70));
+    app.handle_key_event(KeyCode::Esc.into());
+    app.tick(start + Duration::from_secs(75));
+    assert_eq!(Duration::from_secs(15), app.elapsed);
+
+    app.handle_key_event(KeyCode::Char('a').into());
+    app.handle_key_event(KeyCode::Char('u').into());
+    assert!(app.game_handler.game.as_ref().unwrap().is_won());
+
+    app.tick(start + Duration::from_secs(80));
+    assert_eq!(Duration::from_secs(15), app.elapsed);
+    assert_eq!(
+        15,
+        app.stats_handler.borrow().history(&GameId(100))[0].seconds
+    );
+}
+
+#[test]
+fn location_at_hits_board() {
+    let area = Rect::new(0, 0, 50, 24);
+
+    assert_eq!(None, render::location_at(area, 8, 1));
+    assert_eq!(
+        Some(Location::Cell { i: 0 }),
+        render::location_at(area, 8, 2)
+    );
+    assert_eq!(
+        Some(Location::Cell { i: 3 }),
+        render::location_at(area, 23, 2)
+    );
+    assert_eq!(None, render::location_at(area, 24, 2));
+    assert_eq!(Some(Location::Foundation), render::location_at(area, 26, 2));
+    assert_eq!(Some(Location::Foundation), render::location_at(area, 41, 2));
+    assert_eq!(None, render::location_at(area, 42, 2));
+    assert_eq!(None, render::location_at(area, 20, 3));
+    assert_eq!(None, render::location_at(area, 8, 4));
+    assert_eq!(
+        Some(Location::Column { i: 0 }),
+        render::location_at(area, 9, 4)
+    );
+    assert_eq!(
+        Some(Location::Column { i: 7 }),
+        render::location_at(area, 40, 22)
+    );
+    assert_eq!(None, render::location_at(area, 41, 22));
+    assert_eq!(None, render::location_at(area, 9, 23));
+
+    // the compact board
+    let narrow = Rect::new(0, 0, 32, 24);
+    assert_eq!(None, render::location_at(narrow, 2, 2));
+    assert_eq!(
+        Some(Location::Cell { i: 0 }),
+        render::location_at(narrow, 3, 2)
+    );
+    assert_eq!(
+        Some(Location::Cell { i: 3 }),
+        render::location_at(narrow, 14, 2)
+    );
+    assert_eq!(None, render::location_at(narrow, 16, 2));
+    assert_eq!(
+        Some(Location::Foundation),
+        render::location_at(narrow, 17, 2)
+    );
+    assert_eq!(
+        Some(Location::Foundation),
+        render::location_at(narrow, 28, 2)
+    );
+    assert_eq!(None, render::location_at(narrow, 29, 2));
+    assert_eq!(None, render::location_at(narrow, 3, 4));
+    assert_eq!(
+        Some(Location::Column { i: 0 }),
+        render::location_at(narrow, 4, 4)
+    );
+    assert_eq!(
+        Some(Location::Column { i: 1 }),
+        render::location_at(narrow, 7, 4)
+    );
+    assert_eq!(
+        Some(Location::Column { i: 7 }),
+        render::location_at(narrow, 27, 22)
+    );
+    assert_eq!(None, render::location_at(narrow, 28, 22));
+}
+
+#[test]
+fn mouse_clicks_make_move() {
+    let mut app = helper::setup_app();
+    app.game_from_numeric_id(GameId(1));
+    let mut buf = Buffer::empty(Rect::new(0, 0, 50, 24));
+    app.render(buf.area, &mut buf);
+
+    helper::click(&mut app, 10, 10);
+    assert_eq!(Some(Location::Column { i: 0 }), app.move_from);
+    helper::click(&mut app, 9, 2);
+    assert!(app.move_from.is_none());
+
+    let mut expected = helper::setup_app();
+    expected.game_from_numeric_id(GameId(1));
+    expected.handle_key_event(KeyCode::Char('a').into());
+    expected.handle_key_event(KeyCode::Char('q').into());
+
+    assert_eq!(expected.game_handler.game, app.game_handler.game);
+}
+
+#[test]
+fn mouse_drag_makes_move() {
+    let mut app = helper::setup_app();
+    app.game_from_numeric_id(GameId(1));
+    let mut buf = Buffer::empty(Rect::new(0, 0, 50, 24));
+    app.render(buf.area, &mut buf);
+
+    app.handle_mouse_event(helper::mouse(
+        MouseEventKind::Down(MouseButton::Left),
+        10,
+        10,
+    ));
+    app.handle_mouse_event(helper::mouse(
+        MouseEventKind::Drag(MouseButton::Left),
+        11,
+        6,
+    ));
+    app.handle_mouse_event(helper::mouse(MouseEventKind::Up(MouseButton::Left), 9, 2));
+
+    assert!(app.move_from.is_none());
+    assert!(app.game_handler.game.as_ref().unwrap().cells[0].is_some());
+}
+
+#[test]
+fn mouse_click_elsewhere_clears_move() {
+    let mut app = helper::setup_app();
+    app.game_from_numeric_id(GameId(1));
+    let mut buf = Buffer::empty(Rect::new(0, 0, 50, 24));
+    app.render(buf.area, &mut buf);
+
+    helper::click(&mut app, 10, 10);
+    assert!(app.move_from.is_some());
+
+    helper::click(&mut app, 2, 10);
+    assert!(app.move_from.is_none());
+
+    helper::click(&mut app, 10, 10);
+    app.handle_mouse_event(helper::mouse(
+        MouseEventKind::Down(MouseButton::Right),
+        9,
+        2,
+    ));
+    assert!(app.move_from.is_none());
+    assert!(app.game_handler.game.as_ref().unwrap().cells[0].is_none());
+}
+
+#[test]
+fn mouse_is_ignored_in_modals() {
+    let mut app = helper::setup_app();
+    app.game_from_numeric_id(GameId(1));
+    let mut buf = Buffer::empty(Rect::new(0, 0, 50, 24));
+    app.render(buf.area, &mut buf);
+
+    app.handle_key_event(KeyCode::F(1).into());
+    helper::click(&mut app, 10, 10);
+
+    assert!(app.move_from.is_none());
+    assert_eq!(AppState::HelpModal, app.app_state);
+}
+
+#[test]
+fn selection_highlights_run_and_targets() {
+    let mut app = helper::setup_app();
+    app.game_from_numeric_id(GameId(1));
+    let card = |x: &str| Card::try_from(x).unwrap();
+    app.game_handler.game = Some(Game {
+        id: GameId(1),
+        cells: [None, None, None, Some(card("K♠"))],
+        foundations: [vec![], vec![], vec![card("A♥"), card("2♥")], vec![]],
+        columns: [
+            vec![card("9♦"), card("5♥"), card("4♠"), card("3♥")],
+            vec![card("6♣")],
+            vec![card("4♣")],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![card("Q♦")],
+        ],
+    });
+
+    assert!(app.highlights().is_empty());
+    assert!(app.targets().is_empty());
+
+    app.handle_key_event(KeyCode::Char('a').into());
+
+    assert_eq!(
+        vec![render::Highlight::Run { i: 0, count: 3 }],
+        app.highlights()
+    );
+    assert_eq!(
+        vec![
+            render::Highlight::Cell { i: 0 },
+            render::Highlight::Cell { i: 1 },
+            render::Highlight::Cell { i: 2 },
+            render::Highlight::Foundation { i: 2 },
+            render::Highlight::Column { i: 1 },
+            render::Highlight::Column { i: 2 },
+            render::Highlight::Column { i: 3 },
+            render::Highlight::Column { i: 4 },
+            render::Highlight::Column { i: 5 },
+            render::Highlight::Column { i: 6 },
+        ],
+        app.targets()
+    );
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 50, 24));
+    app.render(buf.area, &mut buf);
+
+    let reversed = |buf: &Buffer, x: u16, y: u16| buf[(x, y)].modifier.contains(Modifier::REVERSED);
+    let underlined =
+        |buf: &Buffer, x: u16, y: u16| buf[(x, y)].modifier.contains(Modifier::UNDERLINED);
+
+    assert!(!reversed(&buf, 10, 4));
+    assert!(reversed(&buf, 10, 5));
+    assert!(reversed(&buf, 10, 7));
+    assert!(underlined(&buf, 9, 2));
+    assert!(!underlined(&buf, 21, 2));
+    assert!(underlined(&buf, 35, 2));
+    assert!(underlined(&buf, 14, 4));
+    assert!(underlined(&buf, 22, 4));
+    assert!(!underlined(&buf, 22, 5));
+    assert!(!underlined(&buf, 38, 4));
+
+    // the selection is cleared with the move
+    app.handle_key_event(KeyCode::Char('s').into());
+
+    assert!(app.highlights().is_empty());
+    assert!(app.targets().is_empty());
+    assert_eq!(4, app.game_handler.game.as_ref().unwrap().columns[1].len());
+}
+
+#[test]
+fn selection_highlights_cell_and_targets() {
+    let mut app = helper::setup_app();
+    app.game_from_numeric_id(GameId(1));
+    app.handle_key_event(KeyCode::Char('a').into());
+    app.handle_key_event(KeyCode::Char('q').into());
+
+    app.handle_key_event(KeyCode::Char('q').into());
+
+    assert_eq!(vec![render::Highlight::Cell { i: 0 }], app.highlights());
+    assert_eq!(
+        vec![
+            render::Highlight::Cell { i: 1 },
+            render::Highlight::Cell { i: 2 },
+            render::Highlight::Cell { i: 3 },
+        ],
+        app.targets()
+    );
+}
+
+#[test]
+fn handle_key_event_settings_modal_rebind_and_save() {
+    let mut expected_config = helper::get_default_key_config();
+    expected_config.cell2 = 'x';
+
+    let mut key_config_mock = MockPersistKeyConfig::new();
+    key_config_mock
+        .expect_read()
+        .return_const(Ok(helper::get_default_key_config()));
+    key_config_mock
+        .expect_write()
+        .with(predicate::eq(expected_config.clone()))
+        .once()
+        .return_const(Ok(()));
+    let mut app = App::new(
+        key_config_mock,
+        helper::get_journey_repository(),
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
+
+    app.handle_key_event(KeyCode::F(6).into());
+    app.handle_key_event(KeyCode::Down.into());
+    app.handle_key_event(KeyCode::Enter.into());
+    assert_eq!(
+        app.app_state,
+        AppState::SettingsModal {
+            draft: Box::new(helper::get_default_key_config()),
+            selected: 1,
+            capturing: true,
+        }
+    );
+
+    // the next key is assigned, even one bound to an action
+    app.handle_key_event(KeyCode::Char('x').into());
+    assert_eq!(
+        app.app_state,
+        AppState::SettingsModal {
+            draft: Box::new(expected_config.clone()),
+            selected: 1,
+            capturing: false,
+        }
+    );
+    assert_eq!('w', app.key_config.cell2);
+
+    app.handle_key_event(KeyCode::Char('s').into());
+    assert_eq!(app.app_state, AppState::Base);
+    assert_eq!(expected_config, app.key_config);
+
+    app.game_from_numeric_id(GameId(1));
+    app.handle_key_event(KeyCode::Char('x').into());
+    assert_eq!(Some(Location::Cell { i: 1 }), app.move_from);
+}
+
+#[test]
+fn handle_key_event_settings_modal_capture() {
+    let mut app = helper::setup_app();
+    app.handle_key_event(KeyCode::F(6).into());
+
+    // a location needs a character
+    app.handle_key_event(KeyCode::Enter.into());
+    app.handle_key_event(KeyCode::F(9).into());
+    app.handle_key_event(KeyCode::Up.into());
+    assert_eq!(
+        app.app_state,
+        AppState::SettingsModal {
+            draft: Box::new(helper::get_default_key_config()),
+            selected: 0,
+            capturing: true,
+        }
+    );
+
+    // cancel
+    app.handle_key_event(KeyCode::Esc.into());
+    assert_eq!(
+        app.app_state,
+        AppState::SettingsModal {
+            draft: Box::new(helper::get_default_key_config()),
+            selected: 0,
+            capturing: false,
+        }
+    );
+
+    // actions take any key, including the one to quit
+    let entries = KeyConfig::default().entries();
+    let help = entries.iter().position(|(name, _)| name == "help").unwrap();
+    for _ in 0..help {
+        app.handle_key_event(KeyCode::Down.into());
+    }
+    app.handle_key_event(KeyCode::Enter.into());
+    let mut key: KeyEvent = KeyCode::Char('q').into();
+    key.modifiers = KeyModifiers::CONTROL;
+    app.handle_key_event(key);
+
+    let AppState::SettingsModal {
+        ref draft,
+        selected,
+        capturing,
+    } = app.app_state
+    else {
+        panic!("should be in the settings modal");
+    };
+    assert_eq!(help, selected);
+    assert!(!capturing);
+    assert_eq!(KeyBinding::Ctrl('q'), draft.actions.help);
+
+    // reset to the default
+    app.handle_key_event(KeyCode::Delete.into());
+    let AppState::SettingsModal { ref draft, .. } = app.app_state else {
+        panic!("should be in the settings modal");
+    };
+    assert_eq!(KeyBinding::F(1), draft.actions.help);
+
+    // the selection stays within the entries
+    for _ in 0..100 {
+        app.handle_key_event(KeyCode::Down.into());
+    }
+    let AppState::SettingsModal { selected, .. } = app.app_state else {
+        panic!("should be in the settings modal");
+    };
+    assert_eq!(entries.len() - 1, selected);
+
+    // closing discards the changes
+    app.handle_key_event(KeyCode::Enter.into());
+    app.handle_key_event(KeyCode::Char('0').into());
+    app.handle_key_event(KeyCode::Esc.into());
+    assert_eq!(app.app_state, AppState::Base);
+    assert_eq!(helper::get_default_key_config(), app.key_config);
+
+    app.handle_key_event(KeyCode::F(6).into());
+    let mut key: KeyEvent = KeyCode::Char('q').into();
+    key.modifiers = KeyModifiers::CONTROL;
+    app.handle_key_event(key);
+    assert_eq!(app.app_state, AppState::Exit);
+}
+
+#[test]
+fn settings_modal_with_conflict_does_not_save() {
+    let mut key_config_mock = MockPersistKeyConfig::new();
+    key_config_mock
+        .expect_read()
+        .return_const(Ok(helper::get_default_key_config()));
+    key_config_mock
+        .expect_write()
+        .with(predicate::eq(helper::get_default_key_config()))
+        .once()
+        .return_const(Ok(()));
+    let mut app = App::new(
+        key_config_mock,
+        helper::get_journey_repository(),
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
+
+    app.handle_key_event(KeyCode::F(6).into());
+    app.handle_key_event(KeyCode::Down.into());
+    app.handle_key_event(KeyCode::Enter.into());
+    app.handle_key_event(KeyCode::Char('q').into());
+    app.handle_key_event(KeyCode::Char('s').into());
+
+    assert!(matches!(app.app_state, AppState::SettingsModal { .. }));
+    assert_eq!('w', app.key_config.cell2);
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 50, 24));
+    app.render(buf.area, &mut buf);
+
+    let expected = vec![
+        "┏━━━━━━━━━━━━━━━━━ RSLibreCell ━━━━━━━━━━━━━━━━━━┓",
+        "┃ ┌───────────────── Settings ─────────────────┐ ┃",
+        "┃ │ <Left><Right> theme: dark ♣♦♥♠             │ ┃",
+        "┃ │ <a> suits: symbols                         │ ┃",
+        "┃ │ <Enter> to rebind, <Del> for default       │ ┃",
+        "┃ │                                            │ ┃",
+        "┃ │ cell1               <q>                    │ ┃",
+        "┃ │ cell2               <q>                    │ ┃",
+        "┃ │ cell3               <e>                    │ ┃",
+        "┃ │ cell4               <r>                    │ ┃",
+        "┃ │ foundation1         <u>                    │ ┃",
+        "┃ │ foundation2         <i>                    │ ┃",
+        "┃ │ foundation3         <o>                    │ ┃",
+        "┃ │ foundation4         <p>                    │ ┃",
+        "┃ │ column1             <a>                    │ ┃",
+        "┃ │ column2             <s>                    │ ┃",
+        "┃ │ column3             <d>                    │ ┃",
+        "┃ │ column4             <f>                    │ ┃",
+        "┃ │ column5             <j>                    │ ┃",
+        "┃ │                                            │ ┃",
+        "┃ │ The key <q> is used by cell1 and cell2.    │ ┃",
+        "┃ │                                            │ ┃",
+        "┃ └─────────── Save <s> Close <Esc> ───────────┘ ┃",
+        "┗━━━━━━━━━━━ Help <F1> Quit <CTRL-q> ━━━━━━━━━━━━┛",
+    ];
+    assert_eq!(expected, helper::lines(&buf));
+    assert_eq!(Color::Red, buf[(4, 6)].fg);
+    assert_eq!(Color::Red, buf[(4, 7)].fg);
+    assert!(buf[(4, 7)].modifier.contains(Modifier::REVERSED));
+    assert_eq!(Color::Reset, buf[(4, 8)].fg);
+
+    // resolving the conflict allows to save
+    app.handle_key_event(KeyCode::Delete.into());
+    app.handle_key_event(KeyCode::Char('s').into());
+
+    assert_eq!(app.app_state, AppState::Base);
+}
+
+#[test]
+fn failing_key_config_write_shows_error_modal() {
+    let mut key_config_mock = MockPersistKeyConfig::new();
+    key_config_mock
+        .expect_read()
+        .return_const(Ok(helper::get_default_key_config()));
+    key_config_mock
+        .expect_write()
+        .once()
+        .return_const(Err(RepositoryError::NoDataDirectory));
+    let mut app = App::new(
+        key_config_mock,
+        helper::get_journey_repository(),
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
+
+    app.handle_key_event(KeyCode::F(6).into());
+    app.handle_key_event(KeyCode::Enter.into());
+    app.handle_key_event(KeyCode::Char('x').into());
+    app.handle_key_event(KeyCode::Char('s').into());
+
+    assert_eq!(
+        app.app_state,
+        AppState::ErrorModal {
+            messages: vec![format!(
+                "{}. The keys are used, but were not saved.",
+                RepositoryError::NoDataDirectory
+            )],
+            next: Box::new(AppState::Base),
+        }
+    );
+    assert_eq!('x', app.key_config.cell1);
+}
+
+#[test]
+fn settings_modal_chooses_theme() {
+    let mut expected_config = helper::get_default_key_config();
+    expected_config.theme = Theme::FourColor;
+
+    let mut key_config_mock = MockPersistKeyConfig::new();
+    key_config_mock
+        .expect_read()
+        .return_const(Ok(helper::get_default_key_config()));
+    key_config_mock
+        .expect_write()
+        .with(predicate::eq(expected_config.clone()))
+        .once()
+        .return_const(Ok(()));
+    let mut app = App::new(
+        key_config_mock,
+        helper::get_journey_repository(),
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
+    app.game_from_numeric_id(GameId(1));
+
+    // the first row starts with J♦ and has J♣ in the fourth column
+    let mut buf = Buffer::empty(Rect::new(0, 0, 50, 24));
+    app.render(buf.area, &mut buf);
+    assert_eq!("♦", buf[(11, 4)].symbol());
+    assert_eq!("♣", buf[(23, 4)].symbol());
+    assert_eq!(Color::Red, buf[(11, 4)].fg);
+    assert_eq!(Color::Reset, buf[(23, 4)].fg);
+
+    app.handle_key_event(KeyCode::F(6).into());
+    app.handle_key_event(KeyCode::Left.into());
+    app.handle_key_event(KeyCode::Left.into());
+    app.handle_key_event(KeyCode::Right.into());
+    app.handle_key_event(KeyCode::Right.into());
+    app.handle_key_event(KeyCode::Left.into());
+
+    // the theme is previewed, but not yet used
+    app.render(buf.area, &mut buf);
+    assert_eq!(
+        "┃ │ <Left><Right> theme: four-color ♣♦♥♠       │ ┃",
+        helper::lines(&buf)[2]
+    );
+    assert_eq!(Color::LightBlue, buf[(36, 2)].fg);
+    assert_eq!(Color::Yellow, buf[(37, 2)].fg);
+    assert_eq!(Color::Red, buf[(38, 2)].fg);
+    assert_eq!(Color::Reset, buf[(39, 2)].fg);
+    assert_eq!(Theme::Dark, app.key_config.theme);
+
+    app.handle_key_event(KeyCode::Char('s').into());
+    assert_eq!(expected_config, app.key_config);
+
+    app.render(buf.area, &mut buf);
+    assert_eq!(Color::Yellow, buf[(11, 4)].fg);
+    assert_eq!(Color::LightBlue, buf[(23, 4)].fg);
+}
+
+#[test]
+fn settings_modal_switches_to_letters_for_suits() {
+    let mut expected_config = helper::get_default_key_config();
+    expected_config.ascii = true;
+
+    let mut key_config_mock = MockPersistKeyConfig::new();
+    key_config_mock
+        .expect_read()
+        .return_const(Ok(helper::get_default_key_config()));
+    key_config_mock
+        .expect_write()
+        .with(predicate::eq(expected_config.clone()))
+        .once()
+        .return_const(Ok(()));
+    let mut app = App::new(
+        key_config_mock,
+        helper::get_journey_repository(),
+        helper::get_game_repository(),
+        helper::get_stats_repository(),
+    );
+    app.game_from_numeric_id(GameId(1));
+
+    app.handle_key_event(KeyCode::F(6).into());
+    app.handle_key_event(KeyCode::Char('a').into());
+
+    // the letters are previewed, but not yet used
+    let mut buf = Buffer::empty(Rect::new(0, 0, 50, 24));
+    app.render(buf.area, &mut buf);
+    assert_eq!(
+        "┃ │ <Left><Right> theme: dark CDHS             │ ┃",
+        helper::lines(&buf)[2]
+    );
+    assert_eq!(
+        "┃ │ <a> suits: letters                         │ ┃",
+        helper::lines(&buf)[3]
+    );
+    assert_eq!(Color::Red, buf[(31, 2)].fg);
+    assert!(!app.key_config.ascii);
+
+    app.handle_key_event(KeyCode::Char('s').into());
+    assert_eq!(expected_config, app.key_config);
+
+    // the first row starts with J♦ and has J♣ in the fourth column
+    app.render(buf.area, &mut buf);
+    assert_eq!("D", buf[(11, 4)].symbol());
+    assert_eq!("C", buf[(23, 4)].symbol());
+    assert_eq!(Color::Red, buf[(11, 4)].fg);
+    assert_eq!(Color::Reset, buf[(23, 4)].fg);
+}
+
+#[test]
+fn palettes_distinguish_suits_and_keep_highlights() {
+    for theme in Theme::ALL {
+        let palette = render::Palette::of(theme);
+
+        assert_ne!(palette.clubs, palette.diamonds);
+        assert_ne!(palette.spades, palette.hearts);
+        assert!(palette.highlight.add_modifier.contains(Modifier::REVERSED));
+        assert!(palette.target.add_modifier.contains(Modifier::UNDERLINED));
+    }
+
+    let four_color = render::Palette::of(Theme::FourColor);
+    let mut colors = vec![
+        four_color.clubs,
+        four_color.diamonds,
+        four_color.hearts,
+        four_color.spades,
+    ];
+    colors.dedup();
+    assert_eq!(4, colors.len());
+}
+
+#[test]
+fn handle_key_event_hint_shows_status_until_computed() {
+    let mut app = helper::setup_app();
+    app.game_from_numeric_id(GameId(1));
+
+    app.handle_key_event(KeyCode::Char('h').into());
+
+    assert_eq!("Looking for a hint...", app.status());
+
+    helper::wait_for_hint(&mut app);
+
+    assert_eq!("Hint: <k> then <q>", app.status());
+}
+
+#[test]
+fn handle_key_event_hint_is_dropped_after_move() {
+    let mut app = helper::setup_app();
+    app.game_from_numeric_id(GameId(1));
+
+    app.handle_key_event(KeyCode::Char('h').into());
+    app.handle_key_event(KeyCode::Char('k').into());
+    app.handle_key_event(KeyCode::Char('q').into());
+    helper::wait_for_hint(&mut app);
+
+    assert!(app.hint.is_none());
+}
+
+mod helper {
+    use super::*;
+
+    pub fn wait_for_hint<T, U, V, W>(app: &mut App<T, U, V, W>)
+    where
+        T: PersistJourney + Debug,
+        U: PersistGame + Debug,
+        V: PersistStats + Debug,
+        W: PersistKeyConfig + Debug,
+    {
+        while app.pending_hint.is_some() {
+            std::thread::sleep(Duration::from_millis(10));
+            app.tick(Instant::now());
+        }
+    }
+
+    pub fn setup_app(
+    ) -> App<MockPersistJourney, MockPersistGame, MockPersistStats, MockPersistKeyConfig> {
+        let key_config = get_default_key_config();
+        App::new(
+            helper::key_config_repository(Ok(key_config)),
+            helper::get_journey_repository(),
+            helper::get_game_repository(),
+            helper::get_stats_repository(),
+        )
+    }
+
+    pub fn get_key_config_repository() -> MockPersistKeyConfig {
+        key_config_repository(Ok(get_default_key_config()))
+    }
+
+    pub fn key_config_repository(
+        key_config: Result<KeyConfig, RepositoryError>,
+    ) -> MockPersistKeyConfig {
+        let mut mock = MockPersistKeyConfig::new();
+        mock.expect_read().return_const(key_config);
+        mock.expect_write().return_const(Ok(()));
+        mock
+    }
+
+    pub fn get_journey_repository() -> MockPersistJourney {
+        let mut mock = MockPersistJourney::new();
+        mock.expect_check_lock().return_const(Ok(()));
+        mock.expect_read()
+            .return_const(Ok((GameId(123), vec![GameId(117), GameId(118)])));
+        mock.expect_write().return_const(Ok(()));
+        mock
+    }
+
+    pub fn get_game_repository() -> MockPersistGame {
+        let mut mock = MockPersistGame::new();
+        mock.expect_read().return_const(Ok(None));
+        mock.expect_write().return_const(Ok(()));
+        mock.expect_clear().return_const(Ok(()));
+        mock
+    }
+
+    pub fn get_stats_repository() -> MockPersistStats {
+        let mut mock = MockPersistStats::new();
+        mock.expect_read().return_const(Ok(vec![]));
+        mock.expect_write().return_const(Ok(()));
+        mock
+    }
+
+    pub fn lines(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    pub fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    pub fn click<T, U, V, W>(app: &mut App<T, U, V, W>, column: u16, row: u16)
+    where
+        T: PersistJourney,
+        T: Debug,
+        U: PersistGame,
+        U: Debug,
+        V: PersistStats,
+        V: Debug,
+        W: PersistKeyConfig,
+        W: Debug,
+    {
+        app.handle_mouse_event(mouse(MouseEventKind::Down(MouseButton::Left), column, row));
+        app.handle_mouse_event(mouse(MouseEventKind::Up(MouseButton::Left), column, row));
+    }
+
+    pub fn id_entry(digits: &str) -> [u8; ID_LENGTH] {
+        let mut result = [SPACE_ASCII_CODE; ID_LENGTH];
+        result[ID_LENGTH - digits.len()..].copy_from_slice(digits.as_bytes());
+        result
     }
 
     pub fn get_default_key_config() -> KeyConfig {
@@ -1970,6 +4366,9 @@ mod helper {
             column6: 'k',
             column7: 'l',
             column8: 'ö',
+            actions: ActionKeys::default(),
+            theme: Theme::Dark,
+            ascii: false,
         }
     }
 }
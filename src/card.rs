@@ -75,13 +75,13 @@ impl TryFrom<u8> for Rank {
 /// The suit of a card.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Suit {
-    /// Suit of clubs, ♣.
+    /// Suit of clubs, ♣ or C.
     Clubs = 0,
-    /// Suit of diamonds, ♦.
+    /// Suit of diamonds, ♦ or D.
     Diamonds = 1,
-    /// Suit of hearts, ♥.
+    /// Suit of hearts, ♥ or H.
     Hearts = 2,
-    /// Suit of spades, ♠.
+    /// Suit of spades, ♠ or S.
     Spades = 3,
 }
 
@@ -130,7 +130,7 @@ impl Card {
     /// Creates a card from its string representation.
     ///
     /// The string representation must be a valid rank identifier
-    /// followed by a valid suit symbol, such as `"8♠"` or `"K♦"`.
+    /// followed by a valid suit symbol or letter, such as `"8♠"` or `"KD"`.
     ///
     /// # Panics
     /// The method will panic if given an invalid string.
@@ -141,6 +141,10 @@ impl Card {
 }
 
 impl fmt::Display for Card {
+    /// Provides the rank identifier followed by the suit symbol, such as `"8♠"`.
+    ///
+    /// The alternate form `{:#}` uses the letters C, D, H, S instead, such as `"8S"`,
+    /// for terminals without the symbols.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let rank = match self.rank {
             Rank::Ace => "A",
@@ -158,11 +162,15 @@ impl fmt::Display for Card {
             Rank::King => "K",
         };
 
-        let suit = match self.suit {
-            Suit::Clubs => "♣",
-            Suit::Diamonds => "♦",
-            Suit::Hearts => "♥",
-            Suit::Spades => "♠",
+        let suit = match (self.suit, f.alternate()) {
+            (Suit::Clubs, false) => "♣",
+            (Suit::Diamonds, false) => "♦",
+            (Suit::Hearts, false) => "♥",
+            (Suit::Spades, false) => "♠",
+            (Suit::Clubs, true) => "C",
+            (Suit::Diamonds, true) => "D",
+            (Suit::Hearts, true) => "H",
+            (Suit::Spades, true) => "S",
         };
 
         write!(f, "{}{}", rank, suit)
@@ -175,15 +183,15 @@ impl TryFrom<&str> for Card {
     /// Tries to create a card fomr its string representation.
     ///
     /// The string representation must be a valid rank identifier
-    /// followed by a valid suit symbol, such as `"8♠"` or `"K♦"`.
+    /// followed by a valid suit symbol or letter, such as `"8♠"` or `"KD"`.
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if value.len() < 3 {
-            return Err(());
-        }
-
         let mut chars = value.chars();
 
-        let rank = match chars.next().unwrap() {
+        let (Some(rank), Some(suit)) = (chars.next(), chars.next()) else {
+            return Err(());
+        };
+
+        let rank = match rank {
             'A' => Rank::Ace,
             '2' => Rank::Two,
             '3' => Rank::Three,
@@ -200,11 +208,11 @@ impl TryFrom<&str> for Card {
             _ => return Err(()),
         };
 
-        let suit = match chars.next().unwrap() {
-            '♣' => Suit::Clubs,
-            '♦' => Suit::Diamonds,
-            '♥' => Suit::Hearts,
-            '♠' => Suit::Spades,
+        let suit = match suit {
+            '♣' | 'C' => Suit::Clubs,
+            '♦' | 'D' => Suit::Diamonds,
+            '♥' | 'H' => Suit::Hearts,
+            '♠' | 'S' => Suit::Spades,
             _ => return Err(()),
         };
 
@@ -58,6 +58,14 @@ fn card_display_trait_works() {
     assert_eq!(card2.to_string(), "J♥");
 }
 
+#[test]
+fn card_display_trait_alternate_uses_letters() {
+    assert_eq!(format!("{:#}", Card::from_id(0)), "AC");
+    assert_eq!(format!("{:#}", Card::from_id(42)), "JH");
+    assert_eq!(format!("{:#}", Card::from_id(37)), "TD");
+    assert_eq!(format!("{:#}", Card::from_id(51)), "KS");
+}
+
 #[test]
 fn card_tryfrom_ref_str_with_unicode_representation_works() {
     let card1 = Card::try_from("T♣").unwrap();
@@ -69,17 +77,30 @@ fn card_tryfrom_ref_str_with_unicode_representation_works() {
     assert_eq!(Card::from_id(42), card2);
 }
 
+#[test]
+fn card_tryfrom_ref_str_with_ascii_representation_works() {
+    assert_eq!(Card::from_id(36), Card::try_from("TC").unwrap());
+    assert_eq!(Card::from_id(42), Card::try_from("JH").unwrap());
+    assert_eq!(Card::from_id(37), Card::try_from("TD").unwrap());
+    assert_eq!(Card::from_id(51), Card::try_from("KS").unwrap());
+}
+
 #[test]
 fn card_tryfrom_with_short_string_errors() {
-    let _ = Card::try_from("T").expect_err("should have error");
+    assert!(Card::try_from("T").is_err());
 }
 
 #[test]
 fn card_tryfrom_with_bad_input1_errors() {
-    let _ = Card::try_from("R♣").expect_err("should have error");
+    assert!(Card::try_from("R♣").is_err());
 }
 
 #[test]
 fn card_tryfrom_with_bad_input2_errors() {
-    let _ = Card::try_from("T?").expect_err("should have error");
+    assert!(Card::try_from("T?").is_err());
+}
+
+#[test]
+fn card_tryfrom_with_lowercase_letter_errors() {
+    assert!(Card::try_from("Tc").is_err());
 }
@@ -0,0 +1,191 @@
+/*
+    RSLibreCell - a FreeCell implementation
+    Copyright (C) 2025 and later: tristhaus
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use rslibrecell::{
+    game::{Game, GameId, MAX_GAME_ID},
+    r#move::{make_move, Move},
+    solver::{solve, Solution, DEFAULT_BUDGET},
+};
+
+/// The text describing the usage of the command line.
+pub const USAGE: &str = concat!(
+    "Usage: rslibrecell [COMMAND]\n",
+    "\n",
+    "Without a command, the game is started in the terminal.\n",
+    "\n",
+    "Commands:\n",
+    "  print <id>           Print the game with the given ID\n",
+    "  solve <id|file>      Print a solution for the game with the given ID\n",
+    "                       or the game contained in the given file\n",
+    "  validate <file>      Check the game contained in the given file\n",
+    "  replay <id> <moves>  Make the moves, e.g. \"3a 1h\", on the game\n",
+    "                       with the given ID and print the final game\n",
+    "  help                 Print this text\n",
+    "\n",
+    "Options:\n",
+    "  --ascii              Print the suits as the letters C, D, H, S;\n",
+    "                       given to 'print' or 'replay' before the ID\n",
+);
+
+/// A command given on the command line.
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    /// Print the game with the given ID, with letters for the suits if set.
+    Print(GameId, bool),
+    /// Print a solution for the given game.
+    Solve(Source),
+    /// Check the game contained in the given file.
+    Validate(PathBuf),
+    /// Make the moves on the game with the given ID and print the final game,
+    /// with letters for the suits if set.
+    Replay(GameId, Vec<Move>, bool),
+    /// Print the usage text.
+    Help,
+}
+
+/// The source of a game given on the command line.
+#[derive(Debug, PartialEq)]
+pub enum Source {
+    /// The game with the given ID.
+    Id(GameId),
+    /// The game contained in the given file, in its canonical representation.
+    File(PathBuf),
+}
+
+/// Parses the command line arguments, excluding the name of the program.
+///
+/// Returns `None` if there are no arguments, *i.e.* the game should be started.
+pub fn parse(args: &[String]) -> Result<Option<Command>, String> {
+    let (name, args) = match args.split_first() {
+        None => return Ok(None),
+        Some(x) => x,
+    };
+
+    let command = match (name.as_str(), args) {
+        ("print", [flag, id]) if flag == "--ascii" => Command::Print(parse_id(id)?, true),
+        ("print", [id]) => Command::Print(parse_id(id)?, false),
+        ("solve", [source]) => {
+            if source.chars().all(|c| c.is_ascii_digit()) {
+                Command::Solve(Source::Id(parse_id(source)?))
+            } else {
+                Command::Solve(Source::File(PathBuf::from(source)))
+            }
+        }
+        ("validate", [file]) => Command::Validate(PathBuf::from(file)),
+        ("replay", [flag, id, moves @ ..]) if flag == "--ascii" => {
+            Command::Replay(parse_id(id)?, parse_moves(moves)?, true)
+        }
+        ("replay", [id, moves @ ..]) => Command::Replay(parse_id(id)?, parse_moves(moves)?, false),
+        ("help" | "--help" | "-h", []) => Command::Help,
+        ("print" | "solve" | "validate" | "replay" | "help", _) => {
+            return Err(format!("Wrong number of arguments for '{}'", name))
+        }
+        _ => return Err(format!("Unknown command '{}'", name)),
+    };
+
+    Ok(Some(command))
+}
+
+/// Executes the command, writing its regular output to `out`.
+///
+/// Returns a message describing the problem in case of failure.
+pub fn execute(command: &Command, out: &mut impl Write) -> Result<(), String> {
+    let output = match command {
+        Command::Print(id, ascii) => show(&Game::from_id(id.clone()), *ascii),
+        Command::Solve(source) => {
+            let game = match source {
+                Source::Id(id) => Game::from_id(id.clone()),
+                Source::File(file) => read_game(file)?,
+            };
+
+            match solve(&game, DEFAULT_BUDGET) {
+                Solution::Solved(moves) => {
+                    let moves: Vec<String> = moves.iter().map(|x| x.to_string()).collect();
+                    format!("{}\n", moves.join(" "))
+                }
+                Solution::Unsolvable => return Err(String::from("The game cannot be won")),
+                Solution::BudgetExhausted => {
+                    return Err(String::from("No solution was found within the budget"))
+                }
+            }
+        }
+        Command::Validate(file) => {
+            let game = read_game(file)?;
+            format!("Valid game #{}\n", game.id.0)
+        }
+        Command::Replay(id, moves, ascii) => {
+            let mut game = Game::from_id(id.clone());
+
+            for (i, mv) in moves.iter().enumerate() {
+                game = make_move(&game, mv.clone())
+                    .map_err(|error| format!("Move {} ({}) failed: {}", i + 1, mv, error))?;
+            }
+
+            show(&game, *ascii)
+        }
+        Command::Help => String::from(USAGE),
+    };
+
+    out.write_all(output.as_bytes())
+        .map_err(|error| format!("Cannot write output: {}", error))
+}
+
+/// Provides the canonical representation of the game, with letters for the suits if `ascii`.
+fn show(game: &Game, ascii: bool) -> String {
+    if ascii {
+        format!("{:#}", game)
+    } else {
+        game.to_string()
+    }
+}
+
+fn parse_id(id: &str) -> Result<GameId, String> {
+    match id.parse::<u64>() {
+        Ok(value) if 0 < value && value <= MAX_GAME_ID => Ok(GameId(value)),
+        _ => Err(format!(
+            "Invalid game ID '{}', expected 1 to {}",
+            id, MAX_GAME_ID
+        )),
+    }
+}
+
+/// Parses moves in their standard notation, which may be given
+/// as separate arguments or separated by whitespace within an argument.
+fn parse_moves(args: &[String]) -> Result<Vec<Move>, String> {
+    args.iter()
+        .flat_map(|x| x.split_whitespace())
+        .map(|x| Move::try_from(x).map_err(|_| format!("Invalid move '{}'", x)))
+        .collect()
+}
+
+fn read_game(file: &Path) -> Result<Game, String> {
+    let content = fs::read_to_string(file)
+        .map_err(|error| format!("Cannot read '{}': {}", file.display(), error))?;
+
+    Game::try_from(content.as_str())
+        .map_err(|_| format!("'{}' does not contain a valid game", file.display()))
+}
+
+#[cfg(test)]
+mod test;
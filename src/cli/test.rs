@@ -0,0 +1,279 @@
+/*
+    RSLibreCell - a FreeCell implementation
+    Copyright (C) 2025 and later: tristhaus
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use super::*;
+
+mod helper {
+    use super::*;
+
+    pub fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|x| x.to_string()).collect()
+    }
+
+    pub fn execute_to_string(command: &Command) -> Result<String, String> {
+        let mut out: Vec<u8> = vec![];
+        execute(command, &mut out)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    pub fn temp_file(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("rslibrecell_cli_test_{}.txt", name));
+        fs::write(&path, content).unwrap();
+        path
+    }
+}
+
+#[test]
+fn parse_without_arguments_starts_game() {
+    assert_eq!(Ok(None), parse(&[]));
+}
+
+#[test]
+fn parse_print_works() {
+    assert_eq!(
+        Ok(Some(Command::Print(GameId(617), false))),
+        parse(&helper::args(&["print", "617"]))
+    );
+    assert_eq!(
+        Ok(Some(Command::Print(GameId(MAX_GAME_ID), false))),
+        parse(&helper::args(&["print", "8589934591"]))
+    );
+    assert_eq!(
+        Ok(Some(Command::Print(GameId(617), true))),
+        parse(&helper::args(&["print", "--ascii", "617"]))
+    );
+}
+
+#[test]
+fn parse_print_with_bad_id_errors() {
+    assert!(parse(&helper::args(&["print", "0"])).is_err());
+    assert!(parse(&helper::args(&["print", "8589934592"])).is_err());
+    assert!(parse(&helper::args(&["print", "-1"])).is_err());
+    assert!(parse(&helper::args(&["print", "abc"])).is_err());
+}
+
+#[test]
+fn parse_solve_distinguishes_id_and_file() {
+    assert_eq!(
+        Ok(Some(Command::Solve(Source::Id(GameId(1))))),
+        parse(&helper::args(&["solve", "1"]))
+    );
+    assert_eq!(
+        Ok(Some(Command::Solve(Source::File(PathBuf::from(
+            "board.txt"
+        ))))),
+        parse(&helper::args(&["solve", "board.txt"]))
+    );
+}
+
+#[test]
+fn parse_validate_works() {
+    assert_eq!(
+        Ok(Some(Command::Validate(PathBuf::from("board.txt")))),
+        parse(&helper::args(&["validate", "board.txt"]))
+    );
+}
+
+#[test]
+fn parse_replay_accepts_moves_in_one_or_several_arguments() {
+    let expected = Ok(Some(Command::Replay(
+        GameId(1),
+        vec![
+            Move::try_from("3a").unwrap(),
+            Move::try_from("1h").unwrap(),
+            Move::try_from("b5").unwrap(),
+        ],
+        false,
+    )));
+
+    assert_eq!(expected, parse(&helper::args(&["replay", "1", "3a 1h b5"])));
+    assert_eq!(
+        expected,
+        parse(&helper::args(&["replay", "1", "3a", "1h", "b5"]))
+    );
+    assert_eq!(
+        Ok(Some(Command::Replay(GameId(1), vec![], false))),
+        parse(&helper::args(&["replay", "1"]))
+    );
+    assert_eq!(
+        Ok(Some(Command::Replay(
+            GameId(1),
+            vec![Move::try_from("3a").unwrap()],
+            true
+        ))),
+        parse(&helper::args(&["replay", "--ascii", "1", "3a"]))
+    );
+}
+
+#[test]
+fn parse_replay_with_bad_move_errors() {
+    assert_eq!(
+        Err(String::from("Invalid move '9a'")),
+        parse(&helper::args(&["replay", "1", "3a 9a"]))
+    );
+}
+
+#[test]
+fn parse_with_wrong_number_of_arguments_errors() {
+    assert!(parse(&helper::args(&["print"])).is_err());
+    assert!(parse(&helper::args(&["print", "1", "2"])).is_err());
+    assert!(parse(&helper::args(&["print", "--ascii"])).is_err());
+    assert!(parse(&helper::args(&["print", "1", "--ascii"])).is_err());
+    assert!(parse(&helper::args(&["solve"])).is_err());
+    assert!(parse(&helper::args(&["validate"])).is_err());
+    assert!(parse(&helper::args(&["replay"])).is_err());
+    assert!(parse(&helper::args(&["help", "print"])).is_err());
+}
+
+#[test]
+fn parse_unknown_command_errors() {
+    assert_eq!(
+        Err(String::from("Unknown command 'play'")),
+        parse(&helper::args(&["play", "1"]))
+    );
+}
+
+#[test]
+fn execute_print_works() {
+    let actual = helper::execute_to_string(&Command::Print(GameId(617), false)).unwrap();
+
+    assert_eq!(Game::from_id(GameId(617)).to_string(), actual);
+}
+
+#[test]
+fn execute_print_with_ascii_uses_letters() {
+    let actual = helper::execute_to_string(&Command::Print(GameId(617), true)).unwrap();
+
+    assert_eq!(format!("{:#}", Game::from_id(GameId(617))), actual);
+    assert!(actual.is_ascii());
+}
+
+#[test]
+fn execute_solve_prints_winning_moves() {
+    let output = helper::execute_to_string(&Command::Solve(Source::Id(GameId(1)))).unwrap();
+
+    let mut game = Game::from_id(GameId(1));
+    for mv in output.split_whitespace() {
+        game = make_move(&game, Move::try_from(mv).unwrap()).unwrap();
+    }
+
+    assert!(game.is_won());
+}
+
+#[test]
+fn execute_solve_from_file_works() {
+    let path = helper::temp_file("solve", &Game::from_id(GameId(617)).to_string());
+
+    let output = helper::execute_to_string(&Command::Solve(Source::File(path.clone())));
+
+    fs::remove_file(path).unwrap();
+
+    assert!(output.is_ok_and(|x| !x.trim().is_empty()));
+}
+
+#[test]
+fn execute_validate_works() {
+    let path = helper::temp_file("validate_valid", &Game::from_id(GameId(42)).to_string());
+
+    let actual = helper::execute_to_string(&Command::Validate(path.clone()));
+
+    fs::remove_file(path).unwrap();
+
+    assert_eq!(Ok(String::from("Valid game #42\n")), actual);
+}
+
+#[test]
+fn execute_validate_accepts_letters() {
+    let path = helper::temp_file(
+        "validate_letters",
+        &format!("{:#}", Game::from_id(GameId(42))),
+    );
+
+    let actual = helper::execute_to_string(&Command::Validate(path.clone()));
+
+    fs::remove_file(path).unwrap();
+
+    assert_eq!(Ok(String::from("Valid game #42\n")), actual);
+}
+
+#[test]
+fn execute_validate_with_invalid_game_errors() {
+    let content = Game::from_id(GameId(42)).to_string().replace("K♠", "K♥");
+    let path = helper::temp_file("validate_invalid", &content);
+
+    let actual = helper::execute_to_string(&Command::Validate(path.clone()));
+
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        Err(format!(
+            "'{}' does not contain a valid game",
+            path.display()
+        )),
+        actual
+    );
+}
+
+#[test]
+fn execute_validate_with_missing_file_errors() {
+    let path = std::env::temp_dir().join("rslibrecell_cli_test_missing.txt");
+
+    let actual = helper::execute_to_string(&Command::Validate(path));
+
+    assert!(actual.is_err_and(|x| x.starts_with("Cannot read")));
+}
+
+#[test]
+fn execute_replay_works() {
+    let moves = vec![Move::try_from("3a").unwrap(), Move::try_from("3b").unwrap()];
+
+    let actual =
+        helper::execute_to_string(&Command::Replay(GameId(1), moves.clone(), false)).unwrap();
+
+    let mut expected = Game::from_id(GameId(1));
+    for mv in moves {
+        expected = make_move(&expected, mv).unwrap();
+    }
+
+    assert_eq!(expected.to_string(), actual);
+    assert!(actual.starts_with(concat!(
+        "RustLibreCell                  #1 \n",
+        "\n",
+        " 2♥  8♦  ..  .. || ..  ..  ..  .. \n",
+    )));
+}
+
+#[test]
+fn execute_replay_with_illegal_move_errors() {
+    let moves = vec![Move::try_from("3a").unwrap(), Move::try_from("a1").unwrap()];
+
+    let actual = helper::execute_to_string(&Command::Replay(GameId(1), moves, false));
+
+    assert_eq!(
+        Err(String::from("Move 2 (a1) failed: Ranks do not match")),
+        actual
+    );
+}
+
+#[test]
+fn execute_help_prints_usage() {
+    assert_eq!(
+        Ok(String::from(USAGE)),
+        helper::execute_to_string(&Command::Help)
+    );
+}
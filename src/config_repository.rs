@@ -1,5 +1,3 @@
-use std::io::Read;
-
 /*
     RSLibreCell - a FreeCell implementation
     Copyright (C) 2025 and later: tristhaus
@@ -17,10 +15,235 @@ use std::io::Read;
     You should have received a copy of the GNU General Public License
     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
+use std::{fmt, path::PathBuf};
+
+use mockall::automock;
 use serde::{Deserialize, Serialize};
 
+use crate::repository_error::RepositoryError;
+
+#[cfg(test)]
+mod test;
+
+/// Defines a key that triggers an action.
+///
+/// In the config file, a key is written as a single character, `Space`,
+/// a function key such as `F1`, or a character held with control such as `CTRL-q`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum KeyBinding {
+    /// A character, including the space.
+    Char(char),
+    /// A character pressed while holding control.
+    Ctrl(char),
+    /// A function key, given by its number.
+    F(u8),
+}
+
+impl fmt::Display for KeyBinding {
+    /// Provides the representation used in the config file and the help.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyBinding::Char(' ') => write!(f, "Space"),
+            KeyBinding::Char(char) => write!(f, "{}", char),
+            KeyBinding::Ctrl(char) => write!(f, "CTRL-{}", char),
+            KeyBinding::F(number) => write!(f, "F{}", number),
+        }
+    }
+}
+
+impl TryFrom<&str> for KeyBinding {
+    type Error = String;
+
+    /// Parses the representation used in the config file.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let single_char = |value: &str| {
+            let mut chars = value.chars();
+            match (chars.next(), chars.next()) {
+                (Some(char), None) => Some(char),
+                _ => None,
+            }
+        };
+
+        if let Some(char) = single_char(value) {
+            return Ok(KeyBinding::Char(char));
+        }
+
+        if value.eq_ignore_ascii_case("space") {
+            return Ok(KeyBinding::Char(' '));
+        }
+
+        if let Some(char) = value
+            .get(..5)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("ctrl-"))
+            .and_then(|_| single_char(&value[5..]))
+        {
+            return Ok(KeyBinding::Ctrl(char));
+        }
+
+        if let Some(number) = value
+            .strip_prefix(['F', 'f'])
+            .and_then(|number| number.parse::<u8>().ok())
+            .filter(|number| (1..=12).contains(number))
+        {
+            return Ok(KeyBinding::F(number));
+        }
+
+        Err(format!("invalid key: {}", value))
+    }
+}
+
+impl TryFrom<String> for KeyBinding {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        KeyBinding::try_from(value.as_str())
+    }
+}
+
+impl From<KeyBinding> for String {
+    fn from(value: KeyBinding) -> Self {
+        value.to_string()
+    }
+}
+
+/// Defines the keys triggering the actions apart from choosing a location.
+///
+/// Keys missing in the config file take their default.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct ActionKeys {
+    /// The key to quit the application, available everywhere.
+    pub quit: KeyBinding,
+    /// The key to open the Help box.
+    pub help: KeyBinding,
+    /// The key to open the About box.
+    pub about: KeyBinding,
+    /// The key to start a random game.
+    pub random_game: KeyBinding,
+    /// The key to choose a game by its ID.
+    pub select_by_id: KeyBinding,
+    /// The key to open the Journey box.
+    pub journey: KeyBinding,
+    /// The key to open the History box.
+    pub history: KeyBinding,
+    /// The key to open the Statistics box.
+    pub statistics: KeyBinding,
+    /// The key to open the Settings box.
+    pub settings: KeyBinding,
+    /// The key to abort a move.
+    pub clear: KeyBinding,
+    /// The key to revert the last move.
+    pub revert: KeyBinding,
+    /// The key to redo a reverted move.
+    pub redo: KeyBinding,
+    /// The key to get a hint.
+    pub hint: KeyBinding,
+    /// The key to start the next game in the Journey box.
+    pub journey_next: KeyBinding,
+    /// The key to skip the next game in the Journey box.
+    pub journey_skip: KeyBinding,
+    /// The keys to start the skipped games shown in the Journey box, in order.
+    pub journey_skipped: [KeyBinding; 8],
+    /// The key to resume the saved game offered on start.
+    pub resume_yes: KeyBinding,
+    /// The key to discard the saved game offered on start.
+    pub resume_no: KeyBinding,
+    /// The key to name a checkpoint in the History box.
+    pub history_checkpoint: KeyBinding,
+    /// The keys to switch to the branches shown in the History box, in order.
+    pub history_branches: [KeyBinding; 9],
+    /// The keys to jump to the checkpoints shown in the History box, in order.
+    pub history_checkpoints: [KeyBinding; 10],
+    /// The key to switch between suit symbols and letters in the Settings box.
+    pub settings_suits: KeyBinding,
+    /// The key to save the keys in the Settings box.
+    pub settings_save: KeyBinding,
+}
+
+impl Default for ActionKeys {
+    /// Provides the keys used before they became configurable.
+    fn default() -> Self {
+        ActionKeys {
+            quit: KeyBinding::Ctrl('q'),
+            help: KeyBinding::F(1),
+            about: KeyBinding::F(12),
+            random_game: KeyBinding::F(2),
+            select_by_id: KeyBinding::F(3),
+            journey: KeyBinding::Char('!'),
+            history: KeyBinding::F(4),
+            statistics: KeyBinding::F(5),
+            settings: KeyBinding::F(6),
+            clear: KeyBinding::Char(' '),
+            revert: KeyBinding::Char('R'),
+            redo: KeyBinding::Char('Y'),
+            hint: KeyBinding::Char('h'),
+            journey_next: KeyBinding::Char('1'),
+            journey_skip: KeyBinding::Char('s'),
+            journey_skipped: ['2', '3', '4', '5', '6', '7', '8', '9'].map(KeyBinding::Char),
+            resume_yes: KeyBinding::Char('y'),
+            resume_no: KeyBinding::Char('n'),
+            history_checkpoint: KeyBinding::Char('+'),
+            history_branches: ['1', '2', '3', '4', '5', '6', '7', '8', '9'].map(KeyBinding::Char),
+            history_checkpoints: ['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j']
+                .map(KeyBinding::Char),
+            settings_suits: KeyBinding::Char('a'),
+            settings_save: KeyBinding::Char('s'),
+        }
+    }
+}
+
+/// Defines the named color themes.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum Theme {
+    /// Red and default colored suits, as suited for a dark background.
+    #[default]
+    Dark,
+    /// Red and black suits, as suited for a light background.
+    Light,
+    /// Bright colors and bold highlights.
+    HighContrast,
+    /// A distinct color for each suit, which does not depend on telling red from green.
+    FourColor,
+}
+
+impl Theme {
+    /// All themes, in the order they are offered.
+    pub const ALL: [Theme; 4] = [
+        Theme::Dark,
+        Theme::Light,
+        Theme::HighContrast,
+        Theme::FourColor,
+    ];
+
+    /// Gets the theme offered after this one, wrapping around.
+    pub fn next(self) -> Theme {
+        let i = Theme::ALL.iter().position(|x| *x == self).unwrap();
+        Theme::ALL[(i + 1) % Theme::ALL.len()]
+    }
+
+    /// Gets the theme offered before this one, wrapping around.
+    pub fn previous(self) -> Theme {
+        let i = Theme::ALL.iter().position(|x| *x == self).unwrap();
+        Theme::ALL[(i + Theme::ALL.len() - 1) % Theme::ALL.len()]
+    }
+}
+
+impl fmt::Display for Theme {
+    /// Provides the name used in the config file.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Theme::Dark => write!(f, "dark"),
+            Theme::Light => write!(f, "light"),
+            Theme::HighContrast => write!(f, "high-contrast"),
+            Theme::FourColor => write!(f, "four-color"),
+        }
+    }
+}
+
 /// Defines the key config to play the actual game.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct KeyConfig {
     /// The key referring to the first cell.
     pub cell1: char,
@@ -54,24 +277,22 @@ pub struct KeyConfig {
     pub column7: char,
     /// The key referring to the eighth column.
     pub column8: char,
+    /// The keys triggering the other actions.
+    #[serde(default)]
+    pub actions: ActionKeys,
+    /// The color theme, which is kept with the keys.
+    #[serde(default)]
+    pub theme: Theme,
+    /// Whether the suits are shown as the letters C, D, H, S
+    /// instead of symbols, for terminals lacking the latter.
+    #[serde(default)]
+    pub ascii: bool,
 }
 
-/// Gets the config, either from disk or the default.
-pub fn get_config() -> KeyConfig {
-    let path = get_key_config_path();
-
-    if let Ok(true) = std::fs::exists(&path) {
-        let mut file =
-            std::fs::File::open(&path).expect("unable to open key config from 'key_config.json'");
-        let mut data = String::new();
-        file.read_to_string(&mut data)
-            .expect("unable to read key config string data from 'key_config.json'");
-
-        let deserialized =
-            serde_json::from_str(&data).expect("unable to parse key config from 'key_config.json'");
-
-        deserialized
-    } else {
+impl Default for KeyConfig {
+    /// Provides the home row of a QWERTY US keyboard for the columns,
+    /// and the keys above for the cells and foundations.
+    fn default() -> Self {
         KeyConfig {
             cell1: 'q',
             cell2: 'w',
@@ -89,15 +310,439 @@ pub fn get_config() -> KeyConfig {
             column6: 'k',
             column7: 'l',
             column8: ';',
+            actions: ActionKeys::default(),
+            theme: Theme::default(),
+            ascii: false,
+        }
+    }
+}
+
+/// Describes a problem with the keys of a config, see `KeyConfig::validated`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum KeyConfigProblem {
+    /// Several entries use the same key where they are interpreted together.
+    Conflict {
+        /// The key in question.
+        key: KeyBinding,
+        /// The names of the entries using the key.
+        names: Vec<String>,
+    },
+    /// An entry uses a key that cannot work for it.
+    Unusable {
+        /// The key in question.
+        key: KeyBinding,
+        /// The name of the entry using the key.
+        name: String,
+        /// Why the key cannot work.
+        reason: &'static str,
+    },
+}
+
+impl KeyConfigProblem {
+    /// Provides the names of the entries at fault.
+    pub fn names(&self) -> Vec<String> {
+        match self {
+            KeyConfigProblem::Conflict { names, .. } => names.clone(),
+            KeyConfigProblem::Unusable { name, .. } => vec![name.clone()],
+        }
+    }
+}
+
+impl fmt::Display for KeyConfigProblem {
+    /// Provides a short description of the problem, suitable for the user.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyConfigProblem::Conflict { key, names } => {
+                let (last, others) = names.split_last().expect("a conflict has several names");
+                write!(
+                    f,
+                    "The key <{}> is used by {} and {}",
+                    key,
+                    others.join(", "),
+                    last
+                )
+            }
+            KeyConfigProblem::Unusable { key, name, reason } => {
+                write!(
+                    f,
+                    "The key <{}> cannot be used for {}, {}",
+                    key, name, reason
+                )
+            }
+        }
+    }
+}
+
+/// The situations in which keys are interpreted together.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Context {
+    /// The board, including the boxes reached from it apart from the ones below.
+    Board,
+    /// The Journey box.
+    Journey,
+    /// The box offering to resume the saved game.
+    Resume,
+    /// The History box.
+    History,
+    /// The Settings box.
+    Settings,
+}
+
+const BOARD: &[Context] = &[Context::Board];
+const JOURNEY: &[Context] = &[Context::Journey];
+const RESUME: &[Context] = &[Context::Resume];
+const HISTORY: &[Context] = &[Context::History];
+const SETTINGS: &[Context] = &[Context::Settings];
+const EVERYWHERE: &[Context] = &[
+    Context::Board,
+    Context::Journey,
+    Context::Resume,
+    Context::History,
+    Context::Settings,
+];
+
+/// Refers to an entry of a key config, compare `KeyConfig::slots`.
+enum Slot<'a> {
+    /// A key choosing a location.
+    Location(&'a mut char),
+    /// A key triggering another action.
+    Action(&'a mut KeyBinding),
+}
+
+impl Slot<'_> {
+    /// Provides the key of the entry.
+    fn key(&self) -> KeyBinding {
+        match self {
+            Slot::Location(char) => KeyBinding::Char(**char),
+            Slot::Action(key) => (*key).clone(),
+        }
+    }
+
+    /// Overwrites the key of the entry with the one of the other entry of the same kind.
+    fn set_from(&mut self, other: &Slot) {
+        match (self, other) {
+            (Slot::Location(char), Slot::Location(other)) => **char = **other,
+            (Slot::Action(key), Slot::Action(other)) => **key = (*other).clone(),
+            _ => panic!("should never happen"),
         }
     }
 }
 
-fn get_key_config_path() -> std::path::PathBuf {
-    let mut data_path =
-        dirs::data_dir().expect("unable to find data directory for key config data");
+impl KeyConfig {
+    /// Checks the config for keys that conflict or cannot work,
+    /// resetting the entries at fault to their defaults until no problem is left.
+    ///
+    /// Returns the checked config and the problems found,
+    /// each with the names of the entries reset because of it.
+    pub fn validated(mut self) -> (KeyConfig, Vec<(KeyConfigProblem, Vec<String>)>) {
+        let mut problems = vec![];
+        let mut default = KeyConfig::default();
 
-    data_path.push("rslibrecell");
-    data_path.push("key_config.json");
-    return data_path;
+        // every round resets at least one entry deviating from the default,
+        // which is free of problems, hence this terminates
+        loop {
+            let found = self.problems();
+            if found.is_empty() {
+                break;
+            }
+
+            let deviating: Vec<String> = self
+                .slots()
+                .into_iter()
+                .zip(default.slots())
+                .filter(|((_, _, slot), (_, _, default_slot))| slot.key() != default_slot.key())
+                .map(|((name, _, _), _)| name)
+                .collect();
+
+            for ((name, _, mut slot), (_, _, default_slot)) in
+                self.slots().into_iter().zip(default.slots())
+            {
+                if found.iter().any(|x| x.names().contains(&name)) {
+                    slot.set_from(&default_slot);
+                }
+            }
+
+            for problem in found {
+                let reset = problem
+                    .names()
+                    .into_iter()
+                    .filter(|x| deviating.contains(x))
+                    .collect();
+                problems.push((problem, reset));
+            }
+        }
+
+        (self, problems)
+    }
+
+    /// Lists the entries of the config by name, with their keys.
+    pub fn entries(&self) -> Vec<(String, KeyBinding)> {
+        let mut config = self.clone();
+        config
+            .slots()
+            .into_iter()
+            .map(|(name, _, slot)| (name, slot.key()))
+            .collect()
+    }
+
+    /// Assigns the key to the entry with the given index, compare `entries`.
+    ///
+    /// Returns whether the key was assigned, which fails for locations unless given a character.
+    pub fn rebind(&mut self, index: usize, key: KeyBinding) -> bool {
+        let mut slots = self.slots();
+        let Some((_, _, slot)) = slots.get_mut(index) else {
+            return false;
+        };
+
+        match (slot, key) {
+            (Slot::Location(char), KeyBinding::Char(new)) => **char = new,
+            (Slot::Location(_), _) => return false,
+            (Slot::Action(key), new) => **key = new,
+        }
+
+        true
+    }
+
+    /// Lists the problems of the config, in the order of its entries.
+    pub fn problems(&self) -> Vec<KeyConfigProblem> {
+        let mut config = self.clone();
+        let entries: Vec<(String, &[Context], KeyBinding)> = config
+            .slots()
+            .into_iter()
+            .map(|(name, contexts, slot)| (name, contexts, slot.key()))
+            .collect();
+
+        let mut problems = vec![];
+
+        for (name, _, key) in &entries {
+            if let Some(reason) = unusable_reason(name, key) {
+                problems.push(KeyConfigProblem::Unusable {
+                    key: key.clone(),
+                    name: name.clone(),
+                    reason,
+                });
+            }
+        }
+
+        for context in EVERYWHERE {
+            let mut seen: Vec<&KeyBinding> = vec![];
+
+            for (_, contexts, key) in &entries {
+                if !contexts.contains(context) || seen.contains(&key) {
+                    continue;
+                }
+                seen.push(key);
+
+                let names: Vec<String> = entries
+                    .iter()
+                    .filter(|(_, contexts, other)| contexts.contains(context) && other == key)
+                    .map(|(name, _, _)| name.clone())
+                    .collect();
+
+                // the foundations are a single location
+                let single_location = names.iter().all(|x| x.starts_with("foundation"));
+
+                if names.len() > 1 && !single_location {
+                    problems.push(KeyConfigProblem::Conflict {
+                        key: key.clone(),
+                        names,
+                    });
+                }
+            }
+        }
+
+        problems
+    }
+
+    /// Lists the entries of the config by name, with the contexts they are interpreted in.
+    fn slots(&mut self) -> Vec<(String, &'static [Context], Slot<'_>)> {
+        let mut slots: Vec<(String, &'static [Context], Slot<'_>)> = vec![];
+
+        for (name, char) in [
+            ("cell1", &mut self.cell1),
+            ("cell2", &mut self.cell2),
+            ("cell3", &mut self.cell3),
+            ("cell4", &mut self.cell4),
+            ("foundation1", &mut self.foundation1),
+            ("foundation2", &mut self.foundation2),
+            ("foundation3", &mut self.foundation3),
+            ("foundation4", &mut self.foundation4),
+            ("column1", &mut self.column1),
+            ("column2", &mut self.column2),
+            ("column3", &mut self.column3),
+            ("column4", &mut self.column4),
+            ("column5", &mut self.column5),
+            ("column6", &mut self.column6),
+            ("column7", &mut self.column7),
+            ("column8", &mut self.column8),
+        ] {
+            slots.push((String::from(name), BOARD, Slot::Location(char)));
+        }
+
+        let actions = &mut self.actions;
+        for (name, contexts, key) in [
+            ("quit", EVERYWHERE, &mut actions.quit),
+            ("help", BOARD, &mut actions.help),
+            ("about", BOARD, &mut actions.about),
+            ("random_game", BOARD, &mut actions.random_game),
+            ("select_by_id", BOARD, &mut actions.select_by_id),
+            ("journey", BOARD, &mut actions.journey),
+            ("history", BOARD, &mut actions.history),
+            ("statistics", BOARD, &mut actions.statistics),
+            ("settings", BOARD, &mut actions.settings),
+            ("clear", BOARD, &mut actions.clear),
+            ("revert", BOARD, &mut actions.revert),
+            ("redo", BOARD, &mut actions.redo),
+            ("hint", BOARD, &mut actions.hint),
+            ("journey_next", JOURNEY, &mut actions.journey_next),
+            ("journey_skip", JOURNEY, &mut actions.journey_skip),
+        ] {
+            slots.push((String::from(name), contexts, Slot::Action(key)));
+        }
+
+        for (i, key) in actions.journey_skipped.iter_mut().enumerate() {
+            slots.push((
+                format!("journey_skipped #{}", i + 1),
+                JOURNEY,
+                Slot::Action(key),
+            ));
+        }
+
+        for (name, contexts, key) in [
+            ("resume_yes", RESUME, &mut actions.resume_yes),
+            ("resume_no", RESUME, &mut actions.resume_no),
+            (
+                "history_checkpoint",
+                HISTORY,
+                &mut actions.history_checkpoint,
+            ),
+        ] {
+            slots.push((String::from(name), contexts, Slot::Action(key)));
+        }
+
+        for (i, key) in actions.history_branches.iter_mut().enumerate() {
+            slots.push((
+                format!("history_branches #{}", i + 1),
+                HISTORY,
+                Slot::Action(key),
+            ));
+        }
+
+        for (i, key) in actions.history_checkpoints.iter_mut().enumerate() {
+            slots.push((
+                format!("history_checkpoints #{}", i + 1),
+                HISTORY,
+                Slot::Action(key),
+            ));
+        }
+
+        for (name, contexts, key) in [
+            ("settings_suits", SETTINGS, &mut actions.settings_suits),
+            ("settings_save", SETTINGS, &mut actions.settings_save),
+        ] {
+            slots.push((String::from(name), contexts, Slot::Action(key)));
+        }
+
+        slots
+    }
+}
+
+/// Explains why the key cannot work for the entry of the given name, if it cannot.
+fn unusable_reason(name: &str, key: &KeyBinding) -> Option<&'static str> {
+    match key {
+        KeyBinding::Char(char) | KeyBinding::Ctrl(char) if char.is_control() => {
+            Some("as it is a control character")
+        }
+        KeyBinding::Ctrl(char) if !char.is_ascii_alphanumeric() => {
+            Some("as only letters and digits can be held with control")
+        }
+        KeyBinding::F(number) if !(1..=12).contains(number) => {
+            Some("as there is no such function key")
+        }
+        // quit is checked before the keys of every box, including the typing of names
+        KeyBinding::Char(_) if name == "quit" => {
+            Some("as quit needs a function key or a key held with control")
+        }
+        KeyBinding::Char(char) if char.is_ascii_digit() && name == "help" => {
+            Some("as digits are needed to enter a game ID")
+        }
+        _ => None,
+    }
+}
+
+#[automock]
+/// Defines the behavior to persist the key config.
+pub trait PersistKeyConfig {
+    /// Reads the key config from the underlying medium, providing the default if there is none.
+    fn read(&self) -> Result<KeyConfig, RepositoryError>;
+
+    /// Writes the key config to the underlying medium.
+    fn write(&self, key_config: &KeyConfig) -> Result<(), RepositoryError>;
+}
+
+#[derive(Debug)]
+/// Productive implementation.
+pub struct DiskKeyConfigRepo {}
+
+impl PersistKeyConfig for DiskKeyConfigRepo {
+    /// Reads the config from disk.
+    ///
+    /// The default is also used if there is no data directory.
+    fn read(&self) -> Result<KeyConfig, RepositoryError> {
+        let path = match DiskKeyConfigRepo::get_key_config_path() {
+            Some(path) => path,
+            None => return Ok(KeyConfig::default()),
+        };
+
+        match std::fs::exists(&path) {
+            Ok(true) => {}
+            Ok(false) => return Ok(KeyConfig::default()),
+            Err(err) => {
+                return Err(RepositoryError::Read {
+                    path,
+                    kind: err.kind(),
+                })
+            }
+        }
+
+        let data = std::fs::read_to_string(&path).map_err(|err| RepositoryError::Read {
+            path: path.clone(),
+            kind: err.kind(),
+        })?;
+
+        serde_json::from_str(&data).map_err(|_| RepositoryError::Malformed { path })
+    }
+
+    fn write(&self, key_config: &KeyConfig) -> Result<(), RepositoryError> {
+        let path =
+            DiskKeyConfigRepo::get_key_config_path().ok_or(RepositoryError::NoDataDirectory)?;
+
+        let mut data_dir = path.clone();
+        data_dir.pop();
+
+        if let Err(err) = std::fs::create_dir_all(&data_dir) {
+            return Err(RepositoryError::Write {
+                path: data_dir,
+                kind: err.kind(),
+            });
+        }
+
+        // pretty, as the file is meant to be edited by hand as well
+        let data = serde_json::to_string_pretty(key_config).unwrap();
+
+        std::fs::write(&path, data).map_err(|err| RepositoryError::Write {
+            path,
+            kind: err.kind(),
+        })
+    }
+}
+
+impl DiskKeyConfigRepo {
+    fn get_key_config_path() -> Option<PathBuf> {
+        let mut data_path = dirs::data_dir()?;
+
+        data_path.push("rslibrecell");
+        data_path.push("key_config.json");
+        Some(data_path)
+    }
 }
@@ -0,0 +1,513 @@
+/*
+    RSLibreCell - a FreeCell implementation
+    Copyright (C) 2025 and later: tristhaus
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use super::*;
+
+#[test]
+fn key_binding_display_and_parse_round_trip() {
+    let bindings = [
+        (KeyBinding::Char('a'), "a"),
+        (KeyBinding::Char('!'), "!"),
+        (KeyBinding::Char('ö'), "ö"),
+        (KeyBinding::Char(' '), "Space"),
+        (KeyBinding::Ctrl('q'), "CTRL-q"),
+        (KeyBinding::F(1), "F1"),
+        (KeyBinding::F(12), "F12"),
+    ];
+
+    for (binding, representation) in bindings {
+        assert_eq!(representation, binding.to_string());
+        assert_eq!(Ok(binding), KeyBinding::try_from(representation));
+    }
+}
+
+#[test]
+fn key_binding_parse_is_lenient_about_case() {
+    assert_eq!(Ok(KeyBinding::Char(' ')), KeyBinding::try_from("space"));
+    assert_eq!(Ok(KeyBinding::Char(' ')), KeyBinding::try_from(" "));
+    assert_eq!(Ok(KeyBinding::Ctrl('x')), KeyBinding::try_from("Ctrl-x"));
+    assert_eq!(Ok(KeyBinding::F(7)), KeyBinding::try_from("f7"));
+    assert_eq!(Ok(KeyBinding::Char('F')), KeyBinding::try_from("F"));
+}
+
+#[test]
+fn key_binding_parse_rejects_invalid_keys() {
+    for representation in ["", "ab", "F0", "F13", "Fx", "CTRL-", "CTRL-ab", "Alt-q"] {
+        assert!(
+            KeyBinding::try_from(representation).is_err(),
+            "{}",
+            representation
+        );
+    }
+}
+
+#[test]
+fn key_config_without_actions_uses_default_actions() {
+    let data = r#"{
+        "cell1" : "q", "cell2" : "w", "cell3" : "e", "cell4" : "r",
+        "foundation1" : "u", "foundation2" : "i", "foundation3" : "o", "foundation4" : "p",
+        "column1" : "a", "column2" : "s", "column3" : "d", "column4" : "f",
+        "column5" : "j", "column6" : "k", "column7" : "l", "column8" : ";"
+    }"#;
+
+    let key_config: KeyConfig = serde_json::from_str(data).unwrap();
+
+    assert_eq!(ActionKeys::default(), key_config.actions);
+}
+
+#[test]
+fn key_config_with_partial_actions_uses_defaults_for_the_rest() {
+    let data = r#"{
+        "cell1" : "q", "cell2" : "w", "cell3" : "e", "cell4" : "r",
+        "foundation1" : "u", "foundation2" : "i", "foundation3" : "o", "foundation4" : "p",
+        "column1" : "a", "column2" : "s", "column3" : "d", "column4" : "f",
+        "column5" : "j", "column6" : "k", "column7" : "l", "column8" : ";",
+        "actions" : { "quit" : "CTRL-x", "journey" : "F8", "revert" : "Space", "clear" : "z" }
+    }"#;
+
+    let key_config: KeyConfig = serde_json::from_str(data).unwrap();
+
+    let expected = ActionKeys {
+        quit: KeyBinding::Ctrl('x'),
+        journey: KeyBinding::F(8),
+        revert: KeyBinding::Char(' '),
+        clear: KeyBinding::Char('z'),
+        ..ActionKeys::default()
+    };
+    assert_eq!(expected, key_config.actions);
+}
+
+#[test]
+fn key_config_with_invalid_action_key_is_rejected() {
+    let data = r#"{
+        "cell1" : "q", "cell2" : "w", "cell3" : "e", "cell4" : "r",
+        "foundation1" : "u", "foundation2" : "i", "foundation3" : "o", "foundation4" : "p",
+        "column1" : "a", "column2" : "s", "column3" : "d", "column4" : "f",
+        "column5" : "j", "column6" : "k", "column7" : "l", "column8" : ";",
+        "actions" : { "quit" : "Alt-q" }
+    }"#;
+
+    assert!(serde_json::from_str::<KeyConfig>(data).is_err());
+}
+
+#[test]
+fn default_key_config_serializes_actions_readably() {
+    let data = serde_json::to_string(&KeyConfig::default()).unwrap();
+
+    assert!(data.contains(r#""quit":"CTRL-q""#));
+    assert!(data.contains(r#""clear":"Space""#));
+    assert!(data.contains(r#""journey_skipped":["2","3","4","5","6","7","8","9"]"#));
+}
+
+#[test]
+fn sample_key_config_mirrors_default() {
+    let data = include_str!("../../config/key_config.json");
+
+    let key_config: KeyConfig = serde_json::from_str(data).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(KeyConfig::default()).unwrap(),
+        serde_json::to_value(key_config).unwrap()
+    );
+}
+
+#[test]
+fn default_key_config_is_valid() {
+    let (key_config, problems) = KeyConfig::default().validated();
+
+    assert_eq!(KeyConfig::default(), key_config);
+    assert!(problems.is_empty());
+}
+
+#[test]
+fn validated_resets_locations_sharing_a_key() {
+    let key_config = KeyConfig {
+        cell2: 'q',
+        column8: 'a',
+        ..KeyConfig::default()
+    };
+
+    let (key_config, problems) = key_config.validated();
+
+    assert_eq!(KeyConfig::default(), key_config);
+    assert_eq!(
+        vec![
+            (
+                KeyConfigProblem::Conflict {
+                    key: KeyBinding::Char('q'),
+                    names: vec![String::from("cell1"), String::from("cell2")],
+                },
+                vec![String::from("cell2")]
+            ),
+            (
+                KeyConfigProblem::Conflict {
+                    key: KeyBinding::Char('a'),
+                    names: vec![String::from("column1"), String::from("column8")],
+                },
+                vec![String::from("column8")]
+            ),
+        ],
+        problems
+    );
+}
+
+#[test]
+fn validated_allows_foundations_sharing_a_key() {
+    let key_config = KeyConfig {
+        foundation1: 'u',
+        foundation2: 'u',
+        foundation3: 'u',
+        foundation4: 'u',
+        ..KeyConfig::default()
+    };
+
+    let (validated, problems) = key_config.clone().validated();
+
+    assert_eq!(key_config, validated);
+    assert!(problems.is_empty());
+}
+
+#[test]
+fn validated_resets_locations_using_action_keys() {
+    let key_config = KeyConfig {
+        column1: 'R',
+        column2: ' ',
+        ..KeyConfig::default()
+    };
+
+    let (key_config, problems) = key_config.validated();
+
+    assert_eq!(KeyConfig::default(), key_config);
+    assert_eq!(2, problems.len());
+    assert_eq!(
+        "The key <R> is used by column1 and revert",
+        problems[0].0.to_string()
+    );
+    assert_eq!(vec![String::from("column1")], problems[0].1);
+    assert_eq!(
+        "The key <Space> is used by column2 and clear",
+        problems[1].0.to_string()
+    );
+    assert_eq!(vec![String::from("column2")], problems[1].1);
+}
+
+#[test]
+fn validated_keeps_unrelated_changes() {
+    let key_config = KeyConfig {
+        column1: 'x',
+        actions: ActionKeys {
+            revert: KeyBinding::Char('x'),
+            redo: KeyBinding::Char('z'),
+            journey_skip: KeyBinding::Char('a'),
+            ..ActionKeys::default()
+        },
+        ..KeyConfig::default()
+    };
+
+    let (key_config, problems) = key_config.validated();
+
+    let expected = KeyConfig {
+        actions: ActionKeys {
+            redo: KeyBinding::Char('z'),
+            journey_skip: KeyBinding::Char('a'),
+            ..ActionKeys::default()
+        },
+        ..KeyConfig::default()
+    };
+    assert_eq!(expected, key_config);
+    assert_eq!(
+        vec![(
+            KeyConfigProblem::Conflict {
+                key: KeyBinding::Char('x'),
+                names: vec![String::from("column1"), String::from("revert")],
+            },
+            vec![String::from("column1"), String::from("revert")]
+        )],
+        problems
+    );
+}
+
+#[test]
+fn validated_checks_journey_box_separately() {
+    let key_config = KeyConfig {
+        actions: ActionKeys {
+            journey_skip: KeyBinding::Char('1'),
+            journey_skipped: ['2', '3', '4', '5', '6', '7', '8', '8'].map(KeyBinding::Char),
+            ..ActionKeys::default()
+        },
+        ..KeyConfig::default()
+    };
+
+    let (key_config, problems) = key_config.validated();
+
+    assert_eq!(KeyConfig::default(), key_config);
+    assert_eq!(
+        vec![
+            "The key <1> is used by journey_next and journey_skip",
+            "The key <8> is used by journey_skipped #7 and journey_skipped #8",
+        ],
+        problems
+            .iter()
+            .map(|(x, _)| x.to_string())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(vec![String::from("journey_skip")], problems[0].1);
+    assert_eq!(vec![String::from("journey_skipped #8")], problems[1].1);
+}
+
+#[test]
+fn validated_checks_resume_history_and_settings_boxes_separately() {
+    let key_config = KeyConfig {
+        actions: ActionKeys {
+            resume_no: KeyBinding::Char('y'),
+            history_checkpoint: KeyBinding::Ctrl('q'),
+            history_checkpoints: ['1', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j']
+                .map(KeyBinding::Char),
+            settings_save: KeyBinding::Char('a'),
+            ..ActionKeys::default()
+        },
+        ..KeyConfig::default()
+    };
+
+    let (key_config, problems) = key_config.validated();
+
+    assert_eq!(KeyConfig::default(), key_config);
+    assert_eq!(
+        vec![
+            "The key <y> is used by resume_yes and resume_no",
+            "The key <CTRL-q> is used by quit and history_checkpoint",
+            "The key <1> is used by history_branches #1 and history_checkpoints #1",
+            "The key <a> is used by settings_suits and settings_save",
+        ],
+        problems
+            .iter()
+            .map(|(x, _)| x.to_string())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(vec![String::from("resume_no")], problems[0].1);
+    assert_eq!(vec![String::from("history_checkpoint")], problems[1].1);
+    assert_eq!(vec![String::from("history_checkpoints #1")], problems[2].1);
+    assert_eq!(vec![String::from("settings_save")], problems[3].1);
+}
+
+#[test]
+fn validated_resets_plain_quit_key() {
+    for char in ['x', '%', 'Z'] {
+        let key_config = KeyConfig {
+            actions: ActionKeys {
+                quit: KeyBinding::Char(char),
+                ..ActionKeys::default()
+            },
+            ..KeyConfig::default()
+        };
+
+        let (key_config, problems) = key_config.validated();
+
+        assert_eq!(KeyConfig::default(), key_config);
+        assert_eq!(
+            vec![format!(
+                "The key <{}> cannot be used for quit, as quit needs a function key or a key held with control",
+                KeyBinding::Char(char)
+            )],
+            problems
+                .iter()
+                .map(|(x, _)| x.to_string())
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn validated_resets_unusable_keys() {
+    let key_config = KeyConfig {
+        cell1: '\t',
+        actions: ActionKeys {
+            help: KeyBinding::Char('7'),
+            about: KeyBinding::F(13),
+            quit: KeyBinding::Ctrl('#'),
+            ..ActionKeys::default()
+        },
+        ..KeyConfig::default()
+    };
+
+    let (key_config, problems) = key_config.validated();
+
+    assert_eq!(KeyConfig::default(), key_config);
+    assert_eq!(
+        vec![
+            "The key <\t> cannot be used for cell1, as it is a control character",
+            "The key <CTRL-#> cannot be used for quit, as only letters and digits can be held with control",
+            "The key <7> cannot be used for help, as digits are needed to enter a game ID",
+            "The key <F13> cannot be used for about, as there is no such function key",
+        ],
+        problems
+            .iter()
+            .map(|(x, _)| x.to_string())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn validated_repeats_until_resets_cause_no_conflict() {
+    // resetting column1 to its default lets it conflict with column2
+    let key_config = KeyConfig {
+        column1: 'h',
+        column2: 'a',
+        ..KeyConfig::default()
+    };
+
+    let (key_config, problems) = key_config.validated();
+
+    assert_eq!(KeyConfig::default(), key_config);
+    assert_eq!(
+        vec![
+            (
+                KeyConfigProblem::Conflict {
+                    key: KeyBinding::Char('h'),
+                    names: vec![String::from("column1"), String::from("hint")],
+                },
+                vec![String::from("column1")]
+            ),
+            (
+                KeyConfigProblem::Conflict {
+                    key: KeyBinding::Char('a'),
+                    names: vec![String::from("column1"), String::from("column2")],
+                },
+                vec![String::from("column2")]
+            ),
+        ],
+        problems
+    );
+}
+
+#[test]
+fn entries_list_locations_then_actions() {
+    let entries = KeyConfig::default().entries();
+
+    assert_eq!(63, entries.len());
+    assert_eq!((String::from("cell1"), KeyBinding::Char('q')), entries[0]);
+    assert_eq!(
+        (String::from("column8"), KeyBinding::Char(';')),
+        entries[15]
+    );
+    assert_eq!((String::from("quit"), KeyBinding::Ctrl('q')), entries[16]);
+    assert_eq!(
+        (String::from("journey_skipped #8"), KeyBinding::Char('9')),
+        entries[38]
+    );
+    assert_eq!(
+        (String::from("resume_yes"), KeyBinding::Char('y')),
+        entries[39]
+    );
+    assert_eq!(
+        (
+            String::from("history_checkpoints #10"),
+            KeyBinding::Char('j')
+        ),
+        entries[60]
+    );
+    assert_eq!(
+        (String::from("settings_save"), KeyBinding::Char('s')),
+        entries[62]
+    );
+}
+
+#[test]
+fn rebind_assigns_keys() {
+    let mut key_config = KeyConfig::default();
+
+    assert!(key_config.rebind(1, KeyBinding::Char('x')));
+    assert!(key_config.rebind(16, KeyBinding::F(10)));
+    assert!(key_config.rebind(38, KeyBinding::Ctrl('n')));
+
+    let expected = KeyConfig {
+        cell2: 'x',
+        actions: ActionKeys {
+            quit: KeyBinding::F(10),
+            journey_skipped: ['2', '3', '4', '5', '6', '7', '8', 'n'].map(|x| match x {
+                'n' => KeyBinding::Ctrl(x),
+                _ => KeyBinding::Char(x),
+            }),
+            ..ActionKeys::default()
+        },
+        ..KeyConfig::default()
+    };
+    assert_eq!(expected, key_config);
+}
+
+#[test]
+fn rebind_rejects_locations_without_character_and_unknown_entries() {
+    let mut key_config = KeyConfig::default();
+
+    assert!(!key_config.rebind(0, KeyBinding::F(10)));
+    assert!(!key_config.rebind(15, KeyBinding::Ctrl('x')));
+    assert!(!key_config.rebind(63, KeyBinding::Char('x')));
+
+    assert_eq!(KeyConfig::default(), key_config);
+}
+
+#[test]
+fn theme_cycles_through_all() {
+    assert_eq!(Theme::Light, Theme::Dark.next());
+    assert_eq!(Theme::Dark, Theme::FourColor.next());
+    assert_eq!(Theme::FourColor, Theme::Dark.previous());
+    assert_eq!(Theme::HighContrast, Theme::FourColor.previous());
+
+    for theme in Theme::ALL {
+        assert_eq!(theme, theme.next().previous());
+    }
+}
+
+#[test]
+fn theme_is_read_by_name_and_defaults_to_dark() {
+    let data = r#"{
+        "cell1" : "q", "cell2" : "w", "cell3" : "e", "cell4" : "r",
+        "foundation1" : "u", "foundation2" : "i", "foundation3" : "o", "foundation4" : "p",
+        "column1" : "a", "column2" : "s", "column3" : "d", "column4" : "f",
+        "column5" : "j", "column6" : "k", "column7" : "l", "column8" : ";"
+    }"#;
+
+    let key_config: KeyConfig = serde_json::from_str(data).unwrap();
+    assert_eq!(Theme::Dark, key_config.theme);
+
+    for theme in Theme::ALL {
+        let data = data.replace(";\"", &format!(";\", \"theme\" : \"{}\"", theme));
+        let key_config: KeyConfig = serde_json::from_str(&data).unwrap();
+        assert_eq!(theme, key_config.theme);
+    }
+
+    let data = data.replace(";\"", ";\", \"theme\" : \"sepia\"");
+    assert!(serde_json::from_str::<KeyConfig>(&data).is_err());
+}
+
+#[test]
+fn ascii_is_read_and_defaults_to_symbols() {
+    let data = r#"{
+        "cell1" : "q", "cell2" : "w", "cell3" : "e", "cell4" : "r",
+        "foundation1" : "u", "foundation2" : "i", "foundation3" : "o", "foundation4" : "p",
+        "column1" : "a", "column2" : "s", "column3" : "d", "column4" : "f",
+        "column5" : "j", "column6" : "k", "column7" : "l", "column8" : ";"
+    }"#;
+
+    let key_config: KeyConfig = serde_json::from_str(data).unwrap();
+    assert!(!key_config.ascii);
+
+    let data = data.replace(";\"", ";\", \"ascii\" : true");
+    let key_config: KeyConfig = serde_json::from_str(&data).unwrap();
+    assert!(key_config.ascii);
+}
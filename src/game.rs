@@ -23,9 +23,12 @@ use crate::{card::Card, game::prng::Prng};
 /// Contains a certain pseudo-random number generator.
 mod prng;
 
-/// Defines the ID of a FreeCell game.
+/// The highest ID of a FreeCell game, as supported by FreeCell Pro.
+pub const MAX_GAME_ID: u64 = 8_589_934_591;
+
+/// Defines the ID of a FreeCell game, from 1 to `MAX_GAME_ID`.
 #[derive(Clone, Debug, PartialEq)]
-pub struct GameId(pub u16);
+pub struct GameId(pub u64);
 
 /// Defines a FreeCell game.
 #[derive(Clone, Debug, PartialEq)]
@@ -43,12 +46,23 @@ pub struct Game {
 impl fmt::Display for Game {
     /// Provides the canonical representation of the game,
     /// which should be parseable via `try_from`.
+    ///
+    /// The alternate form `{:#}` shows the suits as letters, compare `Card`.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut result = String::from("RustLibreCell              ");
+        let show = |card: &Card| {
+            if f.alternate() {
+                format!("{:#}", card)
+            } else {
+                card.to_string()
+            }
+        };
+
+        let mut result = String::from("RustLibreCell ");
 
         let id = self.id.0.to_string();
 
-        for _ in 0..(5 - id.len()) {
+        // Keep the header aligned with the board, even for long IDs.
+        for _ in 0..18usize.saturating_sub(id.len()) {
             result += " ";
         }
 
@@ -60,7 +74,7 @@ impl fmt::Display for Game {
         for cell in &self.cells {
             match cell {
                 None => result.push_str(" .. "),
-                Some(cell) => result = format!("{} {} ", result, cell),
+                Some(cell) => result = format!("{} {} ", result, show(cell)),
             }
         }
 
@@ -70,7 +84,7 @@ impl fmt::Display for Game {
             if foundation.len() == 0 {
                 result += " .. "
             } else {
-                result = format!("{} {} ", result, foundation[foundation.len() - 1])
+                result = format!("{} {} ", result, show(&foundation[foundation.len() - 1]))
             }
         }
 
@@ -81,7 +95,7 @@ impl fmt::Display for Game {
             for column in &self.columns {
                 match column.get(i) {
                     None => result += "    ",
-                    Some(card) => result = format!("{} {} ", result, card),
+                    Some(card) => result = format!("{} {} ", result, show(card)),
                 }
             }
             result += " \n";
@@ -95,7 +109,7 @@ impl TryFrom<&str> for Game {
     type Error = ();
 
     /// Attempts to create a game from its canonical string representation,
-    /// compare `fmt`.
+    /// compare `fmt`. The suits may be given as symbols or as letters.
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let mut cells = [
             Option::<Card>::None,
@@ -138,7 +152,7 @@ impl TryFrom<&str> for Game {
             }
         }
 
-        let game_id = match game_id.trim().parse::<u16>() {
+        let game_id = match game_id.trim().parse::<u64>() {
             Ok(id) => GameId(id),
             Err(_) => return Err(()),
         };
@@ -238,8 +252,8 @@ impl Game {
     ///
     /// # Panics
     /// The method will panic if the underlying code, especially the PRNG, panics.
-    pub(crate) fn from_id(id: GameId) -> Game {
-        let mut prng = Prng { state: id.0 as u32 };
+    pub fn from_id(id: GameId) -> Game {
+        let mut prng = Prng::new(id.0);
 
         let mut game = Game {
             id,
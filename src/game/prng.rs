@@ -16,16 +16,44 @@
     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
-/// Implements the specific PRNG needed for creating MS FreeCell deals.
+/// Implements the specific PRNG needed for creating MS FreeCell deals,
+/// including the extended range of deals introduced by FreeCell Pro.
 pub struct Prng {
-    pub state: u32,
+    /// The seed, *i.e.* the ID of the game, which selects the variant.
+    pub seed: u64,
+    pub state: u64,
 }
 
 impl Prng {
+    /// Creates a new PRNG for the given seed.
+    ///
+    /// Seeds of at least 2^32 start from the state `seed - 2^32`.
+    pub fn new(seed: u64) -> Prng {
+        let state = if seed < 0x1_0000_0000 {
+            seed
+        } else {
+            seed - 0x1_0000_0000
+        };
+
+        Prng { seed, state }
+    }
+
     /// Gets the next value of the PRNG and advances its state.
+    ///
+    /// Seeds below 2^31 yield the values of the original MS FreeCell,
+    /// seeds from 2^31 to 2^32 - 1 additionally set bit 15,
+    /// and larger seeds use 16 bits of the state, offset by one.
     pub fn get_next(&mut self) -> u32 {
-        self.state = (self.state.wrapping_mul(214013)).wrapping_add(2531011) % 2147483648;
-        self.state / 65536
+        // wrapping keeps the lower 33 bits, which are all that is used
+        self.state = self.state.wrapping_mul(214013).wrapping_add(2531011) & 0x1_ffff_ffff;
+
+        if self.seed < 0x8000_0000 {
+            ((self.state >> 16) & 0x7fff) as u32
+        } else if self.seed < 0x1_0000_0000 {
+            ((self.state >> 16) & 0x7fff) as u32 | 0x8000
+        } else {
+            ((self.state >> 16) & 0xffff) as u32 + 1
+        }
     }
 }
 
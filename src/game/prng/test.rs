@@ -20,7 +20,7 @@ use super::*;
 
 #[test]
 fn prng_generate_state_0_works() {
-    let mut prng = Prng { state: 0 };
+    let mut prng = Prng::new(0);
 
     assert_eq!(38, prng.get_next());
     assert_eq!(7719, prng.get_next());
@@ -36,8 +36,41 @@ fn prng_generate_state_0_works() {
 
 #[test]
 fn prng_generate_state_1_works() {
-    let mut prng = Prng { state: 1 };
+    let mut prng = Prng::new(1);
 
     assert_eq!(41, prng.get_next());
     assert_eq!(18467, prng.get_next());
 }
+
+#[test]
+fn prng_generate_state_2_pow_31_sets_bit_15() {
+    let mut prng = Prng::new(0x8000_0000);
+
+    assert_eq!(32806, prng.get_next());
+    assert_eq!(40487, prng.get_next());
+}
+
+#[test]
+fn prng_generate_state_2_pow_32_works() {
+    let mut prng = Prng::new(0x1_0000_0000);
+
+    assert_eq!(39, prng.get_next());
+    assert_eq!(7720, prng.get_next());
+}
+
+#[test]
+fn prng_generate_state_max_game_id_works() {
+    let mut prng = Prng::new(8_589_934_591);
+
+    assert_eq!(36, prng.get_next());
+    assert_eq!(29740, prng.get_next());
+}
+
+#[test]
+fn prng_generate_state_beyond_max_game_id_does_not_panic() {
+    let mut prng = Prng::new(u64::MAX);
+
+    for _ in 0..52 {
+        prng.get_next();
+    }
+}
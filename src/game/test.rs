@@ -250,6 +250,21 @@ fn game_display_trait_works() {
     assert_eq!(reference, result);
 }
 
+#[test]
+fn game_display_trait_alternate_uses_letters() {
+    let game = Game::from_id(GameId(617));
+    let reference = game
+        .to_string()
+        .replace('♣', "C")
+        .replace('♦', "D")
+        .replace('♥', "H")
+        .replace('♠', "S");
+
+    let result = format!("{:#}", game);
+
+    assert_eq!(reference, result);
+}
+
 #[test]
 fn game_tryfrom_ref_str_with_unicode_representation_works() {
     let input = concat!(
@@ -354,6 +369,17 @@ fn game_tryfrom_ref_str_with_unicode_representation_works() {
     assert_eq!(reference, game);
 }
 
+#[test]
+fn game_tryfrom_ref_str_with_ascii_representation_works() {
+    let mut reference = Game::from_id(GameId(617));
+    let card = reference.columns[0].pop();
+    reference.cells[2] = card;
+
+    let game = Game::try_from(format!("{:#}", reference).as_str()).unwrap();
+
+    assert_eq!(reference, game);
+}
+
 #[test]
 fn game_tryfrom_empty_string_errors() {
     Game::try_from("").expect_err("should have error")
@@ -571,3 +597,15 @@ fn game_from_id_30828_works() {
 
     assert_eq!(expected, actual);
 }
+
+#[test]
+fn game_from_id_max_game_id_round_trips() {
+    let game = Game::from_id(GameId(MAX_GAME_ID));
+
+    let representation = game.to_string();
+    assert!(representation.starts_with("RustLibreCell         #8589934591 \n"));
+
+    let actual = Game::try_from(representation.as_str()).unwrap();
+
+    assert_eq!(game, actual);
+}
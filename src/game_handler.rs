@@ -16,109 +16,589 @@
     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
-use std::{cell::RefCell, fmt::Debug, rc::Rc};
+use std::{cell::RefCell, fmt::Debug, rc::Rc, time::Duration};
 
 use rand::Rng;
 
 use crate::{
     game::{Game, GameId},
+    game_handler::game_repository::SavedGame,
     journey_handler::{journey_repository::PersistJourney, JourneyHandler},
-    r#move::{apply, automove, Move},
+    r#move::{make_move, Location, Move, MoveError},
+    solver::{solve, Solution, DEFAULT_BUDGET},
+    stats_handler::{stats_repository::PersistStats, StatsHandler},
 };
 
+/// The maximum number of checkpoints of a game, compare `GameHandler::set_checkpoint`.
+pub const MAX_CHECKPOINTS: usize = 10;
+
+/// The budget of the solver for each earlier position looked at by a hint,
+/// kept below `DEFAULT_BUDGET` as there can be several such positions.
+const BISECTION_BUDGET: usize = DEFAULT_BUDGET / 4;
+
+/// A hint regarding the currently held game.
+#[derive(Clone, Debug)]
+pub enum Hint {
+    /// The recommended next move.
+    Move(Move),
+    /// No way to win the game from the current position was found.
+    NoSolution {
+        /// Indicates that the position has been proven to be unwinnable.
+        proven: bool,
+        /// The number of reverts leading back to the last position
+        /// from which a way to win was found, if any.
+        reverts: Option<usize>,
+    },
+}
+
+/// A line of play within the history of the held game,
+/// ending in a position from which no move has been made yet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Branch {
+    /// The number of moves from the initial position to the end of the branch.
+    pub length: usize,
+    /// The last move of the branch, if any.
+    pub last_move: Option<Move>,
+    /// Indicates that the branch is the one followed by `redo`
+    /// from the current position.
+    pub active: bool,
+}
+
+/// A named position within the history of the held game.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// The name given by the player.
+    pub name: String,
+    /// The number of moves from the initial position to the checkpoint.
+    pub length: usize,
+}
+
+/// A position within the history tree of the held game.
+#[derive(Debug)]
+struct Position {
+    game: Game,
+    /// The move leading to the position, `None` for the initial position.
+    mv: Option<Move>,
+    /// The number of cards moved to the foundations automatically after `mv`.
+    automoves: usize,
+    parent: Option<usize>,
+    /// The positions reached from this one, in the order they were first reached.
+    children: Vec<usize>,
+    /// The child most recently reached from or reverted to this position,
+    /// which is followed by `redo`.
+    next: Option<usize>,
+}
+
 /// A structure to hold a game and its history.
+///
+/// The history is a tree, such that reverting and making a different move
+/// starts a new branch, while keeping the branch that was left.
 #[derive(Debug)]
-pub struct GameHandler<T>
+pub struct GameHandler<T, S>
 where
     T: PersistJourney,
     T: Debug,
+    S: PersistStats,
+    S: Debug,
 {
     /// The current game in its current state, if any.
     pub game: Option<Game>,
     journey_handler: Rc<RefCell<JourneyHandler<T>>>,
-    history: Vec<Game>,
+    stats_handler: Rc<RefCell<StatsHandler<S>>>,
+    /// All positions reached, the initial position first.
+    positions: Vec<Position>,
+    /// The index of the current position.
+    current: usize,
+    /// The named positions, compare `Checkpoint`.
+    checkpoints: Vec<(String, usize)>,
 }
 
-impl<T> GameHandler<T>
+impl<T, S> GameHandler<T, S>
 where
     T: PersistJourney,
     T: Debug,
+    S: PersistStats,
+    S: Debug,
 {
-    /// Creates a new instance containing the given journey handler.
-    pub fn new(journey_handler: Rc<RefCell<JourneyHandler<T>>>) -> Self {
+    /// Creates a new instance containing the given journey and stats handlers.
+    pub fn new(
+        journey_handler: Rc<RefCell<JourneyHandler<T>>>,
+        stats_handler: Rc<RefCell<StatsHandler<S>>>,
+    ) -> Self {
         GameHandler {
             game: None,
             journey_handler: journey_handler.clone(),
-            history: vec![],
+            stats_handler: stats_handler.clone(),
+            positions: vec![],
+            current: 0,
+            checkpoints: vec![],
         }
     }
 
     /// Replaces the currently held game and its history (if any)
     /// with the game defined by the given ID.
-    pub fn game_from_id(&mut self, id: GameId) {
-        self.game = Some(Game::from_id(id));
-        self.history.clear();
+    ///
+    /// The `elapsed` time spent playing the replaced game is recorded if it was abandoned.
+    pub fn game_from_id(&mut self, id: GameId, elapsed: Duration) {
+        self.stats_handler
+            .borrow_mut()
+            .receive_notification_game_started(id.clone(), 0, elapsed);
+        self.reset(id);
+    }
+
+    /// Helper function to game_from_id and replay, which does not notify.
+    fn reset(&mut self, id: GameId) {
+        let game = Game::from_id(id);
+
+        self.positions = vec![Position {
+            game: game.clone(),
+            mv: None,
+            automoves: 0,
+            parent: None,
+            children: vec![],
+            next: None,
+        }];
+        self.current = 0;
+        self.checkpoints.clear();
+        self.game = Some(game);
     }
 
     /// Replaces the currently held game and its history (if any)
-    /// with a random solvable game defined by an ID in the range 1 to 64000.
-    pub fn random_game(&mut self) {
+    /// with a random solvable game defined by an ID in the range 1 to 64000,
+    /// compare `game_from_id`.
+    pub fn random_game(&mut self, elapsed: Duration) {
         // note that other known unsolvable games are currently out of scope
         // they are: 146_692, 186_216, 455_889, 495_505, 512_118, 517_776, 781_948
-        const UNSOLVABLE_GAME: u16 = 11_982;
+        const UNSOLVABLE_GAME: u64 = 11_982;
 
         loop {
-            let candidate = rand::rng().random_range(1u16..64001u16);
+            let candidate = rand::rng().random_range(1u64..64001u64);
 
             if candidate != UNSOLVABLE_GAME {
-                self.game_from_id(GameId(candidate));
+                self.game_from_id(GameId(candidate), elapsed);
                 break;
             }
         }
     }
 
-    /// Make a move on the currently held game.
-    pub fn make_move(&mut self, mv: Move) -> Result<(), ()> {
-        if self.game.is_none() || self.game.as_mut().unwrap().is_won() {
-            return Err(());
+    /// Make a move on the currently held game,
+    /// after the `elapsed` time spent playing it, which is recorded if the game is won.
+    pub fn make_move(&mut self, mv: Move, elapsed: Duration) -> Result<(), MoveError> {
+        self.advance(mv)?;
+
+        self.stats_handler.borrow_mut().receive_notification_move();
+
+        if self.game.as_ref().unwrap().is_won() {
+            let id = self.game.as_ref().unwrap().id.clone();
+
+            self.journey_handler
+                .borrow_mut()
+                .receive_notification_game_won(id.clone());
+            self.stats_handler
+                .borrow_mut()
+                .receive_notification_game_won(id, elapsed);
         }
 
-        let new_state = apply(self.game.as_mut().unwrap(), mv);
+        return Ok(());
+    }
 
-        if let Err(()) = new_state {
-            return Err(());
+    /// Helper function to make_move and replay, which does not notify.
+    fn advance(&mut self, mv: Move) -> Result<(), MoveError> {
+        match &self.game {
+            None => return Err(MoveError::NoGame),
+            Some(game) if game.is_won() => return Err(MoveError::GameWon),
+            Some(_) => {}
         }
 
-        self.history.push(self.game.as_mut().unwrap().clone());
+        let new_state = make_move(self.game.as_ref().unwrap(), mv.clone())?;
 
-        let mut new_state = new_state.unwrap();
+        // all cards reaching the foundations, except one moved there by the player
+        let on_foundations = |game: &Game| game.foundations.iter().map(|x| x.len()).sum::<usize>();
+        let automoves = on_foundations(&new_state)
+            - on_foundations(self.game.as_ref().unwrap())
+            - usize::from(mv.to == Location::Foundation);
 
-        while let Some(automoved) = automove(&new_state) {
-            new_state = automoved
-        }
+        // a move leading to an already known position continues its branch
+        let known = self.positions[self.current]
+            .children
+            .iter()
+            .find(|x| self.positions[**x].game == new_state)
+            .copied();
 
+        let next = match known {
+            Some(next) => next,
+            None => {
+                self.positions.push(Position {
+                    game: new_state.clone(),
+                    mv: Some(mv),
+                    automoves,
+                    parent: Some(self.current),
+                    children: vec![],
+                    next: None,
+                });
+                let next = self.positions.len() - 1;
+                self.positions[self.current].children.push(next);
+                next
+            }
+        };
+
+        self.positions[self.current].next = Some(next);
+        self.current = next;
         self.game = Some(new_state);
 
-        if self.game.as_ref().unwrap().is_won() {
-            self.journey_handler
-                .borrow_mut()
-                .receive_notification_game_won(self.game.as_ref().unwrap().id.clone());
+        Ok(())
+    }
+
+    /// Return the held game to its state before the last move, if any.
+    pub fn revert(&mut self) -> Result<(), MoveError> {
+        if self.game.as_ref().is_some_and(|x| x.is_won()) {
+            return Err(MoveError::GameWon);
         }
 
+        let parent = match self.positions.get(self.current).and_then(|x| x.parent) {
+            Some(parent) => parent,
+            None => return Err(MoveError::NothingToRevert),
+        };
+
+        self.positions[parent].next = Some(self.current);
+        self.go_to(parent);
+        self.stats_handler
+            .borrow_mut()
+            .receive_notification_revert();
+
         return Ok(());
     }
 
-    /// Return the held game to its state before the last move, if any.
-    pub fn revert(&mut self) -> Result<(), ()> {
-        if self.history.is_empty() || self.game.as_ref().is_some_and(|x| x.is_won()) {
-            return Err(());
+    /// Return the held game to its state before the last revert, if any.
+    ///
+    /// Redo follows the branch most recently left by a revert or a switch.
+    pub fn redo(&mut self) -> Result<(), MoveError> {
+        let next = match self.positions.get(self.current).and_then(|x| x.next) {
+            Some(next) => next,
+            None => return Err(MoveError::NothingToRedo),
+        };
+
+        self.go_to(next);
+        self.stats_handler.borrow_mut().receive_notification_move();
+
+        Ok(())
+    }
+
+    /// Counts the moves leading from the initial position to the current position,
+    /// as the moves made by the player and the cards moved to the foundations automatically.
+    pub fn move_counts(&self) -> (usize, usize) {
+        if self.positions.is_empty() {
+            return (0, 0);
         }
 
-        self.game = Some(self.history.pop().unwrap());
+        let line = self.line(self.current);
+        let automoves = line.iter().map(|x| self.positions[*x].automoves).sum();
 
-        return Ok(());
+        (line.len() - 1, automoves)
+    }
+
+    /// Lists the moves leading from the initial position to the current position.
+    pub fn moves(&self) -> Vec<Move> {
+        if self.positions.is_empty() {
+            return vec![];
+        }
+
+        self.line(self.current)[1..]
+            .iter()
+            .map(|x| self.positions[*x].mv.clone().unwrap())
+            .collect()
+    }
+
+    /// Lists the branches of the history of the held game, in the order
+    /// they were started.
+    pub fn branches(&self) -> Vec<Branch> {
+        if self.positions.is_empty() {
+            return vec![];
+        }
+
+        let mut active = self.current;
+        while let Some(next) = self.positions[active].next {
+            active = next;
+        }
+
+        self.leaves()
+            .into_iter()
+            .map(|index| Branch {
+                length: self.line(index).len() - 1,
+                last_move: self.positions[index].mv.clone(),
+                active: index == active,
+            })
+            .collect()
+    }
+
+    /// Continues the held game at the end of the branch with the given index,
+    /// compare `branches`.
+    pub fn switch_to_branch(&mut self, index: usize) -> Result<(), MoveError> {
+        if self.game.as_ref().is_some_and(|x| x.is_won()) {
+            return Err(MoveError::GameWon);
+        }
+
+        match self.leaves().get(index) {
+            Some(leaf) => {
+                self.go_to(*leaf);
+                Ok(())
+            }
+            None => Err(MoveError::UnknownBranch),
+        }
+    }
+
+    /// Names the current position of the held game,
+    /// replacing a checkpoint of the same name.
+    ///
+    /// At most `MAX_CHECKPOINTS` checkpoints can be set.
+    pub fn set_checkpoint(&mut self, name: &str) -> Result<(), MoveError> {
+        if self.game.is_none() {
+            return Err(MoveError::NoGame);
+        }
+
+        let full = self.checkpoints.len() >= MAX_CHECKPOINTS;
+
+        match self.checkpoints.iter_mut().find(|x| x.0 == name) {
+            Some(checkpoint) => checkpoint.1 = self.current,
+            None if full => return Err(MoveError::TooManyCheckpoints),
+            None => self.checkpoints.push((name.to_string(), self.current)),
+        }
+
+        Ok(())
+    }
+
+    /// Lists the checkpoints of the held game, in the order they were set.
+    pub fn checkpoints(&self) -> Vec<Checkpoint> {
+        self.checkpoints
+            .iter()
+            .map(|(name, index)| Checkpoint {
+                name: name.clone(),
+                length: self.line(*index).len() - 1,
+            })
+            .collect()
+    }
+
+    /// Returns the held game to the checkpoint with the given index,
+    /// compare `checkpoints`.
+    pub fn jump_to_checkpoint(&mut self, index: usize) -> Result<(), MoveError> {
+        if self.game.as_ref().is_some_and(|x| x.is_won()) {
+            return Err(MoveError::GameWon);
+        }
+
+        match self.checkpoints.get(index) {
+            Some((_, position)) => {
+                self.go_to(*position);
+                Ok(())
+            }
+            None => Err(MoveError::UnknownCheckpoint),
+        }
+    }
+
+    /// Provides the held game together with its history for saving,
+    /// unless there is no game or it is already won.
+    pub fn saved_game(&self, elapsed: Duration) -> Option<SavedGame> {
+        let game = self.game.as_ref().filter(|x| !x.is_won())?;
+
+        Some(SavedGame {
+            id: game.id.clone(),
+            moves: self.positions[1..]
+                .iter()
+                .map(|x| (x.parent.unwrap(), x.mv.clone().unwrap()))
+                .collect(),
+            current: self.current,
+            checkpoints: self.checkpoints.clone(),
+            elapsed,
+        })
+    }
+
+    /// Replaces the currently held game and its history (if any)
+    /// with the saved game, replaying all saved moves.
+    ///
+    /// If the saved game is inconsistent, no game is held afterwards.
+    /// The `elapsed` time spent playing the replaced game is recorded if it was abandoned.
+    /// Returns the time spent playing the resumed game before it was saved.
+    pub fn resume(
+        &mut self,
+        saved_game: &SavedGame,
+        elapsed: Duration,
+    ) -> Result<Duration, MoveError> {
+        let result = self.replay(saved_game);
+
+        match result {
+            Ok(()) => self
+                .stats_handler
+                .borrow_mut()
+                .receive_notification_game_started(
+                    saved_game.id.clone(),
+                    saved_game.length(),
+                    elapsed,
+                ),
+            Err(_) => {
+                self.game = None;
+                self.positions.clear();
+                self.current = 0;
+                self.checkpoints.clear();
+            }
+        }
+
+        result.map(|()| saved_game.elapsed)
+    }
+
+    /// Helper function to resume.
+    fn replay(&mut self, saved_game: &SavedGame) -> Result<(), MoveError> {
+        self.reset(saved_game.id.clone());
+
+        for (index, (parent, mv)) in saved_game.moves.iter().enumerate() {
+            if *parent >= self.positions.len() {
+                return Err(MoveError::UnknownBranch);
+            }
+
+            self.go_to(*parent);
+            self.advance(mv.clone())?;
+
+            // each move has to lead to a new position
+            if self.current != index + 1 {
+                return Err(MoveError::UnknownBranch);
+            }
+        }
+
+        if saved_game.current >= self.positions.len() {
+            return Err(MoveError::UnknownBranch);
+        }
+
+        if saved_game
+            .checkpoints
+            .iter()
+            .any(|x| x.1 >= self.positions.len())
+        {
+            return Err(MoveError::UnknownCheckpoint);
+        }
+
+        // redo follows the line leading to the current position
+        let mut index = saved_game.current;
+        while let Some(parent) = self.positions[index].parent {
+            self.positions[parent].next = Some(index);
+            index = parent;
+        }
+
+        self.go_to(saved_game.current);
+        self.checkpoints = saved_game.checkpoints.clone();
+
+        Ok(())
+    }
+
+    /// Makes the position with the given index the current one.
+    fn go_to(&mut self, index: usize) {
+        self.current = index;
+        self.game = Some(self.positions[index].game.clone());
+    }
+
+    /// Provides the indices of the positions without children,
+    /// *i.e.* the ends of the branches, depth-first.
+    fn leaves(&self) -> Vec<usize> {
+        let mut result = vec![];
+        let mut stack = if self.positions.is_empty() {
+            vec![]
+        } else {
+            vec![0]
+        };
+
+        while let Some(index) = stack.pop() {
+            let position = &self.positions[index];
+
+            if position.children.is_empty() {
+                result.push(index);
+            }
+
+            stack.extend(position.children.iter().rev());
+        }
+
+        result
+    }
+
+    /// Provides the indices of the positions from the initial position
+    /// up to and including the position with the given index.
+    fn line(&self, index: usize) -> Vec<usize> {
+        let mut result = vec![index];
+
+        while let Some(parent) = self.positions[*result.last().unwrap()].parent {
+            result.push(parent);
+        }
+
+        result.reverse();
+        result
+    }
+
+    /// Provides a hint for the currently held game, if it is not yet won.
+    ///
+    /// This involves running the solver and may take a moment,
+    /// compare `hint_request` to compute the hint elsewhere.
+    pub fn hint(&self) -> Option<Hint> {
+        self.hint_request().map(|x| x.solve())
+    }
+
+    /// Provides the data needed to compute a hint for the currently held game,
+    /// if it is not yet won, such that the hint can be computed on another thread.
+    pub fn hint_request(&self) -> Option<HintRequest> {
+        let game = self.game.as_ref().filter(|x| !x.is_won())?;
+
+        let mut history = self.line(self.current);
+        history.pop();
+
+        Some(HintRequest {
+            game: game.clone(),
+            history: history
+                .into_iter()
+                .map(|x| self.positions[x].game.clone())
+                .collect(),
+        })
+    }
+}
+
+/// The data needed to compute a hint, compare `GameHandler::hint_request`.
+#[derive(Clone, Debug)]
+pub struct HintRequest {
+    /// The game the hint is for.
+    pub game: Game,
+    /// The positions leading to `game`, starting with the initial position.
+    history: Vec<Game>,
+}
+
+impl HintRequest {
+    /// Computes the hint by running the solver, which may take a moment.
+    pub fn solve(&self) -> Hint {
+        let proven = match solve(&self.game, DEFAULT_BUDGET) {
+            Solution::Solved(moves) => return Hint::Move(moves[0].clone()),
+            Solution::Unsolvable => true,
+            Solution::BudgetExhausted => false,
+        };
+
+        // any position before a winnable position is winnable as well,
+        // so the last winnable position can be found by bisection
+        let mut lower = 0;
+        let mut upper = self.history.len();
+        let mut winnable: Option<usize> = None;
+
+        while lower < upper {
+            let middle = (lower + upper) / 2;
+
+            if let Solution::Solved(_) = solve(&self.history[middle], BISECTION_BUDGET) {
+                winnable = Some(middle);
+                lower = middle + 1;
+            } else {
+                upper = middle;
+            }
+        }
+
+        Hint::NoSolution {
+            proven,
+            reverts: winnable.map(|x| self.history.len() - x),
+        }
     }
 }
 
+/// Contains logic to persist a game in progress.
+pub mod game_repository;
+
 #[cfg(test)]
 mod test;
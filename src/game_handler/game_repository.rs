@@ -0,0 +1,208 @@
+/*
+    RSLibreCell - a FreeCell implementation
+    Copyright (C) 2025 and later: tristhaus
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::{convert::TryFrom, path::PathBuf, time::Duration};
+
+use mockall::automock;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::{GameId, MAX_GAME_ID},
+    r#move::Move,
+    repository_error::RepositoryError,
+};
+
+/// Defines a game in progress together with its history.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SavedGame {
+    /// The ID of the game.
+    pub id: GameId,
+    /// The moves made, each together with the index of the position it was made from.
+    /// The move with index `i` leads to the position with index `i + 1`,
+    /// the initial position having index 0.
+    pub moves: Vec<(usize, Move)>,
+    /// The index of the current position.
+    pub current: usize,
+    /// The checkpoints as names and indices of positions.
+    pub checkpoints: Vec<(String, usize)>,
+    /// The time spent playing the game so far.
+    pub elapsed: Duration,
+}
+
+impl SavedGame {
+    /// Gets the number of moves leading from the initial position to the current position.
+    pub fn length(&self) -> usize {
+        let mut result = 0;
+        let mut position = self.current;
+
+        while position > 0 && position <= self.moves.len() {
+            position = self.moves[position - 1].0;
+            result += 1;
+        }
+
+        result
+    }
+}
+
+#[automock]
+/// Defines the behavior to persist a game in progress.
+pub trait PersistGame {
+    /// Reads the saved game from the underlying medium, if any.
+    fn read(&self) -> Result<Option<SavedGame>, RepositoryError>;
+
+    /// Writes a game to the underlying medium, replacing any saved game.
+    fn write(&self, game: &SavedGame) -> Result<(), RepositoryError>;
+
+    /// Removes the saved game from the underlying medium, if any.
+    fn clear(&self) -> Result<(), RepositoryError>;
+}
+
+#[derive(Debug)]
+/// Productive implementation.
+pub struct DiskGameRepo {}
+
+impl PersistGame for DiskGameRepo {
+    fn read(&self) -> Result<Option<SavedGame>, RepositoryError> {
+        // without a data directory, no game can have been saved
+        let data_path = match DiskGameRepo::get_data_path() {
+            Some(data_path) => data_path,
+            None => return Ok(None),
+        };
+
+        match std::fs::exists(&data_path) {
+            Ok(true) => match std::fs::read_to_string(&data_path) {
+                Ok(data) => DiskGameRepo::deserialize(&data)
+                    .map(Some)
+                    .ok_or(RepositoryError::Malformed { path: data_path }),
+                Err(err) => Err(RepositoryError::Read {
+                    path: data_path,
+                    kind: err.kind(),
+                }),
+            },
+            Ok(false) => Ok(None),
+            Err(err) => Err(RepositoryError::Read {
+                path: data_path,
+                kind: err.kind(),
+            }),
+        }
+    }
+
+    fn write(&self, game: &SavedGame) -> Result<(), RepositoryError> {
+        let data_path = DiskGameRepo::get_data_path().ok_or(RepositoryError::NoDataDirectory)?;
+
+        let mut data_dir = data_path.clone();
+        data_dir.pop();
+
+        if let Err(err) = std::fs::create_dir_all(&data_dir) {
+            return Err(RepositoryError::Write {
+                path: data_dir,
+                kind: err.kind(),
+            });
+        }
+
+        std::fs::write(&data_path, DiskGameRepo::serialize(game)).map_err(|err| {
+            RepositoryError::Write {
+                path: data_path,
+                kind: err.kind(),
+            }
+        })
+    }
+
+    fn clear(&self) -> Result<(), RepositoryError> {
+        let data_path = match DiskGameRepo::get_data_path() {
+            Some(data_path) => data_path,
+            None => return Ok(()),
+        };
+
+        match std::fs::remove_file(&data_path) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(RepositoryError::Write {
+                path: data_path,
+                kind: err.kind(),
+            }),
+        }
+    }
+}
+
+/// The on-disk form of a saved game, using the standard notation for moves.
+#[derive(Serialize, Deserialize)]
+struct SavedGameData {
+    id: u64,
+    moves: Vec<(usize, String)>,
+    current: usize,
+    checkpoints: Vec<(String, usize)>,
+    /// The time spent playing in seconds, missing in files of older versions.
+    #[serde(default)]
+    elapsed: u64,
+}
+
+impl DiskGameRepo {
+    /// Gets the relevant full path to the file containing the saved game,
+    /// which is placed next to the journey data, if there is a data directory.
+    fn get_data_path() -> Option<PathBuf> {
+        let mut data_path = dirs::data_dir()?;
+
+        data_path.push("rslibrecell");
+        data_path.push("game.json");
+        Some(data_path)
+    }
+
+    /// Deserializes a saved game from JSON, yielding `None` if the data is malformed,
+    /// including a game ID out of range.
+    pub(crate) fn deserialize(data: &str) -> Option<SavedGame> {
+        let data: SavedGameData = serde_json::from_str(data).ok()?;
+
+        if data.id == 0 || data.id > MAX_GAME_ID {
+            return None;
+        }
+
+        let mut moves = vec![];
+        for (from, mv) in data.moves {
+            moves.push((from, Move::try_from(mv.as_str()).ok()?));
+        }
+
+        Some(SavedGame {
+            id: GameId(data.id),
+            moves,
+            current: data.current,
+            checkpoints: data.checkpoints,
+            elapsed: Duration::from_secs(data.elapsed),
+        })
+    }
+
+    /// Serializes a saved game into JSON.
+    pub(crate) fn serialize(game: &SavedGame) -> String {
+        let data = SavedGameData {
+            id: game.id.0,
+            moves: game
+                .moves
+                .iter()
+                .map(|(from, mv)| (*from, mv.to_string()))
+                .collect(),
+            current: game.current,
+            checkpoints: game.checkpoints.clone(),
+            elapsed: game.elapsed.as_secs(),
+        };
+
+        serde_json::to_string(&data).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,105 @@
+/*
+    RSLibreCell - a FreeCell implementation
+    Copyright (C) 2025 and later: tristhaus
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use super::*;
+
+mod helper {
+    use super::*;
+
+    pub fn saved_game() -> SavedGame {
+        SavedGame {
+            id: GameId(123),
+            moves: vec![
+                (0, Move::try_from("71").unwrap()),
+                (1, Move::try_from("8b").unwrap()),
+                (1, Move::try_from("8a").unwrap()),
+            ],
+            current: 2,
+            checkpoints: vec![("start".to_string(), 0), ("tricky".to_string(), 3)],
+            elapsed: Duration::from_secs(95),
+        }
+    }
+}
+
+const SAVED_GAME_JSON: &str = concat!(
+    r#"{"id":123,"moves":[[0,"71"],[1,"8b"],[1,"8a"]],"current":2,"#,
+    r#""checkpoints":[["start",0],["tricky",3]],"elapsed":95}"#
+);
+
+#[test]
+fn serialize_saved_game() {
+    let actual = DiskGameRepo::serialize(&helper::saved_game());
+
+    assert_eq!(SAVED_GAME_JSON, actual);
+}
+
+#[test]
+fn deserialize_saved_game() {
+    let actual = DiskGameRepo::deserialize(SAVED_GAME_JSON);
+
+    assert_eq!(Some(helper::saved_game()), actual);
+}
+
+#[test]
+fn deserialize_saved_game_without_elapsed_time() {
+    let data = concat!(
+        r#"{"id":123,"moves":[[0,"71"],[1,"8b"],[1,"8a"]],"current":2,"#,
+        r#""checkpoints":[["start",0],["tricky",3]]}"#
+    );
+
+    let actual = DiskGameRepo::deserialize(data);
+
+    assert_eq!(
+        Some(SavedGame {
+            elapsed: Duration::ZERO,
+            ..helper::saved_game()
+        }),
+        actual
+    );
+}
+
+#[test]
+fn deserialize_malformed_data_yields_none() {
+    assert!(DiskGameRepo::deserialize("").is_none());
+    assert!(DiskGameRepo::deserialize(r#"{"id":123}"#).is_none());
+    assert!(DiskGameRepo::deserialize(
+        r#"{"id":123,"moves":[[0,"7x"]],"current":1,"checkpoints":[]}"#
+    )
+    .is_none());
+}
+
+#[test]
+fn deserialize_game_id_out_of_range_yields_none() {
+    let json = |id: u64| SAVED_GAME_JSON.replace("123", &id.to_string());
+
+    assert!(DiskGameRepo::deserialize(&json(MAX_GAME_ID)).is_some());
+    assert!(DiskGameRepo::deserialize(&json(0)).is_none());
+    assert!(DiskGameRepo::deserialize(&json(MAX_GAME_ID + 1)).is_none());
+    assert!(DiskGameRepo::deserialize(&json(u64::MAX)).is_none());
+}
+
+#[test]
+fn saved_game_length_counts_moves_to_current_position() {
+    let mut saved_game = helper::saved_game();
+
+    assert_eq!(2, saved_game.length());
+
+    saved_game.current = 0;
+
+    assert_eq!(0, saved_game.length());
+}
@@ -19,7 +19,9 @@
 use mockall::predicate;
 
 use crate::{
-    card::Card, journey_handler::journey_repository::MockPersistJourney, r#move::Location,
+    card::Card, game_handler::game_repository::SavedGame,
+    journey_handler::journey_repository::MockPersistJourney, r#move::Location,
+    stats_handler::stats_repository::MockPersistStats,
 };
 
 use super::*;
@@ -30,14 +32,14 @@ fn gamehandler_game_from_id_contains_expected() {
 
     assert!(game_handler.game.is_none());
 
-    game_handler.game_from_id(GameId(1));
+    game_handler.game_from_id(GameId(1), Duration::ZERO);
 
     assert!(game_handler
         .game
         .as_ref()
         .is_some_and(|x| x.columns[0][0] == Card::from_str("J♦")));
 
-    game_handler.game_from_id(GameId(2));
+    game_handler.game_from_id(GameId(2), Duration::ZERO);
 
     assert!(game_handler
         .game
@@ -48,7 +50,7 @@ fn gamehandler_game_from_id_contains_expected() {
 #[test]
 fn gamehandler_random_game_creates_different_game() {
     let mut game_handler = helper::setup_game_handler();
-    game_handler.game_from_id(GameId(1));
+    game_handler.game_from_id(GameId(1), Duration::ZERO);
 
     assert!(game_handler
         .game
@@ -56,7 +58,7 @@ fn gamehandler_random_game_creates_different_game() {
         .is_some_and(|x| x.columns[0][0] == Card::from_str("J♦")));
 
     for _ in 0..10 {
-        game_handler.random_game();
+        game_handler.random_game(Duration::ZERO);
 
         if game_handler
             .game
@@ -67,13 +69,13 @@ fn gamehandler_random_game_creates_different_game() {
         }
     }
 
-    assert!(false);
+    panic!("all random games started with the same card");
 }
 
 #[test]
 fn gamehandler_make_move_works_correctly() {
     let mut game_handler = helper::setup_game_handler();
-    game_handler.game_from_id(GameId(123));
+    game_handler.game_from_id(GameId(123), Duration::ZERO);
 
     let initial = game_handler.game.as_ref().unwrap().to_string();
 
@@ -107,17 +109,23 @@ fn gamehandler_make_move_works_correctly() {
     assert_eq!(initial_reference, initial);
 
     assert!(game_handler
-        .make_move(Move {
-            from: Location::Column { i: 6 },
-            to: Location::Column { i: 0 }
-        })
+        .make_move(
+            Move {
+                from: Location::Column { i: 6 },
+                to: Location::Column { i: 0 }
+            },
+            Duration::ZERO
+        )
         .is_ok());
 
     assert!(game_handler
-        .make_move(Move {
-            from: Location::Column { i: 7 },
-            to: Location::Cell { i: 1 }
-        })
+        .make_move(
+            Move {
+                from: Location::Column { i: 7 },
+                to: Location::Cell { i: 1 }
+            },
+            Duration::ZERO
+        )
         .is_ok());
 
     let state = game_handler.game.as_ref().unwrap().to_string();
@@ -155,33 +163,75 @@ fn gamehandler_make_move_works_correctly() {
 #[test]
 fn gamehandler_make_move_rejects_illegal_move() {
     let mut game_handler = helper::setup_game_handler();
-    game_handler.game_from_id(GameId(123));
+    game_handler.game_from_id(GameId(123), Duration::ZERO);
+
+    assert_eq!(
+        Err(MoveError::ColorMismatch),
+        game_handler.make_move(
+            Move {
+                from: Location::Column { i: 4 },
+                to: Location::Column { i: 7 }
+            },
+            Duration::ZERO
+        )
+    );
+}
 
-    assert!(game_handler
-        .make_move(Move {
-            from: Location::Column { i: 4 },
-            to: Location::Column { i: 7 }
-        })
-        .is_err());
+#[test]
+fn gamehandler_make_move_without_game_fails() {
+    let mut game_handler = helper::setup_game_handler();
+
+    assert_eq!(
+        Err(MoveError::NoGame),
+        game_handler.make_move(
+            Move {
+                from: Location::Column { i: 0 },
+                to: Location::Cell { i: 0 }
+            },
+            Duration::ZERO
+        )
+    );
 }
 
 #[test]
-fn gamehandler_win_entire_game_and_trigger_journey_handler() {
+fn gamehandler_revert_without_history_fails() {
+    let mut game_handler = helper::setup_game_handler();
+
+    assert_eq!(Err(MoveError::NothingToRevert), game_handler.revert());
+
+    game_handler.game_from_id(GameId(123), Duration::ZERO);
+
+    assert_eq!(Err(MoveError::NothingToRevert), game_handler.revert());
+}
+
+#[test]
+fn gamehandler_win_entire_game_and_trigger_journey_and_stats_handlers() {
     let mut mock = MockPersistJourney::new();
+    mock.expect_check_lock().return_const(Ok(()));
     mock.expect_read()
-        .return_const((GameId(123), vec![GameId(100), GameId(118)]));
+        .return_const(Ok((GameId(123), vec![GameId(100), GameId(118)])));
     mock.expect_write()
         .with(predicate::eq(GameId(123)), predicate::eq(vec![GameId(118)]))
         .once()
-        .return_const(());
+        .return_const(Ok(()));
+
+    let mut stats_mock = MockPersistStats::new();
+    stats_mock.expect_read().return_const(Ok(vec![]));
+    stats_mock
+        .expect_write()
+        .withf(|records| records.len() == 1 && records[0].id == GameId(100) && records[0].won)
+        .once()
+        .return_const(Ok(()));
 
     let journey_handler = Rc::new(RefCell::new(JourneyHandler::new(mock)));
-    let mut game_handler = GameHandler::new(journey_handler);
+    let stats_handler = Rc::new(RefCell::new(StatsHandler::new(stats_mock)));
+    let mut game_handler = GameHandler::new(journey_handler, stats_handler);
 
-    game_handler.game_from_id(GameId(100));
+    game_handler.game_from_id(GameId(100), Duration::ZERO);
+    assert!(game_handler.set_checkpoint("start").is_ok());
 
     let mut make_move_and_assert = |mv: Move| {
-        assert!(game_handler.make_move(mv).is_ok());
+        assert!(game_handler.make_move(mv, Duration::ZERO).is_ok());
     };
 
     make_move_and_assert(Move {
@@ -310,23 +360,33 @@ fn gamehandler_win_entire_game_and_trigger_journey_handler() {
     });
 
     assert!(game_handler.game.as_ref().unwrap().is_won());
+    assert_eq!((31, 51), game_handler.move_counts());
 
     // no move on won game
-    assert!(game_handler
-        .make_move(Move {
-            from: Location::Column { i: 0 },
-            to: Location::Column { i: 1 },
-        })
-        .is_err());
+    assert_eq!(
+        Err(MoveError::GameWon),
+        game_handler.make_move(
+            Move {
+                from: Location::Column { i: 0 },
+                to: Location::Column { i: 1 },
+            },
+            Duration::ZERO
+        )
+    );
 
     // no revert on won game
-    assert!(game_handler.revert().is_err())
+    assert_eq!(Err(MoveError::GameWon), game_handler.revert());
+
+    // no switching branches or jumping to checkpoints on won game either
+    assert_eq!(Err(MoveError::GameWon), game_handler.switch_to_branch(0));
+    assert_eq!(Err(MoveError::GameWon), game_handler.jump_to_checkpoint(0));
+    assert!(game_handler.game.as_ref().unwrap().is_won());
 }
 
 #[test]
 fn gamehandler_revert_works_correctly() {
     let mut game_handler = helper::setup_game_handler();
-    game_handler.game_from_id(GameId(123));
+    game_handler.game_from_id(GameId(123), Duration::ZERO);
 
     let initial_reference = concat!(
         "RustLibreCell                #123 \n",
@@ -356,17 +416,23 @@ fn gamehandler_revert_works_correctly() {
     );
 
     assert!(game_handler
-        .make_move(Move {
-            from: Location::Column { i: 6 },
-            to: Location::Column { i: 0 }
-        })
+        .make_move(
+            Move {
+                from: Location::Column { i: 6 },
+                to: Location::Column { i: 0 }
+            },
+            Duration::ZERO
+        )
         .is_ok());
 
     assert!(game_handler
-        .make_move(Move {
-            from: Location::Column { i: 7 },
-            to: Location::Cell { i: 1 }
-        })
+        .make_move(
+            Move {
+                from: Location::Column { i: 7 },
+                to: Location::Cell { i: 1 }
+            },
+            Duration::ZERO
+        )
         .is_ok());
 
     assert!(game_handler.revert().is_ok());
@@ -377,18 +443,337 @@ fn gamehandler_revert_works_correctly() {
     assert_eq!(initial_reference, state);
 }
 
+#[test]
+fn gamehandler_redo_replays_reverted_moves() {
+    let mut game_handler = helper::setup_game_handler();
+    game_handler.game_from_id(GameId(123), Duration::ZERO);
+
+    assert_eq!(Err(MoveError::NothingToRedo), game_handler.redo());
+
+    assert!(game_handler
+        .make_move(Move::try_from("71").unwrap(), Duration::ZERO)
+        .is_ok());
+    let first_state = game_handler.game.clone();
+
+    assert!(game_handler
+        .make_move(Move::try_from("8b").unwrap(), Duration::ZERO)
+        .is_ok());
+    let second_state = game_handler.game.clone();
+
+    assert!(game_handler.revert().is_ok());
+    assert!(game_handler.revert().is_ok());
+
+    assert!(game_handler.redo().is_ok());
+    assert_eq!(first_state, game_handler.game);
+
+    assert!(game_handler.redo().is_ok());
+    assert_eq!(second_state, game_handler.game);
+
+    assert_eq!(Err(MoveError::NothingToRedo), game_handler.redo());
+
+    // the redone moves can be reverted again
+    assert!(game_handler.revert().is_ok());
+    assert_eq!(first_state, game_handler.game);
+}
+
+#[test]
+fn gamehandler_redo_is_discarded_by_new_move() {
+    let mut game_handler = helper::setup_game_handler();
+    game_handler.game_from_id(GameId(123), Duration::ZERO);
+
+    assert!(game_handler
+        .make_move(Move::try_from("71").unwrap(), Duration::ZERO)
+        .is_ok());
+    assert!(game_handler.revert().is_ok());
+
+    assert!(game_handler
+        .make_move(Move::try_from("8b").unwrap(), Duration::ZERO)
+        .is_ok());
+
+    assert_eq!(Err(MoveError::NothingToRedo), game_handler.redo());
+
+    assert!(game_handler.revert().is_ok());
+    game_handler.game_from_id(GameId(124), Duration::ZERO);
+
+    assert_eq!(Err(MoveError::NothingToRedo), game_handler.redo());
+}
+
+#[test]
+fn gamehandler_branches_keep_alternative_lines() {
+    let mut game_handler = helper::setup_game_handler();
+
+    assert!(game_handler.branches().is_empty());
+
+    game_handler.game_from_id(GameId(123), Duration::ZERO);
+
+    assert_eq!(
+        vec![Branch {
+            length: 0,
+            last_move: None,
+            active: true
+        }],
+        game_handler.branches()
+    );
+
+    assert!(game_handler
+        .make_move(Move::try_from("71").unwrap(), Duration::ZERO)
+        .is_ok());
+    assert!(game_handler
+        .make_move(Move::try_from("8b").unwrap(), Duration::ZERO)
+        .is_ok());
+    let first_line = game_handler.game.clone();
+
+    assert!(game_handler.revert().is_ok());
+    assert!(game_handler
+        .make_move(Move::try_from("8a").unwrap(), Duration::ZERO)
+        .is_ok());
+    let second_line = game_handler.game.clone();
+
+    assert_eq!(
+        vec![
+            Branch {
+                length: 2,
+                last_move: Some(Move::try_from("8b").unwrap()),
+                active: false
+            },
+            Branch {
+                length: 2,
+                last_move: Some(Move::try_from("8a").unwrap()),
+                active: true
+            }
+        ],
+        game_handler.branches()
+    );
+
+    assert!(game_handler.switch_to_branch(0).is_ok());
+    assert_eq!(first_line, game_handler.game);
+    assert!(game_handler.branches()[0].active);
+
+    // reverting and redoing follows the branch last left
+    assert!(game_handler.revert().is_ok());
+    assert!(game_handler.redo().is_ok());
+    assert_eq!(first_line, game_handler.game);
+
+    assert!(game_handler.switch_to_branch(1).is_ok());
+    assert_eq!(second_line, game_handler.game);
+
+    assert_eq!(
+        Err(MoveError::UnknownBranch),
+        game_handler.switch_to_branch(2)
+    );
+}
+
+#[test]
+fn gamehandler_repeated_move_continues_existing_branch() {
+    let mut game_handler = helper::setup_game_handler();
+    game_handler.game_from_id(GameId(123), Duration::ZERO);
+
+    assert!(game_handler
+        .make_move(Move::try_from("71").unwrap(), Duration::ZERO)
+        .is_ok());
+    assert!(game_handler.revert().is_ok());
+    assert!(game_handler
+        .make_move(Move::try_from("71").unwrap(), Duration::ZERO)
+        .is_ok());
+
+    assert_eq!(1, game_handler.branches().len());
+}
+
+#[test]
+fn gamehandler_checkpoints_can_be_jumped_to() {
+    let mut game_handler = helper::setup_game_handler();
+
+    assert_eq!(Err(MoveError::NoGame), game_handler.set_checkpoint("start"));
+
+    game_handler.game_from_id(GameId(123), Duration::ZERO);
+    let initial = game_handler.game.clone();
+
+    assert!(game_handler.set_checkpoint("start").is_ok());
+    assert!(game_handler
+        .make_move(Move::try_from("71").unwrap(), Duration::ZERO)
+        .is_ok());
+    assert!(game_handler.set_checkpoint("tricky").is_ok());
+    let tricky = game_handler.game.clone();
+    assert!(game_handler
+        .make_move(Move::try_from("8b").unwrap(), Duration::ZERO)
+        .is_ok());
+
+    assert_eq!(
+        vec![
+            Checkpoint {
+                name: "start".to_string(),
+                length: 0
+            },
+            Checkpoint {
+                name: "tricky".to_string(),
+                length: 1
+            }
+        ],
+        game_handler.checkpoints()
+    );
+
+    assert!(game_handler.jump_to_checkpoint(0).is_ok());
+    assert_eq!(initial, game_handler.game);
+
+    assert!(game_handler.jump_to_checkpoint(1).is_ok());
+    assert_eq!(tricky, game_handler.game);
+
+    // the line beyond the checkpoint is kept
+    assert!(game_handler.redo().is_ok());
+
+    // setting a checkpoint of the same name moves it
+    assert!(game_handler.set_checkpoint("start").is_ok());
+    assert_eq!(2, game_handler.checkpoints()[0].length);
+
+    assert_eq!(
+        Err(MoveError::UnknownCheckpoint),
+        game_handler.jump_to_checkpoint(2)
+    );
+
+    game_handler.game_from_id(GameId(124), Duration::ZERO);
+
+    assert!(game_handler.checkpoints().is_empty());
+}
+
+#[test]
+fn gamehandler_checkpoints_are_limited() {
+    let mut game_handler = helper::setup_game_handler();
+    game_handler.game_from_id(GameId(123), Duration::ZERO);
+
+    for i in 0..MAX_CHECKPOINTS {
+        assert!(game_handler.set_checkpoint(&i.to_string()).is_ok());
+    }
+
+    assert_eq!(
+        Err(MoveError::TooManyCheckpoints),
+        game_handler.set_checkpoint("extra")
+    );
+    assert_eq!(MAX_CHECKPOINTS, game_handler.checkpoints().len());
+
+    // an existing checkpoint can still be moved
+    assert!(game_handler
+        .make_move(Move::try_from("71").unwrap(), Duration::ZERO)
+        .is_ok());
+    assert!(game_handler.set_checkpoint("0").is_ok());
+    assert_eq!(1, game_handler.checkpoints()[0].length);
+}
+
+#[test]
+fn gamehandler_saved_game_can_be_resumed() {
+    let mut game_handler = helper::setup_game_handler();
+
+    assert!(game_handler.saved_game(Duration::ZERO).is_none());
+
+    game_handler.game_from_id(GameId(123), Duration::ZERO);
+    assert!(game_handler.set_checkpoint("start").is_ok());
+    assert!(game_handler
+        .make_move(Move::try_from("71").unwrap(), Duration::ZERO)
+        .is_ok());
+    assert!(game_handler
+        .make_move(Move::try_from("8b").unwrap(), Duration::ZERO)
+        .is_ok());
+    assert!(game_handler.revert().is_ok());
+    assert!(game_handler
+        .make_move(Move::try_from("8a").unwrap(), Duration::ZERO)
+        .is_ok());
+    assert!(game_handler.switch_to_branch(0).is_ok());
+    assert!(game_handler.revert().is_ok());
+
+    let saved_game = game_handler.saved_game(Duration::from_secs(42)).unwrap();
+
+    assert_eq!(
+        SavedGame {
+            id: GameId(123),
+            moves: vec![
+                (0, Move::try_from("71").unwrap()),
+                (1, Move::try_from("8b").unwrap()),
+                (1, Move::try_from("8a").unwrap()),
+            ],
+            current: 1,
+            checkpoints: vec![("start".to_string(), 0)],
+            elapsed: Duration::from_secs(42),
+        },
+        saved_game
+    );
+
+    let mut resumed = helper::setup_game_handler();
+
+    assert_eq!(
+        Ok(Duration::from_secs(42)),
+        resumed.resume(&saved_game, Duration::ZERO)
+    );
+
+    assert_eq!(game_handler.game, resumed.game);
+    assert_eq!(game_handler.branches().len(), resumed.branches().len());
+    assert_eq!(game_handler.checkpoints(), resumed.checkpoints());
+    assert_eq!(
+        Some(saved_game),
+        resumed.saved_game(Duration::from_secs(42))
+    );
+}
+
+#[test]
+fn gamehandler_resume_rejects_inconsistent_saved_game() {
+    let mut game_handler = helper::setup_game_handler();
+
+    let illegal_move = SavedGame {
+        id: GameId(123),
+        moves: vec![(0, Move::try_from("58").unwrap())],
+        current: 1,
+        checkpoints: vec![],
+        elapsed: Duration::ZERO,
+    };
+
+    assert_eq!(
+        Err(MoveError::ColorMismatch),
+        game_handler.resume(&illegal_move, Duration::ZERO)
+    );
+    assert!(game_handler.game.is_none());
+
+    let unknown_position = SavedGame {
+        id: GameId(123),
+        moves: vec![(1, Move::try_from("71").unwrap())],
+        current: 0,
+        checkpoints: vec![],
+        elapsed: Duration::ZERO,
+    };
+
+    assert_eq!(
+        Err(MoveError::UnknownBranch),
+        game_handler.resume(&unknown_position, Duration::ZERO)
+    );
+    assert!(game_handler.game.is_none());
+
+    let unknown_checkpoint = SavedGame {
+        id: GameId(123),
+        moves: vec![],
+        current: 0,
+        checkpoints: vec![("start".to_string(), 1)],
+        elapsed: Duration::ZERO,
+    };
+
+    assert_eq!(
+        Err(MoveError::UnknownCheckpoint),
+        game_handler.resume(&unknown_checkpoint, Duration::ZERO)
+    );
+    assert!(game_handler.game.is_none());
+}
+
 #[test]
 fn gamehandler_revert_errors_on_initial_state() {
     let mut game_handler = helper::setup_game_handler();
-    game_handler.game_from_id(GameId(123));
+    game_handler.game_from_id(GameId(123), Duration::ZERO);
 
     assert!(game_handler.revert().is_err());
 
     assert!(game_handler
-        .make_move(Move {
-            from: Location::Column { i: 6 },
-            to: Location::Column { i: 0 }
-        })
+        .make_move(
+            Move {
+                from: Location::Column { i: 6 },
+                to: Location::Column { i: 0 }
+            },
+            Duration::ZERO
+        )
         .is_ok());
 
     assert!(game_handler.revert().is_ok());
@@ -396,15 +781,203 @@ fn gamehandler_revert_errors_on_initial_state() {
     assert!(game_handler.revert().is_err());
 }
 
+#[test]
+fn gamehandler_hint_provides_legal_move() {
+    let mut game_handler = helper::setup_game_handler();
+
+    assert!(game_handler.hint().is_none());
+
+    game_handler.game_from_id(GameId(123), Duration::ZERO);
+
+    let hint = game_handler.hint();
+
+    match hint {
+        Some(Hint::Move(mv)) => assert!(game_handler.make_move(mv, Duration::ZERO).is_ok()),
+        _ => panic!("should have provided a move"),
+    }
+}
+
+#[test]
+fn gamehandler_hint_on_won_game_is_none() {
+    let mut game_handler = helper::setup_game_handler();
+
+    let won = concat!(
+        "RustLibreCell                #123 \n",
+        "\n",
+        " ..  ..  ..  .. || K♣  K♠  K♥  K♦ \n",
+        "--------------------------------- \n",
+        "                                  \n",
+    );
+
+    game_handler.game = Some(Game::try_from(won).unwrap());
+
+    assert!(game_handler.hint().is_none());
+}
+
+#[test]
+fn gamehandler_hint_on_unwinnable_game_suggests_reverts() {
+    let mut game_handler = helper::setup_game_handler();
+    game_handler.game_from_id(GameId(123), Duration::ZERO);
+
+    let unwinnable = concat!(
+        "RustLibreCell                #123 \n",
+        "\n",
+        " K♣  K♦  K♥  K♠ || ..  ..  ..  .. \n",
+        "--------------------------------- \n",
+        "  A♣  A♦  A♥  A♠  2♣  2♦  2♥  2♠  \n",
+        "  3♣  3♦  3♥  3♠  4♣  4♦  4♥  4♠  \n",
+        "  5♣  5♦  5♥  5♠  6♣  6♦  6♥  6♠  \n",
+        "  7♣  7♦  7♥  7♠  8♣  8♦  8♥  8♠  \n",
+        "  9♦  9♥  T♦  T♥  J♦  J♥  Q♦  Q♥  \n",
+        "  Q♣  Q♠  J♣  J♠  T♣  T♠  9♣  9♠  \n",
+        "                                  \n",
+    );
+
+    let unwinnable = Game::try_from(unwinnable).unwrap();
+
+    for parent in 0..2 {
+        game_handler.positions.push(Position {
+            game: unwinnable.clone(),
+            mv: None,
+            automoves: 0,
+            parent: Some(parent),
+            children: vec![],
+            next: None,
+        });
+        game_handler.positions[parent].children.push(parent + 1);
+    }
+    game_handler.current = 2;
+    game_handler.game = Some(unwinnable);
+
+    assert!(matches!(
+        game_handler.hint(),
+        Some(Hint::NoSolution {
+            proven: true,
+            reverts: Some(2)
+        })
+    ));
+}
+
+#[test]
+fn gamehandler_abandoned_game_is_recorded_with_moves_and_reverts() {
+    let mut stats_mock = MockPersistStats::new();
+    stats_mock.expect_read().return_const(Ok(vec![]));
+    stats_mock
+        .expect_write()
+        .withf(|records| {
+            records.len() == 1
+                && records[0].id == GameId(123)
+                && !records[0].won
+                && records[0].moves == 3
+                && records[0].reverts == 1
+        })
+        .once()
+        .return_const(Ok(()));
+
+    let mut game_handler = helper::setup_game_handler_with_stats(stats_mock);
+    game_handler.game_from_id(GameId(123), Duration::ZERO);
+
+    assert!(game_handler
+        .make_move(Move::try_from("71").unwrap(), Duration::ZERO)
+        .is_ok());
+    assert!(game_handler
+        .make_move(Move::try_from("8b").unwrap(), Duration::ZERO)
+        .is_ok());
+    assert!(game_handler.revert().is_ok());
+    assert!(game_handler.redo().is_ok());
+
+    // a failed move is not counted
+    assert!(game_handler
+        .make_move(Move::try_from("1h").unwrap(), Duration::ZERO)
+        .is_err());
+
+    game_handler.game_from_id(GameId(124), Duration::ZERO);
+}
+
+#[test]
+fn gamehandler_resumed_game_is_recorded_with_moves_made_before() {
+    let mut stats_mock = MockPersistStats::new();
+    stats_mock.expect_read().return_const(Ok(vec![]));
+    stats_mock
+        .expect_write()
+        .withf(|records| {
+            records.len() == 1
+                && records[0].id == GameId(123)
+                && records[0].moves == 2
+                && records[0].reverts == 0
+        })
+        .once()
+        .return_const(Ok(()));
+
+    let mut game_handler = helper::setup_game_handler_with_stats(stats_mock);
+
+    assert!(game_handler
+        .resume(
+            &SavedGame {
+                id: GameId(123),
+                moves: vec![
+                    (0, Move::try_from("71").unwrap()),
+                    (1, Move::try_from("8b").unwrap()),
+                ],
+                current: 2,
+                checkpoints: vec![],
+                elapsed: Duration::ZERO,
+            },
+            Duration::ZERO
+        )
+        .is_ok());
+
+    game_handler.game_from_id(GameId(124), Duration::ZERO);
+}
+
+#[test]
+fn gamehandler_move_counts_follow_current_position() {
+    let mut game_handler = helper::setup_game_handler();
+
+    assert_eq!((0, 0), game_handler.move_counts());
+
+    game_handler.game_from_id(GameId(123), Duration::ZERO);
+    assert_eq!((0, 0), game_handler.move_counts());
+
+    assert!(game_handler
+        .make_move(Move::try_from("71").unwrap(), Duration::ZERO)
+        .is_ok());
+    assert!(game_handler
+        .make_move(Move::try_from("8b").unwrap(), Duration::ZERO)
+        .is_ok());
+    assert_eq!(2, game_handler.move_counts().0);
+
+    assert!(game_handler.revert().is_ok());
+    assert_eq!(1, game_handler.move_counts().0);
+
+    assert!(game_handler.redo().is_ok());
+    assert_eq!(2, game_handler.move_counts().0);
+
+    game_handler.game_from_id(GameId(124), Duration::ZERO);
+    assert_eq!((0, 0), game_handler.move_counts());
+}
+
 mod helper {
     use super::*;
 
-    pub fn setup_game_handler() -> GameHandler<MockPersistJourney> {
+    pub fn setup_game_handler() -> GameHandler<MockPersistJourney, MockPersistStats> {
+        let mut stats_mock = MockPersistStats::new();
+        stats_mock.expect_read().return_const(Ok(vec![]));
+        stats_mock.expect_write().return_const(Ok(()));
+
+        setup_game_handler_with_stats(stats_mock)
+    }
+
+    pub fn setup_game_handler_with_stats(
+        stats_mock: MockPersistStats,
+    ) -> GameHandler<MockPersistJourney, MockPersistStats> {
         let mut mock = MockPersistJourney::new();
+        mock.expect_check_lock().return_const(Ok(()));
         mock.expect_read()
-            .return_const((GameId(123), vec![GameId(117), GameId(118)]));
-        mock.expect_write().return_const(());
+            .return_const(Ok((GameId(123), vec![GameId(117), GameId(118)])));
+        mock.expect_write().return_const(Ok(()));
         let journey_handler = Rc::new(RefCell::new(JourneyHandler::new(mock)));
-        GameHandler::new(journey_handler)
+        let stats_handler = Rc::new(RefCell::new(StatsHandler::new(stats_mock)));
+        GameHandler::new(journey_handler, stats_handler)
     }
 }
@@ -16,7 +16,7 @@
     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
-use crate::game::GameId;
+use crate::{game::GameId, repository_error::RepositoryError};
 use journey_repository::PersistJourney;
 
 /// Productive implementation of `HandleJourney`.
@@ -28,6 +28,11 @@ where
     next: GameId,
     repository: T,
     skipped: Vec<GameId>,
+    /// Indicates that the journey must not be written, as it could not be read
+    /// or another instance writes it, and writing would overwrite the user's progress.
+    read_only: bool,
+    /// The last error of the repository not yet taken, if any.
+    error: Option<RepositoryError>,
 }
 
 impl<T> JourneyHandler<T>
@@ -66,6 +71,11 @@ where
         self.next = GameId(self.next.0 + 1);
         self.persist();
     }
+
+    /// Takes the last error of the repository, if any.
+    pub fn take_error(&mut self) -> Option<RepositoryError> {
+        self.error.take()
+    }
 }
 
 impl<T> JourneyHandler<T>
@@ -73,20 +83,40 @@ where
     T: PersistJourney,
 {
     /// Constructs a new `JourneyHandler` instance.
+    ///
+    /// If the journey cannot be read, a new journey is started,
+    /// which is not persisted in order to keep the data on the medium.
+    /// If another instance writes the journey, it is read, but not persisted.
     pub fn new(repository: T) -> JourneyHandler<T> {
-        let data = repository.read();
+        let (data, error) = match repository.read() {
+            Ok(data) => (data, repository.check_lock().err()),
+            Err(error) => ((GameId(1), vec![]), Some(error)),
+        };
 
         JourneyHandler {
             next: data.0,
-            skipped: data.1.clone(),
+            skipped: data.1,
             repository,
+            read_only: error.is_some(),
+            error,
         }
     }
 
     /// Persists the current state of the journey using the repository.
-    fn persist(&self) -> () {
-        self.repository
-            .write(self.next.clone(), self.skipped.clone());
+    fn persist(&mut self) -> () {
+        if self.read_only {
+            return;
+        }
+
+        if let Err(error) = self
+            .repository
+            .write(self.next.clone(), self.skipped.clone())
+        {
+            if let RepositoryError::Locked { .. } = error {
+                self.read_only = true;
+            }
+            self.error = Some(error);
+        }
     }
 }
 
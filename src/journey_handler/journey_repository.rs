@@ -16,143 +16,371 @@
     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
+use std::{
+    fs::{File, OpenOptions, TryLockError},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
 use mockall::automock;
 
-use crate::game::GameId;
+use crate::{game::GameId, repository_error::RepositoryError};
 
 #[automock]
 /// Defines the behavior to persist a journey state.
 pub trait PersistJourney {
     /// Reads a journey from the underlying medium.
-    fn read(&self) -> (GameId, Vec<GameId>);
+    fn read(&self) -> Result<(GameId, Vec<GameId>), RepositoryError>;
 
     /// Writes a journey to the underlying medium.
-    fn write(&self, next: GameId, skipped: Vec<GameId>) -> ();
+    fn write(&self, next: GameId, skipped: Vec<GameId>) -> Result<(), RepositoryError>;
+
+    /// Checks whether another instance writes the journey,
+    /// in which case `RepositoryError::Locked` is returned.
+    fn check_lock(&self) -> Result<(), RepositoryError>;
 }
 
+/// The magic bytes at the start of versioned journey data.
+const MAGIC: [u8; 4] = *b"RSLJ";
+
+/// The version of the journey data written.
+const VERSION: u16 = 1;
+
 #[derive(Debug)]
 /// Productive implementation.
-pub struct DiskJourneyRepo {}
+///
+/// Only one instance at a time may write the journey, which is ensured
+/// by an advisory lock on a file next to the journey data.
+/// Other instances can read the journey, but do not write it.
+pub struct DiskJourneyRepo {
+    /// The full path to the file containing the journey data, if any.
+    data_path: Option<PathBuf>,
+    /// The locked file, which is held as long as the repository exists,
+    /// or the reason the lock could not be acquired.
+    lock: Result<File, RepositoryError>,
+}
 
 impl PersistJourney for DiskJourneyRepo {
-    fn read(&self) -> (GameId, Vec<GameId>) {
-        let data_path = DiskJourneyRepo::get_data_path();
-
-        match std::fs::exists(&data_path) {
-            Ok(result) => {
-                if result {
-                    let bytes = DiskJourneyRepo::read_from_file(data_path.as_path());
-                    return DiskJourneyRepo::deserialize(&bytes);
-                } else {
-                    (GameId(1), vec![])
-                }
-            }
-            Err(err) => panic!("std::fs error '{}'", err.kind().to_string()),
+    fn read(&self) -> Result<(GameId, Vec<GameId>), RepositoryError> {
+        // without a data directory, no journey can have been saved
+        match &self.data_path {
+            Some(data_path) => DiskJourneyRepo::read_journey(data_path),
+            None => Ok((GameId(1), vec![])),
         }
     }
 
-    fn write(&self, next: GameId, skipped: Vec<GameId>) -> () {
-        let data_path = DiskJourneyRepo::get_data_path();
+    fn write(&self, next: GameId, skipped: Vec<GameId>) -> Result<(), RepositoryError> {
+        let data_path = self
+            .data_path
+            .as_ref()
+            .ok_or(RepositoryError::NoDataDirectory)?;
 
-        let mut data_dir = data_path.clone();
-        data_dir.pop();
+        if let Err(error) = &self.lock {
+            return Err(error.clone());
+        }
 
-        match std::fs::exists(&data_dir) {
-            Ok(result) => {
-                if !result {
-                    match std::fs::create_dir(&data_dir) {
-                        Ok(()) => {}
-                        Err(err) => panic!(
-                            "std::fs error '{}' regarding {}",
-                            err.kind().to_string(),
-                            data_dir.to_string_lossy()
-                        ),
-                    }
-                }
-            }
-            Err(err) => panic!(
-                "std::fs error '{}' regarding {}",
-                err.kind().to_string(),
-                data_dir.to_string_lossy()
-            ),
+        DiskJourneyRepo::write_journey(data_path, next, skipped)
+    }
+
+    fn check_lock(&self) -> Result<(), RepositoryError> {
+        // other problems with the lock are reported when writing
+        match &self.lock {
+            Err(error @ RepositoryError::Locked { .. }) => Err(error.clone()),
+            _ => Ok(()),
         }
+    }
+}
 
-        let bytes = DiskJourneyRepo::serialize(next, skipped);
-        DiskJourneyRepo::write_to_file(&data_path, &bytes);
+impl Default for DiskJourneyRepo {
+    fn default() -> Self {
+        DiskJourneyRepo::new()
     }
 }
 
 impl DiskJourneyRepo {
-    /// Gets the relevant full path to the file containing the journey data.
-    fn get_data_path() -> std::path::PathBuf {
-        let mut data_path = dirs::data_dir()
-            .unwrap_or_else(|| panic!("unable to find data directory for journey data"));
+    /// Creates the repository for the journey in the data directory,
+    /// acquiring the lock if no other instance holds it.
+    pub fn new() -> DiskJourneyRepo {
+        DiskJourneyRepo::with_data_path(DiskJourneyRepo::get_data_path())
+    }
+
+    /// Creates the repository for the journey at the given path,
+    /// acquiring the lock if no other instance holds it.
+    pub(crate) fn with_data_path(data_path: Option<PathBuf>) -> DiskJourneyRepo {
+        let lock = match &data_path {
+            Some(data_path) => DiskJourneyRepo::acquire_lock(data_path),
+            None => Err(RepositoryError::NoDataDirectory),
+        };
+
+        DiskJourneyRepo { data_path, lock }
+    }
+
+    /// Gets the relevant full path to the file containing the journey data,
+    /// if there is a data directory.
+    fn get_data_path() -> Option<PathBuf> {
+        let mut data_path = dirs::data_dir()?;
 
         data_path.push("rslibrecell");
         data_path.push("journey.bin");
-        return data_path;
+        Some(data_path)
     }
 
-    /// Helper function to read.
-    fn read_from_file(path: &std::path::Path) -> Vec<u8> {
-        match std::fs::read(path) {
-            Ok(bytes) => bytes,
-            Err(err) => panic!("on read, std::fs error '{}'", err.kind().to_string()),
+    /// Acquires the lock on the file `journey.lock` next to the journey data.
+    ///
+    /// The lock is released by the operating system when the file is closed,
+    /// even if the instance crashes.
+    fn acquire_lock(data_path: &Path) -> Result<File, RepositoryError> {
+        let lock_path = data_path.with_extension("lock");
+
+        let mut data_dir = data_path.to_path_buf();
+        data_dir.pop();
+
+        let file = std::fs::create_dir_all(&data_dir)
+            .and_then(|_| {
+                OpenOptions::new()
+                    .create(true)
+                    .truncate(false)
+                    .write(true)
+                    .open(&lock_path)
+            })
+            .map_err(|err| RepositoryError::Write {
+                path: lock_path.clone(),
+                kind: err.kind(),
+            })?;
+
+        match file.try_lock() {
+            Ok(()) => Ok(file),
+            Err(TryLockError::WouldBlock) => Err(RepositoryError::Locked { path: lock_path }),
+            Err(TryLockError::Error(err)) => Err(RepositoryError::Write {
+                path: lock_path,
+                kind: err.kind(),
+            }),
+        }
+    }
+
+    /// Reads the journey from the file at the path.
+    ///
+    /// If the file is missing or malformed, *e.g.* truncated by a crash,
+    /// the backup kept by `write_journey` is used instead, if possible.
+    pub(crate) fn read_journey(data_path: &Path) -> Result<(GameId, Vec<GameId>), RepositoryError> {
+        let backup_path = DiskJourneyRepo::with_suffix(data_path, ".bak");
+
+        match DiskJourneyRepo::read_journey_file(data_path) {
+            Ok(Some(data)) => Ok(data),
+            Ok(None) => Ok(
+                DiskJourneyRepo::read_journey_file(&backup_path)?.unwrap_or((GameId(1), vec![]))
+            ),
+            Err(RepositoryError::Malformed { path }) => {
+                match DiskJourneyRepo::read_journey_file(&backup_path) {
+                    Ok(Some(data)) => Ok(data),
+                    _ => Err(RepositoryError::Malformed { path }),
+                }
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Writes the journey to the file at the path, such that a crash
+    /// leaves either the previous or the new data in place.
+    ///
+    /// Valid previous data is kept as a backup next to the file.
+    pub(crate) fn write_journey(
+        data_path: &Path,
+        next: GameId,
+        skipped: Vec<GameId>,
+    ) -> Result<(), RepositoryError> {
+        let mut data_dir = data_path.to_path_buf();
+        data_dir.pop();
+
+        if let Err(err) = std::fs::create_dir_all(&data_dir) {
+            return Err(RepositoryError::Write {
+                path: data_dir,
+                kind: err.kind(),
+            });
+        }
+
+        // a malformed file never replaces the backup
+        if let Ok(previous) = std::fs::read(data_path) {
+            if DiskJourneyRepo::deserialize(&previous).is_some() {
+                let backup_path = DiskJourneyRepo::with_suffix(data_path, ".bak");
+                DiskJourneyRepo::write_atomically(&backup_path, &previous)?;
+            }
+        }
+
+        let bytes = DiskJourneyRepo::serialize(next, skipped);
+        DiskJourneyRepo::write_atomically(data_path, &bytes)?;
+
+        // persisting the renames is best effort, not all platforms support it
+        if let Ok(dir) = File::open(&data_dir) {
+            let _ = dir.sync_all();
         }
+
+        Ok(())
     }
 
-    /// Helper function to write.
-    fn write_to_file(path: &std::path::Path, bytes: &Vec<u8>) -> () {
-        match std::fs::write(path, bytes) {
+    /// Reads the journey from a single file, yielding `None` if it does not exist.
+    fn read_journey_file(path: &Path) -> Result<Option<(GameId, Vec<GameId>)>, RepositoryError> {
+        let bytes = match std::fs::read(path) {
             Ok(bytes) => bytes,
-            Err(err) => panic!("on write, std::fs error '{}'", err.kind().to_string()),
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => {
+                return Err(RepositoryError::Read {
+                    path: path.to_path_buf(),
+                    kind: err.kind(),
+                })
+            }
+        };
+
+        match DiskJourneyRepo::deserialize(&bytes) {
+            Some(data) => Ok(Some(data)),
+            None => match DiskJourneyRepo::version(&bytes) {
+                Some(version) if version > VERSION => Err(RepositoryError::UnsupportedVersion {
+                    path: path.to_path_buf(),
+                    version,
+                }),
+                _ => Err(RepositoryError::Malformed {
+                    path: path.to_path_buf(),
+                }),
+            },
+        }
+    }
+
+    /// Writes the bytes to a temporary file, flushes it to the disk
+    /// and renames it to the path, replacing any existing file.
+    pub(crate) fn write_atomically(path: &Path, bytes: &[u8]) -> Result<(), RepositoryError> {
+        let temp_path = DiskJourneyRepo::with_suffix(path, ".tmp");
+
+        let result = File::create(&temp_path)
+            .and_then(|mut file| {
+                file.write_all(bytes)?;
+                file.sync_all()
+            })
+            .and_then(|_| std::fs::rename(&temp_path, path));
+
+        result.map_err(|err| {
+            let _ = std::fs::remove_file(&temp_path);
+            RepositoryError::Write {
+                path: path.to_path_buf(),
+                kind: err.kind(),
+            }
+        })
+    }
+
+    /// Helper function to append a suffix to the file name of a path.
+    pub(crate) fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+        let mut result = path.as_os_str().to_owned();
+        result.push(suffix);
+        PathBuf::from(result)
+    }
+
+    /// Gets the version of the journey data, if it is in a versioned format.
+    pub(crate) fn version(bytes: &[u8]) -> Option<u16> {
+        if bytes.len() < 6 || bytes[0..4] != MAGIC {
+            return None;
+        }
+
+        Some(u16::from_be_bytes([bytes[4], bytes[5]]))
+    }
+
+    /// Deserializes journey data in the current or the legacy format,
+    /// yielding `None` if the data is malformed or of an unsupported version.
+    pub(crate) fn deserialize(bytes: &[u8]) -> Option<(GameId, Vec<GameId>)> {
+        match DiskJourneyRepo::version(bytes) {
+            Some(VERSION) => DiskJourneyRepo::deserialize_version_1(bytes),
+            Some(_) => None,
+            None => DiskJourneyRepo::deserialize_legacy(bytes),
         }
     }
 
-    /// Deserializes an appropriate number of bytes into journey data.
-    pub(crate) fn deserialize(bytes: &Vec<u8>) -> (GameId, Vec<GameId>) {
+    /// Deserializes journey data of version 1, verifying its checksum.
+    fn deserialize_version_1(bytes: &[u8]) -> Option<(GameId, Vec<GameId>)> {
+        if bytes.len() < 22 {
+            return None;
+        }
+
+        let (content, checksum) = bytes.split_at(bytes.len() - 4);
+        if DiskJourneyRepo::checksum(content).to_be_bytes() != checksum {
+            return None;
+        }
+
+        let next_game = u64::from_be_bytes(content[6..14].try_into().ok()?);
+        let all_skipped_count = u32::from_be_bytes(content[14..18].try_into().ok()?) as usize;
+
+        if content.len() != 18 + 8 * all_skipped_count {
+            return None;
+        }
+
+        let all_skipped = content[18..]
+            .chunks_exact(8)
+            .map(|x| GameId(u64::from_be_bytes(x.try_into().unwrap())))
+            .collect();
+
+        Some((GameId(next_game), all_skipped))
+    }
+
+    /// Deserializes journey data in the legacy format,
+    /// *i.e.* an unversioned sequence of big-endian `u16`.
+    fn deserialize_legacy(bytes: &[u8]) -> Option<(GameId, Vec<GameId>)> {
         if bytes.len() < 4 {
-            panic!("too little data (<4)")
+            return None;
         }
 
-        let next_game = (bytes[0] as u16) * 256 + (bytes[1] as u16);
+        let next_game = (bytes[0] as u64) * 256 + (bytes[1] as u64);
 
         let all_skipped_count = (bytes[2] as u16) * 256 + (bytes[3] as u16);
 
-        if bytes.len() < (4 + 2 * all_skipped_count) as usize {
-            panic!("too little data (mismatch in skipped)")
+        if bytes.len() < 4 + 2 * all_skipped_count as usize {
+            return None;
         }
 
         let mut all_skipped: Vec<GameId> = vec![];
 
         for i in 0..(all_skipped_count as usize) {
-            let skipped_game = (bytes[4 + 2 * i] as u16) * 256 + (bytes[5 + 2 * i] as u16);
+            let skipped_game = (bytes[4 + 2 * i] as u64) * 256 + (bytes[5 + 2 * i] as u64);
             all_skipped.push(GameId(skipped_game));
         }
 
-        return (GameId(next_game), all_skipped);
+        return Some((GameId(next_game), all_skipped));
     }
 
-    /// Serializes journey data into bytes.
+    /// Serializes journey data into bytes of the current version.
+    ///
+    /// The format consists of the magic bytes `RSLJ`, the version as `u16`,
+    /// the next game as `u64`, the number of skipped games as `u32`,
+    /// the skipped games as `u64` and the CRC-32 of all preceding bytes as `u32`,
+    /// all numbers being big-endian.
     pub(crate) fn serialize(next: GameId, skipped: Vec<GameId>) -> Vec<u8> {
         let mut result: Vec<u8> = vec![];
 
-        let mut push = |i: u16| {
-            let first = (i / 256) as u8;
-            let second = (i % 256) as u8;
-            result.push(first);
-            result.push(second);
-        };
-
-        push(next.0);
-        push(skipped.len() as u16);
+        result.extend_from_slice(&MAGIC);
+        result.extend_from_slice(&VERSION.to_be_bytes());
+        result.extend_from_slice(&next.0.to_be_bytes());
+        result.extend_from_slice(&(skipped.len() as u32).to_be_bytes());
 
         for skipped in skipped {
-            push(skipped.0);
+            result.extend_from_slice(&skipped.0.to_be_bytes());
         }
 
+        let checksum = DiskJourneyRepo::checksum(&result);
+        result.extend_from_slice(&checksum.to_be_bytes());
+
         return result;
     }
+
+    /// Calculates the CRC-32 (IEEE) of the bytes.
+    pub(crate) fn checksum(bytes: &[u8]) -> u32 {
+        let mut crc = 0xffff_ffffu32;
+
+        for byte in bytes {
+            crc ^= *byte as u32;
+            for _ in 0..8 {
+                let mask = (crc & 1).wrapping_neg();
+                crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+            }
+        }
+
+        !crc
+    }
 }
 
 #[cfg(test)]
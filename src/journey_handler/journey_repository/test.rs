@@ -17,59 +17,361 @@
 */
 
 use super::*;
+use crate::game::MAX_GAME_ID;
+
+mod helper {
+    use super::*;
+
+    /// Provides the path of the journey file in a fresh temporary directory.
+    pub fn data_path(name: &str) -> PathBuf {
+        let data_dir = std::env::temp_dir().join(format!("rslibrecell_journey_test_{}", name));
+        let _ = std::fs::remove_dir_all(&data_dir);
+        data_dir.join("journey.bin")
+    }
+
+    pub fn backup_path(data_path: &Path) -> PathBuf {
+        DiskJourneyRepo::with_suffix(data_path, ".bak")
+    }
+
+    pub fn clean_up(data_path: &Path) {
+        let mut data_dir = data_path.to_path_buf();
+        data_dir.pop();
+        std::fs::remove_dir_all(data_dir).unwrap();
+    }
+}
+
+const VERSION_1_WITH_SKIPPED: [u8; 38] = [
+    0x52, 0x53, 0x4c, 0x4a, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00,
+    0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0x03, 0x20, 0x75, 0x4e, 0xc2,
+];
+
+const VERSION_1_WITHOUT_SKIPPED: [u8; 22] = [
+    0x52, 0x53, 0x4c, 0x4a, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00,
+    0x00, 0x00, 0x4b, 0xd2, 0x6b, 0x55,
+];
+
+#[test]
+fn deserialize_version_1_with_skipped() {
+    let actual = DiskJourneyRepo::deserialize(&VERSION_1_WITH_SKIPPED);
+
+    let expected = Some((GameId(17), vec![GameId(11), GameId(515)]));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn deserialize_version_1_without_skipped() {
+    let actual = DiskJourneyRepo::deserialize(&VERSION_1_WITHOUT_SKIPPED);
+
+    let expected = Some((GameId(17), vec![]));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn deserialize_version_1_with_bad_checksum_yields_none() {
+    let mut input = VERSION_1_WITH_SKIPPED;
+    input[25] = 0x0c;
+
+    assert_eq!(None, DiskJourneyRepo::deserialize(&input));
+}
+
+#[test]
+fn deserialize_version_1_truncated_yields_none() {
+    assert_eq!(
+        None,
+        DiskJourneyRepo::deserialize(&VERSION_1_WITH_SKIPPED[..30])
+    );
+    assert_eq!(
+        None,
+        DiskJourneyRepo::deserialize(&VERSION_1_WITH_SKIPPED[..6])
+    );
+}
+
+#[test]
+fn deserialize_version_1_with_count_mismatch_yields_none() {
+    let mut input = VERSION_1_WITHOUT_SKIPPED[..18].to_vec();
+    input[17] = 0x01;
+    let checksum = DiskJourneyRepo::checksum(&input);
+    input.extend_from_slice(&checksum.to_be_bytes());
+
+    assert_eq!(None, DiskJourneyRepo::deserialize(&input));
+}
 
 #[test]
-fn deserialize_with_skipped() {
+fn deserialize_newer_version_yields_none() {
+    let mut input = VERSION_1_WITHOUT_SKIPPED[..18].to_vec();
+    input[5] = 0x02;
+    let checksum = DiskJourneyRepo::checksum(&input);
+    input.extend_from_slice(&checksum.to_be_bytes());
+
+    assert_eq!(None, DiskJourneyRepo::deserialize(&input));
+    assert_eq!(Some(2), DiskJourneyRepo::version(&input));
+}
+
+#[test]
+fn version_works() {
+    assert_eq!(Some(1), DiskJourneyRepo::version(&VERSION_1_WITH_SKIPPED));
+    assert_eq!(None, DiskJourneyRepo::version(&[0x00, 0x11, 0x00, 0x00]));
+    assert_eq!(None, DiskJourneyRepo::version(&VERSION_1_WITH_SKIPPED[..5]));
+}
+
+#[test]
+fn deserialize_legacy_with_skipped() {
     let input: Vec<u8> = vec![0x00, 0x11, 0x00, 0x02, 0x00, 0x0b, 0x02, 0x03];
 
     let actual = DiskJourneyRepo::deserialize(&input);
 
-    let expected = (GameId(17), vec![GameId(11), GameId(515)]);
+    let expected = Some((GameId(17), vec![GameId(11), GameId(515)]));
 
     assert_eq!(actual, expected);
 }
 
 #[test]
-fn deserialize_without_skipped() {
+fn deserialize_legacy_without_skipped() {
     let input: Vec<u8> = vec![0x00, 0x11, 0x00, 0x00];
 
     let actual = DiskJourneyRepo::deserialize(&input);
 
-    let expected = (GameId(17), vec![]);
+    let expected = Some((GameId(17), vec![]));
 
     assert_eq!(actual, expected);
 }
 
 #[test]
-#[should_panic]
-fn deserialize_too_little_data_sub4() {
+fn deserialize_legacy_too_little_data_sub4_yields_none() {
     let input: Vec<u8> = vec![0x00, 0x11, 0x00];
 
-    _ = DiskJourneyRepo::deserialize(&input);
+    assert_eq!(None, DiskJourneyRepo::deserialize(&input));
 }
 
 #[test]
-#[should_panic]
-fn deserialize_too_little_data_mismatch() {
+fn deserialize_legacy_too_little_data_mismatch_yields_none() {
     let input: Vec<u8> = vec![0x00, 0x11, 0x00, 0x02, 0x00, 0x0b];
 
-    _ = DiskJourneyRepo::deserialize(&input);
+    assert_eq!(None, DiskJourneyRepo::deserialize(&input));
 }
 
 #[test]
 fn serialize_with_skipped() {
     let actual = DiskJourneyRepo::serialize(GameId(17), vec![GameId(11), GameId(515)]);
 
-    let expected: Vec<u8> = vec![0x00, 0x11, 0x00, 0x02, 0x00, 0x0b, 0x02, 0x03];
-
-    assert_eq!(actual, expected);
+    assert_eq!(actual, VERSION_1_WITH_SKIPPED);
 }
 
 #[test]
 fn serialize_without_skipped() {
     let actual = DiskJourneyRepo::serialize(GameId(17), vec![]);
 
-    let expected: Vec<u8> = vec![0x00, 0x11, 0x00, 0x00];
+    assert_eq!(actual, VERSION_1_WITHOUT_SKIPPED);
+}
 
-    assert_eq!(actual, expected);
+#[test]
+fn serialize_beyond_legacy_range_round_trips() {
+    let skipped = vec![GameId(64001), GameId(MAX_GAME_ID)];
+
+    let bytes = DiskJourneyRepo::serialize(GameId(MAX_GAME_ID), skipped.clone());
+
+    assert_eq!(
+        Some((GameId(MAX_GAME_ID), skipped)),
+        DiskJourneyRepo::deserialize(&bytes)
+    );
+}
+
+#[test]
+fn checksum_works() {
+    assert_eq!(0xcbf43926, DiskJourneyRepo::checksum(b"123456789"));
+    assert_eq!(0, DiskJourneyRepo::checksum(&[]));
+}
+
+#[test]
+fn read_journey_without_files_starts_new_journey() {
+    let data_path = helper::data_path("without_files");
+
+    let actual = DiskJourneyRepo::read_journey(&data_path);
+
+    assert_eq!(Ok((GameId(1), vec![])), actual);
+}
+
+#[test]
+fn write_journey_then_read_journey_round_trips() {
+    let data_path = helper::data_path("round_trip");
+
+    DiskJourneyRepo::write_journey(&data_path, GameId(17), vec![GameId(11)]).unwrap();
+
+    let actual = DiskJourneyRepo::read_journey(&data_path);
+
+    assert_eq!(Ok((GameId(17), vec![GameId(11)])), actual);
+    assert!(!helper::backup_path(&data_path).exists());
+    assert!(!DiskJourneyRepo::with_suffix(&data_path, ".tmp").exists());
+
+    helper::clean_up(&data_path);
+}
+
+#[test]
+fn write_journey_keeps_previous_data_as_backup() {
+    let data_path = helper::data_path("backup");
+
+    DiskJourneyRepo::write_journey(&data_path, GameId(17), vec![]).unwrap();
+    DiskJourneyRepo::write_journey(&data_path, GameId(18), vec![]).unwrap();
+
+    let backup = std::fs::read(helper::backup_path(&data_path)).unwrap();
+
+    assert_eq!(
+        Some((GameId(17), vec![])),
+        DiskJourneyRepo::deserialize(&backup)
+    );
+
+    helper::clean_up(&data_path);
+}
+
+#[test]
+fn read_journey_with_truncated_file_falls_back_to_backup() {
+    let data_path = helper::data_path("truncated");
+
+    DiskJourneyRepo::write_journey(&data_path, GameId(17), vec![GameId(11)]).unwrap();
+    DiskJourneyRepo::write_journey(&data_path, GameId(18), vec![GameId(11)]).unwrap();
+
+    let bytes = std::fs::read(&data_path).unwrap();
+    std::fs::write(&data_path, &bytes[..10]).unwrap();
+
+    let actual = DiskJourneyRepo::read_journey(&data_path);
+
+    assert_eq!(Ok((GameId(17), vec![GameId(11)])), actual);
+
+    helper::clean_up(&data_path);
+}
+
+#[test]
+fn read_journey_with_missing_file_falls_back_to_backup() {
+    let data_path = helper::data_path("missing");
+
+    DiskJourneyRepo::write_journey(&data_path, GameId(17), vec![]).unwrap();
+    DiskJourneyRepo::write_journey(&data_path, GameId(18), vec![]).unwrap();
+    std::fs::remove_file(&data_path).unwrap();
+
+    let actual = DiskJourneyRepo::read_journey(&data_path);
+
+    assert_eq!(Ok((GameId(17), vec![])), actual);
+
+    helper::clean_up(&data_path);
+}
+
+#[test]
+fn read_journey_with_malformed_file_and_no_backup_errors() {
+    let data_path = helper::data_path("malformed");
+
+    DiskJourneyRepo::write_journey(&data_path, GameId(17), vec![]).unwrap();
+    std::fs::write(&data_path, [0x00, 0x11, 0x00]).unwrap();
+
+    let actual = DiskJourneyRepo::read_journey(&data_path);
+
+    assert_eq!(
+        Err(RepositoryError::Malformed {
+            path: data_path.clone()
+        }),
+        actual
+    );
+
+    helper::clean_up(&data_path);
+}
+
+#[test]
+fn read_journey_with_newer_version_does_not_fall_back() {
+    let data_path = helper::data_path("newer_version");
+
+    DiskJourneyRepo::write_journey(&data_path, GameId(17), vec![]).unwrap();
+    DiskJourneyRepo::write_journey(&data_path, GameId(18), vec![]).unwrap();
+
+    let mut bytes = VERSION_1_WITHOUT_SKIPPED[..18].to_vec();
+    bytes[5] = 0x02;
+    let checksum = DiskJourneyRepo::checksum(&bytes);
+    bytes.extend_from_slice(&checksum.to_be_bytes());
+    std::fs::write(&data_path, bytes).unwrap();
+
+    let actual = DiskJourneyRepo::read_journey(&data_path);
+
+    assert_eq!(
+        Err(RepositoryError::UnsupportedVersion {
+            path: data_path.clone(),
+            version: 2
+        }),
+        actual
+    );
+
+    helper::clean_up(&data_path);
+}
+
+#[test]
+fn write_journey_with_malformed_file_keeps_backup() {
+    let data_path = helper::data_path("keeps_backup");
+
+    DiskJourneyRepo::write_journey(&data_path, GameId(17), vec![]).unwrap();
+    DiskJourneyRepo::write_journey(&data_path, GameId(18), vec![]).unwrap();
+    std::fs::write(&data_path, [0x00]).unwrap();
+
+    DiskJourneyRepo::write_journey(&data_path, GameId(19), vec![]).unwrap();
+
+    let backup = std::fs::read(helper::backup_path(&data_path)).unwrap();
+
+    assert_eq!(
+        Some((GameId(17), vec![])),
+        DiskJourneyRepo::deserialize(&backup)
+    );
+    assert_eq!(
+        Ok((GameId(19), vec![])),
+        DiskJourneyRepo::read_journey(&data_path)
+    );
+
+    helper::clean_up(&data_path);
+}
+
+#[test]
+fn second_instance_reads_but_does_not_write() {
+    let data_path = helper::data_path("second_instance");
+    let lock_path = data_path.with_extension("lock");
+
+    let first = DiskJourneyRepo::with_data_path(Some(data_path.clone()));
+    let second = DiskJourneyRepo::with_data_path(Some(data_path.clone()));
+
+    assert_eq!(Ok(()), first.check_lock());
+    assert_eq!(
+        Err(RepositoryError::Locked {
+            path: lock_path.clone()
+        }),
+        second.check_lock()
+    );
+
+    first.write(GameId(17), vec![GameId(11)]).unwrap();
+
+    assert_eq!(Ok((GameId(17), vec![GameId(11)])), second.read());
+    assert_eq!(
+        Err(RepositoryError::Locked {
+            path: lock_path.clone()
+        }),
+        second.write(GameId(18), vec![])
+    );
+    assert_eq!(Ok((GameId(17), vec![GameId(11)])), first.read());
+
+    drop(first);
+    drop(second);
+
+    let third = DiskJourneyRepo::with_data_path(Some(data_path.clone()));
+
+    assert_eq!(Ok(()), third.write(GameId(18), vec![]));
+
+    drop(third);
+    helper::clean_up(&data_path);
+}
+
+#[test]
+fn without_data_directory_reads_new_journey_and_does_not_write() {
+    let repository = DiskJourneyRepo::with_data_path(None);
+
+    assert_eq!(Ok((GameId(1), vec![])), repository.read());
+    assert_eq!(
+        Err(RepositoryError::NoDataDirectory),
+        repository.write(GameId(2), vec![])
+    );
 }
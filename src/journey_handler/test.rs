@@ -25,11 +25,12 @@ use super::*;
 #[test]
 fn new_reads_from_repository() {
     let mut repository = MockPersistJourney::new();
+    repository.expect_check_lock().return_const(Ok(()));
     repository
         .expect_read()
         .once()
-        .return_const((GameId(123), vec![GameId(117), GameId(118)]));
-    repository.expect_write().never().return_const(());
+        .return_const(Ok((GameId(123), vec![GameId(117), GameId(118)])));
+    repository.expect_write().never().return_const(Ok(()));
 
     let instance = JourneyHandler::new(repository);
 
@@ -44,11 +45,12 @@ fn new_reads_from_repository() {
 #[test]
 fn receive_notification_game_won_with_unrelated_works() {
     let mut repository = MockPersistJourney::new();
+    repository.expect_check_lock().return_const(Ok(()));
     repository
         .expect_read()
         .once()
-        .return_const((GameId(123), vec![GameId(117), GameId(118)]));
-    repository.expect_write().never().return_const(());
+        .return_const(Ok((GameId(123), vec![GameId(117), GameId(118)])));
+    repository.expect_write().never().return_const(Ok(()));
 
     let mut instance = JourneyHandler::new(repository);
 
@@ -63,10 +65,11 @@ fn receive_notification_game_won_with_unrelated_works() {
 #[test]
 fn receive_notification_game_won_with_next_works() {
     let mut repository = MockPersistJourney::new();
+    repository.expect_check_lock().return_const(Ok(()));
     repository
         .expect_read()
         .once()
-        .return_const((GameId(123), vec![GameId(117), GameId(118)]));
+        .return_const(Ok((GameId(123), vec![GameId(117), GameId(118)])));
     repository
         .expect_write()
         .once()
@@ -74,7 +77,7 @@ fn receive_notification_game_won_with_next_works() {
             predicate::eq(GameId(124)),
             predicate::eq(vec![GameId(117), GameId(118)]),
         )
-        .return_const(());
+        .return_const(Ok(()));
 
     let mut instance = JourneyHandler::new(repository);
 
@@ -89,15 +92,16 @@ fn receive_notification_game_won_with_next_works() {
 #[test]
 fn receive_notification_game_won_with_a_skipped_works() {
     let mut repository = MockPersistJourney::new();
+    repository.expect_check_lock().return_const(Ok(()));
     repository
         .expect_read()
         .once()
-        .return_const((GameId(123), vec![GameId(117), GameId(118)]));
+        .return_const(Ok((GameId(123), vec![GameId(117), GameId(118)])));
     repository
         .expect_write()
         .once()
         .with(predicate::eq(GameId(123)), predicate::eq(vec![GameId(118)]))
-        .return_const(());
+        .return_const(Ok(()));
 
     let mut instance = JourneyHandler::new(repository);
 
@@ -109,10 +113,11 @@ fn receive_notification_game_won_with_a_skipped_works() {
 #[test]
 fn skip_next_game_works() {
     let mut repository = MockPersistJourney::new();
+    repository.expect_check_lock().return_const(Ok(()));
     repository
         .expect_read()
         .once()
-        .return_const((GameId(123), vec![GameId(117), GameId(118)]));
+        .return_const(Ok((GameId(123), vec![GameId(117), GameId(118)])));
     repository
         .expect_write()
         .once()
@@ -120,7 +125,7 @@ fn skip_next_game_works() {
             predicate::eq(GameId(124)),
             predicate::eq(vec![GameId(117), GameId(118), GameId(123)]),
         )
-        .return_const(());
+        .return_const(Ok(()));
 
     let mut instance = JourneyHandler::new(repository);
 
@@ -131,3 +136,115 @@ fn skip_next_game_works() {
         instance.next_game_ids()
     );
 }
+
+#[test]
+fn new_with_failing_read_starts_new_journey_without_writing() {
+    let error = RepositoryError::Malformed {
+        path: std::path::PathBuf::from("journey.bin"),
+    };
+
+    let mut repository = MockPersistJourney::new();
+    repository.expect_check_lock().return_const(Ok(()));
+    repository
+        .expect_read()
+        .once()
+        .return_const(Err(error.clone()));
+    repository.expect_write().never().return_const(Ok(()));
+
+    let mut instance = JourneyHandler::new(repository);
+
+    assert_eq!((GameId(1), vec![]), instance.next_game_ids());
+    assert_eq!(Some(error), instance.take_error());
+    assert_eq!(None, instance.take_error());
+
+    instance.skip_next_game();
+    instance.receive_notification_game_won(GameId(2));
+
+    assert_eq!((GameId(3), vec![GameId(1)]), instance.next_game_ids());
+    assert_eq!(None, instance.take_error());
+}
+
+#[test]
+fn failing_write_keeps_error() {
+    let mut repository = MockPersistJourney::new();
+    repository.expect_check_lock().return_const(Ok(()));
+    repository
+        .expect_read()
+        .once()
+        .return_const(Ok((GameId(123), vec![])));
+    repository
+        .expect_write()
+        .once()
+        .return_const(Err(RepositoryError::NoDataDirectory));
+
+    let mut instance = JourneyHandler::new(repository);
+
+    assert_eq!(None, instance.take_error());
+
+    instance.skip_next_game();
+
+    assert_eq!((GameId(124), vec![GameId(123)]), instance.next_game_ids());
+    assert_eq!(
+        Some(RepositoryError::NoDataDirectory),
+        instance.take_error()
+    );
+    assert_eq!(None, instance.take_error());
+}
+
+#[test]
+fn locked_journey_is_reported_on_construction() {
+    let error = RepositoryError::Locked {
+        path: std::path::PathBuf::from("journey.lock"),
+    };
+
+    let mut repository = MockPersistJourney::new();
+    repository
+        .expect_check_lock()
+        .return_const(Err(error.clone()));
+    repository
+        .expect_read()
+        .once()
+        .return_const(Ok((GameId(123), vec![GameId(7)])));
+    repository.expect_write().never();
+
+    let mut instance = JourneyHandler::new(repository);
+
+    assert_eq!(Some(error), instance.take_error());
+    assert_eq!((GameId(123), vec![GameId(7)]), instance.next_game_ids());
+
+    instance.skip_next_game();
+
+    assert_eq!(None, instance.take_error());
+}
+
+#[test]
+fn locked_write_stops_writing() {
+    let error = RepositoryError::Locked {
+        path: std::path::PathBuf::from("journey.lock"),
+    };
+
+    let mut repository = MockPersistJourney::new();
+    repository.expect_check_lock().return_const(Ok(()));
+    repository
+        .expect_read()
+        .once()
+        .return_const(Ok((GameId(123), vec![])));
+    repository
+        .expect_write()
+        .once()
+        .return_const(Err(error.clone()));
+
+    let mut instance = JourneyHandler::new(repository);
+
+    instance.skip_next_game();
+
+    assert_eq!(Some(error), instance.take_error());
+
+    instance.skip_next_game();
+
+    assert_eq!(
+        (GameId(125), vec![GameId(123), GameId(124)]),
+        instance.next_game_ids()
+    );
+    assert_eq!(None, instance.take_error());
+}
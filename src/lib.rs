@@ -40,6 +40,18 @@ pub mod journey_handler;
 #[warn(missing_docs)]
 pub mod r#move;
 
+/// Contains the error type shared by the repositories.
+#[warn(missing_docs)]
+pub mod repository_error;
+
+/// Contains a solver for games.
+#[warn(missing_docs)]
+pub mod solver;
+
+/// Contains logic to record the results of games.
+#[warn(missing_docs)]
+pub mod stats_handler;
+
 /// Omnibus collection of modules.
 #[warn(missing_docs)]
 pub mod lib {}
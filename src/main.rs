@@ -21,17 +21,74 @@
 //! The project supports automatically moving cards to the foundations (the target area),
 //! and supermoves (moving several cards at once if there are enough free cells).
 
-use std::io;
+use std::{env, io, panic, process};
 
-use rslibrecell::{config_repository, journey_handler::journey_repository::DiskJourneyRepo};
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+};
+use rslibrecell::{
+    config_repository::DiskKeyConfigRepo,
+    game_handler::game_repository::DiskGameRepo,
+    journey_handler::journey_repository::{DiskJourneyRepo, PersistJourney},
+    stats_handler::stats_repository::DiskStatsRepo,
+};
 
 mod app;
+mod cli;
 
 fn main() -> io::Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match cli::parse(&args) {
+        Ok(None) => {}
+        Ok(Some(command)) => {
+            if let Err(message) = cli::execute(&command, &mut io::stdout()) {
+                eprintln!("{}", message);
+                process::exit(1);
+            }
+            return Ok(());
+        }
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, cli::USAGE);
+            process::exit(2);
+        }
+    }
+
     let mut terminal = ratatui::init();
-    let config = config_repository::get_config();
-    let journey_repository = DiskJourneyRepo {};
-    let app_result = app::App::new(config, journey_repository).run(&mut terminal);
+    // the mouse is optional, the keyboard always works
+    _ = execute!(io::stdout(), EnableMouseCapture);
+    let mouse_capture = MouseCapture;
+
+    // the mouse capture is undone before ratatui restores the terminal after a panic
+    let ratatui_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        _ = execute!(io::stdout(), DisableMouseCapture);
+        ratatui_hook(info);
+    }));
+
+    let key_config_repository = DiskKeyConfigRepo {};
+    let journey_repository = DiskJourneyRepo::new();
+    let game_repository = DiskGameRepo {};
+    // the instance writing the journey also writes the results
+    let stats_repository = DiskStatsRepo::new(journey_repository.check_lock().is_err());
+    let app_result = app::App::new(
+        key_config_repository,
+        journey_repository,
+        game_repository,
+        stats_repository,
+    )
+    .run(&mut terminal);
+    drop(mouse_capture);
     ratatui::restore();
     app_result
 }
+
+/// Undoes the mouse capture when dropped, even when unwinding.
+struct MouseCapture;
+
+impl Drop for MouseCapture {
+    fn drop(&mut self) {
+        _ = execute!(io::stdout(), DisableMouseCapture);
+    }
+}
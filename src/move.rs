@@ -16,7 +16,7 @@
     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 */
 
-use std::cmp::min;
+use std::{cmp::min, convert::TryFrom, fmt};
 
 use crate::{
     card::{Card, Rank, Suit},
@@ -24,7 +24,7 @@ use crate::{
 };
 
 /// Defines the `to` and `from` locations of a move.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Location {
     /// The location is a cell with some 0-based index.
     Cell {
@@ -41,7 +41,7 @@ pub enum Location {
 }
 
 /// Defines a move for a game.
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Move {
     /// The initial location of the card(s).
     pub from: Location,
@@ -49,37 +49,295 @@ pub struct Move {
     pub to: Location,
 }
 
+/// Defines the reasons for a move to be rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoveError {
+    /// There is no game to make the move in.
+    NoGame,
+    /// The game is already won.
+    GameWon,
+    /// There is no move to revert.
+    NothingToRevert,
+    /// There is no reverted move to redo.
+    NothingToRedo,
+    /// There is no branch with the given index.
+    UnknownBranch,
+    /// There is no checkpoint with the given index.
+    UnknownCheckpoint,
+    /// All checkpoints are in use.
+    TooManyCheckpoints,
+    /// The cell or column index is out of range.
+    InvalidLocation,
+    /// There is no card at the source location.
+    SourceEmpty,
+    /// The destination cell already holds a card.
+    DestinationOccupied,
+    /// The card would be placed on a card of the same color.
+    ColorMismatch,
+    /// The card does not follow the rank of the card it would be placed on.
+    RankMismatch,
+    /// The run to move is longer than the free cells and columns allow.
+    SupermoveTooLarge {
+        /// The maximum number of cards that can be moved.
+        max: usize,
+    },
+    /// Cards cannot be moved away from a foundation.
+    FromFoundation,
+    /// The source and destination are the same location.
+    SameLocation,
+}
+
+impl fmt::Display for MoveError {
+    /// Provides a short explanation, suitable for a status line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveError::NoGame => write!(f, "No game in progress"),
+            MoveError::GameWon => write!(f, "Game is already won"),
+            MoveError::NothingToRevert => write!(f, "Nothing to revert"),
+            MoveError::NothingToRedo => write!(f, "Nothing to redo"),
+            MoveError::UnknownBranch => write!(f, "No such branch"),
+            MoveError::UnknownCheckpoint => write!(f, "No such checkpoint"),
+            MoveError::TooManyCheckpoints => write!(f, "No more checkpoints"),
+            MoveError::InvalidLocation => write!(f, "No such location"),
+            MoveError::SourceEmpty => write!(f, "Source is empty"),
+            MoveError::DestinationOccupied => write!(f, "Cell is occupied"),
+            MoveError::ColorMismatch => write!(f, "Colors must alternate"),
+            MoveError::RankMismatch => write!(f, "Ranks do not match"),
+            MoveError::SupermoveTooLarge { max: 1 } => write!(f, "Can move at most 1 card"),
+            MoveError::SupermoveTooLarge { max } => write!(f, "Can move at most {max} cards"),
+            MoveError::FromFoundation => write!(f, "No moves from foundations"),
+            MoveError::SameLocation => write!(f, "Same source and target"),
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+impl Location {
+    /// Provides the standard notation of the location,
+    /// *i.e.* `1` to `8` for the columns, `a` to `d` for the cells
+    /// and `h` for the foundations.
+    ///
+    /// Returns `None` for a cell or column index out of range.
+    pub fn notation(&self) -> Option<char> {
+        match self {
+            Location::Cell { i } if *i < 4 => Some((b'a' + *i as u8) as char),
+            Location::Foundation => Some('h'),
+            Location::Column { i } if *i < 8 => Some((b'1' + *i as u8) as char),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    /// Provides the standard notation of the location, compare `notation`.
+    ///
+    /// A cell or column index out of range is written as is,
+    /// such as `cell 4` or `column 8`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.notation(), self) {
+            (Some(notation), _) => write!(f, "{notation}"),
+            (None, Location::Cell { i }) => write!(f, "cell {i}"),
+            (None, Location::Column { i }) => write!(f, "column {i}"),
+            (None, Location::Foundation) => write!(f, "h"),
+        }
+    }
+}
+
+impl TryFrom<char> for Location {
+    type Error = ();
+
+    /// Tries to create a location from its standard notation, compare `notation`.
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'a'..='d' => Ok(Location::Cell {
+                i: (value as u8 - b'a') as usize,
+            }),
+            'h' => Ok(Location::Foundation),
+            '1'..='8' => Ok(Location::Column {
+                i: (value as u8 - b'1') as usize,
+            }),
+            _ => Err(()),
+        }
+    }
+}
+
+impl TryFrom<&str> for Location {
+    type Error = ();
+
+    /// Tries to create a location from its standard notation, compare `notation`.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut chars = value.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Location::try_from(c),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for Move {
+    /// Provides the standard notation of the move, such as `3a` or `1h`,
+    /// consisting of the notation of the `from` and `to` locations.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.from, self.to)
+    }
+}
+
+impl TryFrom<&str> for Move {
+    type Error = ();
+
+    /// Tries to create a move from its standard notation, compare `fmt`.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut chars = value.chars();
+
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(from), Some(to), None) => Ok(Move {
+                from: Location::try_from(from)?,
+                to: Location::try_from(to)?,
+            }),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Defines a legal move together with the number of cards it moves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LegalMove {
+    /// The move itself.
+    pub mv: Move,
+    /// The number of cards moved.
+    pub count: usize,
+}
+
+/// Lists every legal move from the given position.
+///
+/// Moves to the foundations use `Location::Foundation`. Column-to-column moves
+/// are listed once for each number of cards that can be moved, which only
+/// differs from the largest number when moving to an empty column.
+pub fn legal_moves(game: &Game) -> Vec<LegalMove> {
+    let mut result: Vec<LegalMove> = vec![];
+
+    let cells = (0..4).map(|i| Location::Cell { i });
+    let columns = (0..8).map(|i| Location::Column { i });
+
+    let sources: Vec<Location> = cells.clone().chain(columns.clone()).collect();
+    let targets: Vec<Location> = cells
+        .chain(std::iter::once(Location::Foundation))
+        .chain(columns)
+        .collect();
+
+    for from in &sources {
+        for to in &targets {
+            if let (Location::Column { i: from }, Location::Column { i: to }) = (from, to) {
+                for count in column_column_counts(game, *from, *to).unwrap_or_default() {
+                    result.push(LegalMove {
+                        mv: Move {
+                            from: Location::Column { i: *from },
+                            to: Location::Column { i: *to },
+                        },
+                        count,
+                    });
+                }
+                continue;
+            }
+
+            let mv = Move {
+                from: from.clone(),
+                to: to.clone(),
+            };
+
+            if apply(game, mv.clone()).is_ok() {
+                result.push(LegalMove { mv, count: 1 });
+            }
+        }
+    }
+
+    result
+}
+
+/// Applies a legal move to the game, moving exactly the given number of cards,
+/// compare `legal_moves`.
+pub fn apply_legal_move(game: &Game, legal_move: &LegalMove) -> Result<Game, MoveError> {
+    match (&legal_move.mv.from, &legal_move.mv.to) {
+        (Location::Column { i: from }, Location::Column { i: to }) if *from < 8 && *to < 8 => {
+            let counts = column_column_counts(game, *from, *to)?;
+            let max = *counts.last().unwrap();
+
+            if counts.contains(&legal_move.count) {
+                Ok(move_column_column_count(game, *from, *to, legal_move.count))
+            } else if legal_move.count > max {
+                Err(MoveError::SupermoveTooLarge { max })
+            } else {
+                Err(MoveError::RankMismatch)
+            }
+        }
+        _ if legal_move.count == 1 => apply(game, legal_move.mv.clone()),
+        _ => Err(MoveError::SupermoveTooLarge { max: 1 }),
+    }
+}
+
+/// Gets the index of the foundation receiving the cards of the given suit.
+pub fn foundation_index_for(suit: Suit) -> usize {
+    detail::find_foundation_for(suit)
+}
+
+/// Makes a move on the game the way a player does,
+/// *i.e.* applies the move and then performs all possible automoves.
+pub fn make_move(game: &Game, mv: Move) -> Result<Game, MoveError> {
+    let mut result = apply(game, mv)?;
+
+    while let Some(automoved) = automove(&result) {
+        result = automoved;
+    }
+
+    Ok(result)
+}
+
 /// Applies a move to the game.
 ///
 /// # Panics
 /// The method will only panic in case of an internal bug.
-pub(crate) fn apply(game: &Game, mv: Move) -> Result<Game, ()> {
+pub(crate) fn apply(game: &Game, mv: Move) -> Result<Game, MoveError> {
     match mv.from {
         Location::Cell { i: from } => {
-            if from > 3 || game.cells[from].is_none() {
-                return Err(());
+            if from > 3 {
+                return Err(MoveError::InvalidLocation);
+            }
+            if game.cells[from].is_none() {
+                return Err(MoveError::SourceEmpty);
             }
         }
         Location::Foundation => {
-            return Err(());
+            return Err(MoveError::FromFoundation);
         }
         Location::Column { i: from } => {
-            if from > 7 || game.columns[from].is_empty() {
-                return Err(());
+            if from > 7 {
+                return Err(MoveError::InvalidLocation);
+            }
+            if game.columns[from].is_empty() {
+                return Err(MoveError::SourceEmpty);
             }
         }
     }
 
+    if mv.from == mv.to {
+        return Err(MoveError::SameLocation);
+    }
+
     match mv.to {
         Location::Cell { i: to } => {
-            if to > 3 || game.cells[to].is_some() {
-                return Err(());
+            if to > 3 {
+                return Err(MoveError::InvalidLocation);
+            }
+            if game.cells[to].is_some() {
+                return Err(MoveError::DestinationOccupied);
             }
         }
         Location::Foundation => {}
         Location::Column { i: to } => {
             if to > 7 {
-                return Err(());
+                return Err(MoveError::InvalidLocation);
             }
         }
     }
@@ -215,7 +473,7 @@ pub(crate) fn automove(game: &Game) -> Option<Game> {
 }
 
 // `from`, `to` are indices
-fn move_cell_cell(game: &Game, from: usize, to: usize) -> Result<Game, ()> {
+fn move_cell_cell(game: &Game, from: usize, to: usize) -> Result<Game, MoveError> {
     let mut game = game.clone();
 
     game.cells[to] = game.cells[from];
@@ -225,29 +483,24 @@ fn move_cell_cell(game: &Game, from: usize, to: usize) -> Result<Game, ()> {
 }
 
 // `from` is an index
-fn move_cell_foundation(game: &Game, from: usize) -> Result<Game, ()> {
+fn move_cell_foundation(game: &Game, from: usize) -> Result<Game, MoveError> {
     let card = game.cells[from].unwrap();
 
     let mut game = game.clone();
 
-    if let Ok(()) = detail::move_card_to_foundation(&mut game, card) {
-        game.cells[from] = None;
+    detail::move_card_to_foundation(&mut game, card)?;
+    game.cells[from] = None;
 
-        return Ok(game);
-    } else {
-        return Err(());
-    }
+    Ok(game)
 }
 
 // `from`, `to` are indices
-fn move_cell_column(game: &Game, from: usize, to: usize) -> Result<Game, ()> {
+fn move_cell_column(game: &Game, from: usize, to: usize) -> Result<Game, MoveError> {
     let lower = game.cells[from].unwrap();
 
     if !game.columns[to].is_empty() {
         let upper = game.columns[to].last().unwrap();
-        if !detail::fit_together(upper, &lower) {
-            return Err(());
-        }
+        detail::check_fit(upper, &lower)?;
     }
 
     let mut game = game.clone();
@@ -259,7 +512,7 @@ fn move_cell_column(game: &Game, from: usize, to: usize) -> Result<Game, ()> {
 }
 
 // `from`, `to` are indices
-fn move_column_cell(game: &Game, from: usize, to: usize) -> Result<Game, ()> {
+fn move_column_cell(game: &Game, from: usize, to: usize) -> Result<Game, MoveError> {
     let mut game = game.clone();
 
     let card = game.columns[from].pop().unwrap();
@@ -270,22 +523,54 @@ fn move_column_cell(game: &Game, from: usize, to: usize) -> Result<Game, ()> {
 }
 
 // `from` is an index
-fn move_column_foundation(game: &Game, from: usize) -> Result<Game, ()> {
+fn move_column_foundation(game: &Game, from: usize) -> Result<Game, MoveError> {
     let mut game = game.clone();
 
     let card = game.columns[from].pop().unwrap();
 
-    if let Ok(()) = detail::move_card_to_foundation(&mut game, card) {
-        return Ok(game);
-    } else {
-        return Err(());
-    }
+    detail::move_card_to_foundation(&mut game, card)?;
+
+    Ok(game)
 }
 
 // `from`, `to` are indices
-fn move_column_column(game: &Game, from: usize, to: usize) -> Result<Game, ()> {
+fn move_column_column(game: &Game, from: usize, to: usize) -> Result<Game, MoveError> {
+    let counts = column_column_counts(game, from, to)?;
+
+    Ok(move_column_column_count(
+        game,
+        from,
+        to,
+        *counts.last().unwrap(),
+    ))
+}
+
+// `from`, `to` are indices, `count` must be valid
+fn move_column_column_count(game: &Game, from: usize, to: usize, count: usize) -> Game {
+    let mut game = game.clone();
+
+    let mut stash: Vec<Card> = vec![];
+
+    // actually move cards
+    for _i in 0..count {
+        stash.push(game.columns[from].pop().unwrap())
+    }
+
+    for _i in 0..count {
+        game.columns[to].push(stash.pop().unwrap())
+    }
+
+    game
+}
+
+// `from`, `to` are indices, the result is non-empty and in ascending order
+fn column_column_counts(game: &Game, from: usize, to: usize) -> Result<Vec<usize>, MoveError> {
     if from == to {
-        return Err(());
+        return Err(MoveError::SameLocation);
+    }
+
+    if game.columns[from].is_empty() {
+        return Err(MoveError::SourceEmpty);
     }
 
     let mut empty_column_count: u16 = game.columns.iter().filter(|x| x.is_empty()).count() as u16;
@@ -318,41 +603,28 @@ fn move_column_column(game: &Game, from: usize, to: usize) -> Result<Game, ()> {
         }
     }
 
-    max_move_size = min(run, max_move_size);
-
-    // now cut down move such that it fits onto the `to` bottom card
-    let to_card = game.columns[to].last();
-    if to_card.is_some() {
-        while max_move_size > 0 {
-            let from_top_card =
-                game.columns[from][(from_count as usize) - (max_move_size as usize)];
-
-            if detail::fit_together(to_card.unwrap(), &from_top_card) {
-                break;
+    let max_move_size = max_move_size as usize;
+    let from_top_card = |count: usize| game.columns[from][from_count - count];
+
+    // any part of the run fits into an empty column,
+    // otherwise at most one part fits onto the `to` bottom card
+    match game.columns[to].last() {
+        None => Ok((1..=min(run, max_move_size)).collect()),
+        Some(to_card) => {
+            match (1..=run).find(|count| detail::fit_together(to_card, &from_top_card(*count))) {
+                Some(count) if count <= max_move_size => Ok(vec![count]),
+                Some(_) => Err(MoveError::SupermoveTooLarge { max: max_move_size }),
+                None if (1..=run).any(|count| {
+                    detail::check_fit(to_card, &from_top_card(count))
+                        == Err(MoveError::ColorMismatch)
+                }) =>
+                {
+                    Err(MoveError::ColorMismatch)
+                }
+                None => Err(MoveError::RankMismatch),
             }
-
-            max_move_size -= 1;
         }
     }
-
-    if max_move_size == 0 {
-        return Err(());
-    }
-
-    let mut game = game.clone();
-
-    let mut stash: Vec<Card> = vec![];
-
-    // actually move cards
-    for _i in 0..max_move_size {
-        stash.push(game.columns[from].pop().unwrap())
-    }
-
-    for _i in 0..max_move_size {
-        game.columns[to].push(stash.pop().unwrap())
-    }
-
-    return Ok(game);
 }
 
 mod detail {
@@ -361,7 +633,9 @@ mod detail {
         game::Game,
     };
 
-    pub(super) fn move_card_to_foundation(game: &mut Game, card: Card) -> Result<(), ()> {
+    use super::MoveError;
+
+    pub(super) fn move_card_to_foundation(game: &mut Game, card: Card) -> Result<(), MoveError> {
         let foundation = find_foundation_for(card.suit);
 
         let foundation = &mut game.foundations[foundation];
@@ -373,12 +647,12 @@ mod detail {
                 if card.rank == Rank::Ace {
                     foundation.push(card);
                 } else {
-                    return Err(());
+                    return Err(MoveError::RankMismatch);
                 }
             }
             Some(foundation_card) => {
                 if card.rank == Rank::Ace {
-                    return Err(());
+                    return Err(MoveError::RankMismatch);
                 } else {
                     let card_rank = card.rank as u8;
                     let foundation_card_rank = foundation_card.rank as u8;
@@ -386,7 +660,7 @@ mod detail {
                     if card_rank - 1 == foundation_card_rank {
                         foundation.push(card);
                     } else {
-                        return Err(());
+                        return Err(MoveError::RankMismatch);
                     }
                 }
             }
@@ -414,18 +688,21 @@ mod detail {
     /// * upper `6♣`, lower `7♥` yields `false`
     /// * upper `6♣`, lower `5♠` yields `false`
     pub(super) fn fit_together(upper: &Card, lower: &Card) -> bool {
-        if upper.rank == Rank::Ace {
-            return false;
+        check_fit(upper, lower).is_ok()
+    }
+
+    /// Checks whether the two cards will legally fit together, compare `fit_together`,
+    /// reporting a rank mismatch in preference to a color mismatch.
+    pub(super) fn check_fit(upper: &Card, lower: &Card) -> Result<(), MoveError> {
+        if upper.rank == Rank::Ace || upper.rank as u8 - 1 != lower.rank as u8 {
+            return Err(MoveError::RankMismatch);
         }
 
         if is_red_func(*lower) == is_red_func(*upper) {
-            return false;
+            return Err(MoveError::ColorMismatch);
         }
 
-        let over_rank = upper.rank as u8;
-        let under_rank = lower.rank as u8;
-
-        return over_rank - 1 == under_rank;
+        return Ok(());
 
         fn is_red_func(card: Card) -> bool {
             card.suit == Suit::Hearts || card.suit == Suit::Diamonds
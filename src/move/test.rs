@@ -30,12 +30,12 @@ mod helper {
         assert_eq!(expected, after);
     }
 
-    pub fn assert_move_fails(input: &str, mv: Move) {
+    pub fn assert_move_fails(input: &str, mv: Move, expected: MoveError) {
         let before = Game::try_from(input).unwrap();
 
         let error = apply(&before, mv);
 
-        assert!(error.is_err());
+        assert_eq!(error, Err(expected));
     }
 
     pub fn assert_automoves_succeed(input: &str, count: u8, reference: &str) {
@@ -57,6 +57,97 @@ mod helper {
     }
 }
 
+#[test]
+fn location_display_trait_works() {
+    assert_eq!("a", Location::Cell { i: 0 }.to_string());
+    assert_eq!("d", Location::Cell { i: 3 }.to_string());
+    assert_eq!("h", Location::Foundation.to_string());
+    assert_eq!("1", Location::Column { i: 0 }.to_string());
+    assert_eq!("8", Location::Column { i: 7 }.to_string());
+}
+
+#[test]
+fn location_display_trait_writes_index_out_of_range() {
+    assert_eq!("cell 4", Location::Cell { i: 4 }.to_string());
+    assert_eq!("column 8", Location::Column { i: 8 }.to_string());
+}
+
+#[test]
+fn location_notation_works() {
+    assert_eq!(Some('a'), Location::Cell { i: 0 }.notation());
+    assert_eq!(Some('h'), Location::Foundation.notation());
+    assert_eq!(Some('8'), Location::Column { i: 7 }.notation());
+    assert_eq!(None, Location::Cell { i: 4 }.notation());
+    assert_eq!(None, Location::Column { i: 8 }.notation());
+}
+
+#[test]
+fn location_tryfrom_ref_str_works() {
+    assert_eq!(Ok(Location::Cell { i: 1 }), Location::try_from("b"));
+    assert_eq!(Ok(Location::Foundation), Location::try_from("h"));
+    assert_eq!(Ok(Location::Column { i: 5 }), Location::try_from("6"));
+}
+
+#[test]
+fn location_tryfrom_ref_str_with_bad_input_errors() {
+    assert!(Location::try_from("").is_err());
+    assert!(Location::try_from("e").is_err());
+    assert!(Location::try_from("0").is_err());
+    assert!(Location::try_from("9").is_err());
+    assert!(Location::try_from("12").is_err());
+}
+
+#[test]
+fn move_display_trait_works() {
+    let mv = Move {
+        from: Location::Column { i: 2 },
+        to: Location::Cell { i: 0 },
+    };
+
+    assert_eq!("3a", mv.to_string());
+
+    let mv = Move {
+        from: Location::Cell { i: 0 },
+        to: Location::Column { i: 7 },
+    };
+
+    assert_eq!("a8", mv.to_string());
+
+    let mv = Move {
+        from: Location::Column { i: 0 },
+        to: Location::Foundation,
+    };
+
+    assert_eq!("1h", mv.to_string());
+}
+
+#[test]
+fn move_tryfrom_ref_str_works() {
+    assert_eq!(
+        Ok(Move {
+            from: Location::Column { i: 2 },
+            to: Location::Cell { i: 0 },
+        }),
+        Move::try_from("3a")
+    );
+
+    assert_eq!(
+        Ok(Move {
+            from: Location::Cell { i: 3 },
+            to: Location::Foundation,
+        }),
+        Move::try_from("dh")
+    );
+}
+
+#[test]
+fn move_tryfrom_ref_str_with_bad_input_errors() {
+    assert!(Move::try_from("").is_err());
+    assert!(Move::try_from("3").is_err());
+    assert!(Move::try_from("3e").is_err());
+    assert!(Move::try_from("3a4").is_err());
+}
+
 #[test]
 fn apply_cell_cell_works() {
     let input = concat!(
@@ -84,10 +175,7 @@ fn apply_cell_cell_works() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Cell { i: 3 },
-        to: Location::Cell { i: 2 },
-    };
+    let mv = Move::try_from("dc").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -149,7 +237,7 @@ fn apply_cannot_move_from_nonexistent_cell() {
         to: Location::Cell { i: 2 },
     };
 
-    helper::assert_move_fails(input, mv);
+    helper::assert_move_fails(input, mv, MoveError::InvalidLocation);
 }
 
 #[test]
@@ -184,7 +272,7 @@ fn apply_cannot_move_to_nonexistent_cell() {
         to: Location::Cell { i: 4 },
     };
 
-    helper::assert_move_fails(input, mv);
+    helper::assert_move_fails(input, mv, MoveError::InvalidLocation);
 }
 
 #[test]
@@ -214,12 +302,9 @@ fn apply_cannot_move_to_full_cell() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Cell { i: 3 },
-        to: Location::Cell { i: 0 },
-    };
+    let mv = Move::try_from("da").unwrap();
 
-    helper::assert_move_fails(input, mv);
+    helper::assert_move_fails(input, mv, MoveError::DestinationOccupied);
 }
 
 #[test]
@@ -249,12 +334,9 @@ fn apply_cannot_move_from_empty_cell() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Cell { i: 1 },
-        to: Location::Cell { i: 2 },
-    };
+    let mv = Move::try_from("bc").unwrap();
 
-    helper::assert_move_fails(input, mv);
+    helper::assert_move_fails(input, mv, MoveError::SourceEmpty);
 }
 
 #[test]
@@ -284,10 +366,7 @@ fn apply_cell_foundation_ace_works() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Cell { i: 1 },
-        to: Location::Foundation,
-    };
+    let mv = Move::try_from("bh").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -344,10 +423,7 @@ fn apply_cell_foundation_two_works() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Cell { i: 2 },
-        to: Location::Foundation,
-    };
+    let mv = Move::try_from("ch").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -404,12 +480,9 @@ fn apply_cannot_move_from_foundation() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Foundation,
-        to: Location::Cell { i: 2 },
-    };
+    let mv = Move::try_from("hc").unwrap();
 
-    helper::assert_move_fails(input, mv);
+    helper::assert_move_fails(input, mv, MoveError::FromFoundation);
 }
 
 #[test]
@@ -439,12 +512,9 @@ fn apply_cell_to_foundation_without_correct_rank_fails() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Cell { i: 1 },
-        to: Location::Foundation,
-    };
+    let mv = Move::try_from("bh").unwrap();
 
-    helper::assert_move_fails(input, mv);
+    helper::assert_move_fails(input, mv, MoveError::RankMismatch);
 }
 
 #[test]
@@ -474,10 +544,7 @@ fn apply_column_cell_works() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 0 },
-        to: Location::Cell { i: 1 },
-    };
+    let mv = Move::try_from("1b").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -539,7 +606,7 @@ fn apply_cannot_move_from_nonexistent_column() {
         to: Location::Cell { i: 2 },
     };
 
-    helper::assert_move_fails(input, mv);
+    helper::assert_move_fails(input, mv, MoveError::InvalidLocation);
 }
 
 #[test]
@@ -574,7 +641,7 @@ fn apply_cannot_move_to_nonexistent_column() {
         to: Location::Column { i: 8 },
     };
 
-    helper::assert_move_fails(input, mv);
+    helper::assert_move_fails(input, mv, MoveError::InvalidLocation);
 }
 
 #[test]
@@ -604,12 +671,9 @@ fn apply_cannot_move_from_empty_column() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 5 },
-        to: Location::Cell { i: 2 },
-    };
+    let mv = Move::try_from("6c").unwrap();
 
-    helper::assert_move_fails(input, mv);
+    helper::assert_move_fails(input, mv, MoveError::SourceEmpty);
 }
 
 #[test]
@@ -639,10 +703,7 @@ fn apply_column_foundation_ace_works() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 0 },
-        to: Location::Foundation,
-    };
+    let mv = Move::try_from("1h").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -699,10 +760,7 @@ fn apply_column_foundation_two_works() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 6 },
-        to: Location::Foundation,
-    };
+    let mv = Move::try_from("7h").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -759,12 +817,9 @@ fn apply_column_to_foundation_without_correct_rank_fails() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 3 },
-        to: Location::Foundation,
-    };
+    let mv = Move::try_from("4h").unwrap();
 
-    helper::assert_move_fails(input, mv);
+    helper::assert_move_fails(input, mv, MoveError::RankMismatch);
 }
 
 #[test]
@@ -794,12 +849,9 @@ fn apply_column_to_same_column_fails() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 2 },
-        to: Location::Column { i: 2 },
-    };
+    let mv = Move::try_from("33").unwrap();
 
-    helper::assert_move_fails(input, mv);
+    helper::assert_move_fails(input, mv, MoveError::SameLocation);
 }
 
 #[test]
@@ -829,10 +881,7 @@ fn apply_cell_to_empty_column_works() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Cell { i: 0 },
-        to: Location::Column { i: 5 },
-    };
+    let mv = Move::try_from("a6").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -889,10 +938,7 @@ fn apply_cell_to_filled_matching_column_works() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Cell { i: 2 },
-        to: Location::Column { i: 1 },
-    };
+    let mv = Move::try_from("c2").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -949,12 +995,73 @@ fn apply_cell_to_filled_nonmatching_column_fails() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Cell { i: 0 },
-        to: Location::Column { i: 0 },
-    };
+    let mv = Move::try_from("a1").unwrap();
+
+    helper::assert_move_fails(input, mv, MoveError::RankMismatch);
+}
+
+#[test]
+fn apply_cell_to_filled_same_color_column_fails() {
+    let input = concat!(
+        "RustLibreCell                 #42 \n",
+        "\n",
+        " T♣  ..  ..  Q♥ || 2♣  ..  A♥  2♦ \n",
+        "--------------------------------- \n",
+        "  5♠  J♠  K♠  K♦  A♠      5♣  K♣  \n",
+        "  4♦  2♥  7♠  6♣  8♠      4♥  Q♦  \n",
+        "      J♦  Q♠  3♣  3♠          J♣  \n",
+        "      9♠  T♦  8♦  K♥              \n",
+        "      9♦  9♣  7♦  T♥              \n",
+        "      6♥  8♥  6♦  5♦              \n",
+        "      8♣  7♣  Q♣  4♠              \n",
+        "      7♥      J♥  3♦              \n",
+        "      6♠      T♠  2♠              \n",
+        "      5♥      9♥                  \n",
+        "      4♣                          \n",
+        "      3♥                          \n",
+        "                                  \n",
+        "                                  \n",
+        "                                  \n",
+        "                                  \n",
+        "                                  \n",
+        "                                  \n"
+    );
+
+    let mv = Move::try_from("a8").unwrap();
 
-    helper::assert_move_fails(input, mv);
+    helper::assert_move_fails(input, mv, MoveError::ColorMismatch);
+}
+
+#[test]
+fn apply_column_to_filled_same_color_column_fails() {
+    let input = concat!(
+        "RustLibreCell                 #42 \n",
+        "\n",
+        " T♣  ..  ..  Q♥ || 2♣  ..  A♥  2♦ \n",
+        "--------------------------------- \n",
+        "  5♠  J♠  K♠  K♦  A♠      5♣  K♣  \n",
+        "  4♦  2♥  7♠  6♣  8♠      4♥  Q♦  \n",
+        "      J♦  Q♠  3♣  3♠          J♣  \n",
+        "      9♠  T♦  8♦  K♥              \n",
+        "      9♦  9♣  7♦  T♥              \n",
+        "      6♥  8♥  6♦  5♦              \n",
+        "      8♣  7♣  Q♣  4♠              \n",
+        "      7♥      J♥  3♦              \n",
+        "      6♠      T♠  2♠              \n",
+        "      5♥      9♥                  \n",
+        "      4♣                          \n",
+        "      3♥                          \n",
+        "                                  \n",
+        "                                  \n",
+        "                                  \n",
+        "                                  \n",
+        "                                  \n",
+        "                                  \n"
+    );
+
+    let mv = Move::try_from("21").unwrap();
+
+    helper::assert_move_fails(input, mv, MoveError::ColorMismatch);
 }
 
 #[test]
@@ -984,10 +1091,7 @@ fn apply_column_one_card_to_column_empty_moves_one_card() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 6 },
-        to: Location::Column { i: 5 },
-    };
+    let mv = Move::try_from("76").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -1044,10 +1148,7 @@ fn apply_column_three_cards_to_column_empty_moves_one_card() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 7 },
-        to: Location::Column { i: 5 },
-    };
+    let mv = Move::try_from("86").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -1104,10 +1205,7 @@ fn apply_column_one_to_column_filled_matching_moves_one_card() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 5 },
-        to: Location::Column { i: 2 },
-    };
+    let mv = Move::try_from("63").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -1164,12 +1262,9 @@ fn apply_column_to_filled_nonmatching_column_fails() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 6 },
-        to: Location::Column { i: 2 },
-    };
+    let mv = Move::try_from("73").unwrap();
 
-    helper::assert_move_fails(input, mv);
+    helper::assert_move_fails(input, mv, MoveError::RankMismatch);
 }
 
 #[test]
@@ -1199,10 +1294,7 @@ fn apply_column_seven_to_column_empty_supermoves_two_cards() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 3 },
-        to: Location::Column { i: 5 },
-    };
+    let mv = Move::try_from("46").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -1259,10 +1351,7 @@ fn apply_column_four_to_column_filled_matching_supermoves_four_cards() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 2 },
-        to: Location::Column { i: 7 },
-    };
+    let mv = Move::try_from("38").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -1319,10 +1408,7 @@ fn apply_column_six_to_column_filled_matching_supermoves_six_cards() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 1 },
-        to: Location::Column { i: 3 },
-    };
+    let mv = Move::try_from("24").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -1379,12 +1465,9 @@ fn apply_column_six_to_column_filled_matching_fails_run_too_large() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 1 },
-        to: Location::Column { i: 3 },
-    };
+    let mv = Move::try_from("24").unwrap();
 
-    helper::assert_move_fails(input, mv);
+    helper::assert_move_fails(input, mv, MoveError::SupermoveTooLarge { max: 4 });
 }
 
 #[test]
@@ -1414,12 +1497,9 @@ fn apply_column_three_to_column_filled_nonmatching_fails() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 3 },
-        to: Location::Column { i: 2 },
-    };
+    let mv = Move::try_from("43").unwrap();
 
-    helper::assert_move_fails(input, mv);
+    helper::assert_move_fails(input, mv, MoveError::RankMismatch);
 }
 
 #[test]
@@ -1449,10 +1529,7 @@ fn apply_column_six_to_column_filled_matching_supermoves_six_cards_using_multipl
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 3 },
-        to: Location::Column { i: 1 },
-    };
+    let mv = Move::try_from("42").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -1509,10 +1586,7 @@ fn apply_column_five_to_column_filled_matching_supermoves_four_cards_to_match()
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 2 },
-        to: Location::Column { i: 7 },
-    };
+    let mv = Move::try_from("38").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -1569,10 +1643,7 @@ fn apply_column_three_to_column_empty_supermoves_three_cards_cells_only() {
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 4 },
-        to: Location::Column { i: 5 },
-    };
+    let mv = Move::try_from("56").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -1629,10 +1700,7 @@ fn apply_column_five_to_column_filled_matching_supermoves_four_cards_columns_onl
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 2 },
-        to: Location::Column { i: 7 },
-    };
+    let mv = Move::try_from("38").unwrap();
 
     let reference = concat!(
         "RustLibreCell                 #42 \n",
@@ -1690,10 +1758,7 @@ fn apply_column_six_to_column_filled_matching_supermoves_three_cards_involving_a
         "                                  \n"
     );
 
-    let mv = Move {
-        from: Location::Column { i: 6 },
-        to: Location::Column { i: 4 },
-    };
+    let mv = Move::try_from("75").unwrap();
 
     let reference = concat!(
         "RustLibreCell                #100 \n",
@@ -1724,6 +1789,135 @@ fn apply_column_six_to_column_filled_matching_supermoves_three_cards_involving_a
     helper::assert_move_succeeds(input, mv, reference);
 }
 
+#[test]
+fn legal_moves_lists_all_moves() {
+    let input = concat!(
+        "RustLibreCell                 #42 \n",
+        "\n",
+        " T♣  ..  ..  Q♥ || 2♣  ..  A♥  2♦ \n",
+        "--------------------------------- \n",
+        "  5♠  J♠  K♠  K♦  A♠      5♣  K♣  \n",
+        "  4♦  2♥  7♠  6♣  8♠      4♥  Q♦  \n",
+        "      J♦  Q♠  3♣  3♠          J♣  \n",
+        "      9♠  T♦  8♦  K♥              \n",
+        "      9♦  9♣  7♦  T♥              \n",
+        "      6♥  8♥  6♦  5♦              \n",
+        "      8♣  7♣  Q♣  4♠              \n",
+        "      7♥      J♥  3♦              \n",
+        "      6♠      T♠  2♠              \n",
+        "      5♥      9♥                  \n",
+        "      4♣                          \n",
+        "      3♥                          \n",
+        "                                  \n",
+    );
+
+    let game = Game::try_from(input).unwrap();
+
+    let actual: Vec<String> = legal_moves(&game)
+        .iter()
+        .map(|x| format!("{}x{}", x.mv, x.count))
+        .collect();
+
+    let expected = vec![
+        "abx1", "acx1", "a6x1", "dbx1", "dcx1", "d6x1", "1bx1", "1cx1", "16x1", "16x2", "2bx1",
+        "2cx1", "24x6", "26x1", "26x2", "26x3", "3bx1", "3cx1", "36x1", "36x2", "36x3", "38x4",
+        "4bx1", "4cx1", "46x1", "46x2", "46x3", "5bx1", "5cx1", "52x1", "56x1", "56x2", "56x3",
+        "7bx1", "7cx1", "76x1", "76x2", "8bx1", "8cx1", "86x1", "86x2", "86x3",
+    ];
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn legal_moves_include_foundation_moves() {
+    let input = concat!(
+        "RustLibreCell                 #42 \n",
+        "\n",
+        " ..  ..  ..  .. || Q♣  K♠  K♥  K♦ \n",
+        "--------------------------------- \n",
+        "  K♣                              \n",
+    );
+
+    let game = Game::try_from(input).unwrap();
+
+    let actual = legal_moves(&game);
+
+    assert!(actual.contains(&LegalMove {
+        mv: Move::try_from("1h").unwrap(),
+        count: 1,
+    }));
+}
+
+#[test]
+fn apply_legal_move_moves_given_number_of_cards() {
+    let input = concat!(
+        "RustLibreCell                 #42 \n",
+        "\n",
+        " T♣  ..  ..  Q♥ || 2♣  ..  A♥  2♦ \n",
+        "--------------------------------- \n",
+        "  5♠  J♠  K♠  K♦  A♠      5♣  K♣  \n",
+        "  4♦  2♥  7♠  6♣  8♠      4♥  Q♦  \n",
+        "      J♦  Q♠  3♣  3♠          J♣  \n",
+        "      9♠  T♦  8♦  K♥              \n",
+        "      9♦  9♣  7♦  T♥              \n",
+        "      6♥  8♥  6♦  5♦              \n",
+        "      8♣  7♣  Q♣  4♠              \n",
+        "      7♥      J♥  3♦              \n",
+        "      6♠      T♠  2♠              \n",
+        "      5♥      9♥                  \n",
+        "      4♣                          \n",
+        "      3♥                          \n",
+        "                                  \n",
+    );
+
+    let reference = concat!(
+        "RustLibreCell                 #42 \n",
+        "\n",
+        " T♣  ..  ..  Q♥ || 2♣  ..  A♥  2♦ \n",
+        "--------------------------------- \n",
+        "  5♠  J♠  K♠  K♦  A♠  5♥  5♣  K♣  \n",
+        "  4♦  2♥  7♠  6♣  8♠  4♣  4♥  Q♦  \n",
+        "      J♦  Q♠  3♣  3♠  3♥      J♣  \n",
+        "      9♠  T♦  8♦  K♥              \n",
+        "      9♦  9♣  7♦  T♥              \n",
+        "      6♥  8♥  6♦  5♦              \n",
+        "      8♣  7♣  Q♣  4♠              \n",
+        "      7♥      J♥  3♦              \n",
+        "      6♠      T♠  2♠              \n",
+        "              9♥                  \n",
+    );
+
+    let game = Game::try_from(input).unwrap();
+    let expected = Game::try_from(reference).unwrap();
+
+    let legal_move = LegalMove {
+        mv: Move::try_from("26").unwrap(),
+        count: 3,
+    };
+
+    assert_eq!(expected, apply_legal_move(&game, &legal_move).unwrap());
+
+    let illegal_move = LegalMove {
+        mv: Move::try_from("26").unwrap(),
+        count: 4,
+    };
+
+    assert_eq!(
+        Err(MoveError::SupermoveTooLarge { max: 3 }),
+        apply_legal_move(&game, &illegal_move)
+    );
+
+    let illegal_move = LegalMove {
+        mv: Move::try_from("1b").unwrap(),
+        count: 2,
+    };
+
+    assert_eq!(
+        Err(MoveError::SupermoveTooLarge { max: 1 }),
+        apply_legal_move(&game, &illegal_move)
+    );
+}
+
 #[test]
 fn ace_of_hearts_should_be_auto_moved_from_cell() {
     let input = concat!(
@@ -2350,3 +2544,77 @@ fn three_and_four_of_hearts_should_be_auto_moved() {
 
     helper::assert_automoves_succeed(input, count, reference);
 }
+
+#[test]
+fn move_error_display_explains_rejection() {
+    assert_eq!("Ranks do not match", MoveError::RankMismatch.to_string());
+    assert_eq!(
+        "Can move at most 1 card",
+        MoveError::SupermoveTooLarge { max: 1 }.to_string()
+    );
+    assert_eq!(
+        "Can move at most 13 cards",
+        MoveError::SupermoveTooLarge { max: 13 }.to_string()
+    );
+}
+
+#[test]
+fn make_move_performs_automoves() {
+    let input = concat!(
+        "RustLibreCell                #100 \n",
+        "\n",
+        " ..  ..  ..  .. || 2♣  ..  A♥  .. \n",
+        "--------------------------------- \n",
+        "  4♦  T♥  J♣  9♦      3♠  J♦  5♠  \n",
+        "  Q♠  K♠  8♥  K♥      6♦  2♠  3♦  \n",
+        "  4♠  8♣  3♥  6♥      5♦  6♠  2♦  \n",
+        "  4♥  5♣  9♣  4♣      Q♥  6♣  9♥  \n",
+        "  8♦  A♦  T♦  K♣      9♠  8♠  T♠  \n",
+        "  Q♣  2♥  3♣  J♥      Q♦  K♦      \n",
+        "  7♣  J♠  T♣  7♦                  \n",
+        "  7♠                              \n",
+        "  5♥                              \n",
+        "  A♠                              \n",
+        "  7♥                              \n"
+    );
+
+    let mv = Move {
+        from: Location::Column { i: 0 },
+        to: Location::Cell { i: 0 },
+    };
+
+    let reference = concat!(
+        "RustLibreCell                #100 \n",
+        "\n",
+        " 7♥  ..  ..  .. || 2♣  A♠  A♥  .. \n",
+        "--------------------------------- \n",
+        "  4♦  T♥  J♣  9♦      3♠  J♦  5♠  \n",
+        "  Q♠  K♠  8♥  K♥      6♦  2♠  3♦  \n",
+        "  4♠  8♣  3♥  6♥      5♦  6♠  2♦  \n",
+        "  4♥  5♣  9♣  4♣      Q♥  6♣  9♥  \n",
+        "  8♦  A♦  T♦  K♣      9♠  8♠  T♠  \n",
+        "  Q♣  2♥  3♣  J♥      Q♦  K♦      \n",
+        "  7♣  J♠  T♣  7♦                  \n",
+        "  7♠                              \n",
+        "  5♥                              \n"
+    );
+
+    let before = Game::try_from(input).unwrap();
+    let expected = Game::try_from(reference).unwrap();
+
+    let after = make_move(&before, mv).unwrap();
+
+    assert_eq!(expected, after);
+}
+
+#[test]
+fn make_move_with_illegal_move_errors() {
+    let game = Game::from_id(crate::game::GameId(1));
+
+    let mv = Move {
+        from: Location::Cell { i: 0 },
+        to: Location::Column { i: 0 },
+    };
+
+    assert_eq!(Err(MoveError::SourceEmpty), make_move(&game, mv));
+}
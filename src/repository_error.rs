@@ -0,0 +1,91 @@
+/*
+    RSLibreCell - a FreeCell implementation
+    Copyright (C) 2025 and later: tristhaus
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::{fmt, io::ErrorKind, path::PathBuf};
+
+/// Defines the reasons for reading or writing persisted data to fail.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RepositoryError {
+    /// The data directory of the platform cannot be determined.
+    NoDataDirectory,
+    /// The file cannot be read.
+    Read {
+        /// The path of the file.
+        path: PathBuf,
+        /// The kind of the underlying I/O error.
+        kind: ErrorKind,
+    },
+    /// The file or its directory cannot be written.
+    Write {
+        /// The path of the file or directory.
+        path: PathBuf,
+        /// The kind of the underlying I/O error.
+        kind: ErrorKind,
+    },
+    /// The file does not contain valid data.
+    Malformed {
+        /// The path of the file.
+        path: PathBuf,
+    },
+    /// The lock file is held by another instance, which hence is the only one to write.
+    Locked {
+        /// The path of the lock file.
+        path: PathBuf,
+    },
+    /// The file was written in a newer version of the format.
+    UnsupportedVersion {
+        /// The path of the file.
+        path: PathBuf,
+        /// The version of the format.
+        version: u16,
+    },
+}
+
+impl fmt::Display for RepositoryError {
+    /// Provides a short description of the problem, suitable for the user.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepositoryError::NoDataDirectory => write!(f, "Unable to find the data directory"),
+            RepositoryError::Read { path, kind } => {
+                write!(f, "Unable to read {}: {}", path.display(), kind)
+            }
+            RepositoryError::Write { path, kind } => {
+                write!(f, "Unable to write {}: {}", path.display(), kind)
+            }
+            RepositoryError::Malformed { path } => {
+                write!(f, "Malformed data in {}", path.display())
+            }
+            RepositoryError::Locked { path } => write!(
+                f,
+                "Another instance of RSLibreCell holds the lock {}",
+                path.display()
+            ),
+            RepositoryError::UnsupportedVersion { path, version } => write!(
+                f,
+                "Unsupported version {} of {}, written by a newer RSLibreCell",
+                version,
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RepositoryError {}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,65 @@
+/*
+    RSLibreCell - a FreeCell implementation
+    Copyright (C) 2025 and later: tristhaus
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use super::*;
+
+#[test]
+fn repository_error_display_trait_works() {
+    assert_eq!(
+        "Unable to find the data directory",
+        RepositoryError::NoDataDirectory.to_string()
+    );
+    assert_eq!(
+        "Unable to read journey.bin: permission denied",
+        RepositoryError::Read {
+            path: PathBuf::from("journey.bin"),
+            kind: ErrorKind::PermissionDenied,
+        }
+        .to_string()
+    );
+    assert_eq!(
+        "Unable to write game.json: entity not found",
+        RepositoryError::Write {
+            path: PathBuf::from("game.json"),
+            kind: ErrorKind::NotFound,
+        }
+        .to_string()
+    );
+    assert_eq!(
+        "Malformed data in key_config.json",
+        RepositoryError::Malformed {
+            path: PathBuf::from("key_config.json"),
+        }
+        .to_string()
+    );
+    assert_eq!(
+        "Another instance of RSLibreCell holds the lock journey.lock",
+        RepositoryError::Locked {
+            path: PathBuf::from("journey.lock"),
+        }
+        .to_string()
+    );
+    assert_eq!(
+        "Unsupported version 2 of journey.bin, written by a newer RSLibreCell",
+        RepositoryError::UnsupportedVersion {
+            path: PathBuf::from("journey.bin"),
+            version: 2,
+        }
+        .to_string()
+    );
+}
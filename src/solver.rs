@@ -0,0 +1,304 @@
+/*
+    RSLibreCell - a FreeCell implementation
+    Copyright (C) 2025 and later: tristhaus
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet},
+};
+
+use crate::{
+    card::Card,
+    game::{Game, GameId},
+    r#move::{make_move, Location, Move},
+};
+
+/// The default number of distinct positions the solver may examine.
+pub const DEFAULT_BUDGET: usize = 200_000;
+
+/// The outcome of an attempt to solve a game.
+#[derive(Clone, Debug)]
+pub enum Solution {
+    /// The game can be won by making the contained moves in order,
+    /// with automoves being performed after each move.
+    Solved(Vec<Move>),
+    /// The game cannot be won, all reachable positions have been examined.
+    Unsolvable,
+    /// No solution was found before the budget was used up.
+    BudgetExhausted,
+}
+
+/// Attempts to solve the given game, examining at most `budget` distinct positions.
+///
+/// The moves are the same the player can make, *i.e.* column-to-column moves
+/// always move as many cards as allowed, and all possible automoves
+/// are performed after each move, compare `GameHandler::make_move`.
+pub fn solve(game: &Game, budget: usize) -> Solution {
+    if game.is_won() {
+        return Solution::Solved(vec![]);
+    }
+
+    let mut seen: HashSet<Vec<u8>> = HashSet::new();
+    let mut nodes: Vec<Node> = vec![];
+    let mut frontier: BinaryHeap<(Reverse<u32>, Reverse<usize>)> = BinaryHeap::new();
+
+    seen.insert(detail::canonical_key(game));
+    nodes.push(Node {
+        parent: None,
+        mv: None,
+        packed: detail::pack(game),
+    });
+    frontier.push((Reverse(detail::score(game)), Reverse(0)));
+
+    while let Some((_, Reverse(index))) = frontier.pop() {
+        let game = detail::unpack(&game.id, &nodes[index].packed);
+
+        for (mv, next) in successors(&game) {
+            if next.is_won() {
+                let mut moves = vec![mv];
+                let mut current = index;
+                while let Some(parent) = nodes[current].parent {
+                    moves.push(nodes[current].mv.clone().unwrap());
+                    current = parent;
+                }
+                moves.reverse();
+                return Solution::Solved(moves);
+            }
+
+            if !seen.insert(detail::canonical_key(&next)) {
+                continue;
+            }
+
+            if seen.len() > budget {
+                return Solution::BudgetExhausted;
+            }
+
+            nodes.push(Node {
+                parent: Some(index),
+                mv: Some(mv),
+                packed: detail::pack(&next),
+            });
+            frontier.push((Reverse(detail::score(&next)), Reverse(nodes.len() - 1)));
+        }
+
+        // the packed state is no longer needed once expanded
+        nodes[index].packed = vec![];
+    }
+
+    Solution::Unsolvable
+}
+
+/// A position reached during the search.
+struct Node {
+    /// The index of the position this one was reached from.
+    parent: Option<usize>,
+    /// The move leading from the parent to this position.
+    mv: Option<Move>,
+    /// The position in packed form, compare `detail::pack`.
+    packed: Vec<u8>,
+}
+
+/// Provides all moves that can be made from the given position,
+/// together with the position after the move and any automoves.
+fn successors(game: &Game) -> Vec<(Move, Game)> {
+    let mut sources: Vec<Location> = vec![];
+    for (i, cell) in game.cells.iter().enumerate() {
+        if cell.is_some() {
+            sources.push(Location::Cell { i });
+        }
+    }
+    for (i, column) in game.columns.iter().enumerate() {
+        if !column.is_empty() {
+            sources.push(Location::Column { i });
+        }
+    }
+
+    // all empty cells and all empty columns are equivalent,
+    // hence only the first of each is considered
+    let mut targets: Vec<Location> = vec![Location::Foundation];
+    if let Some(i) = game.cells.iter().position(|x| x.is_none()) {
+        targets.push(Location::Cell { i });
+    }
+    let mut empty_column_seen = false;
+    for (i, column) in game.columns.iter().enumerate() {
+        if column.is_empty() {
+            if empty_column_seen {
+                continue;
+            }
+            empty_column_seen = true;
+        }
+        targets.push(Location::Column { i });
+    }
+
+    let mut result = vec![];
+
+    for from in &sources {
+        for to in &targets {
+            if let (Location::Cell { .. }, Location::Cell { .. }) = (from, to) {
+                continue;
+            }
+
+            let mv = Move {
+                from: from.clone(),
+                to: to.clone(),
+            };
+
+            if let Ok(next) = make_move(game, mv.clone()) {
+                result.push((mv, next));
+            }
+        }
+    }
+
+    result
+}
+
+mod detail {
+    use super::*;
+
+    /// Marks an empty foundation, an empty cell or the end of a column in packed form.
+    const SEPARATOR: u8 = 0xff;
+
+    /// Gets the ID of a card, compare `Card::from_id`.
+    fn card_id(card: &Card) -> u8 {
+        (card.rank as u8) * 4 + (card.suit as u8)
+    }
+
+    /// Packs a game into a compact form, keeping the order of cells and columns.
+    pub(super) fn pack(game: &Game) -> Vec<u8> {
+        let mut result = Vec::with_capacity(72);
+
+        for foundation in &game.foundations {
+            result.push(foundation.last().map_or(SEPARATOR, card_id));
+        }
+
+        for cell in &game.cells {
+            result.push(cell.as_ref().map_or(SEPARATOR, card_id));
+        }
+
+        for column in &game.columns {
+            result.extend(column.iter().map(card_id));
+            result.push(SEPARATOR);
+        }
+
+        result
+    }
+
+    /// Restores a game from its packed form, compare `pack`.
+    pub(super) fn unpack(id: &GameId, packed: &[u8]) -> Game {
+        let mut game = Game {
+            id: id.clone(),
+            cells: [None, None, None, None],
+            foundations: [vec![], vec![], vec![], vec![]],
+            columns: [
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+            ],
+        };
+
+        for (i, id) in packed[0..4].iter().enumerate() {
+            if *id != SEPARATOR {
+                for lower_id in (id % 4..=*id).step_by(4) {
+                    game.foundations[i].push(Card::from_id(lower_id));
+                }
+            }
+        }
+
+        for (i, id) in packed[4..8].iter().enumerate() {
+            if *id != SEPARATOR {
+                game.cells[i] = Some(Card::from_id(*id));
+            }
+        }
+
+        let mut column = 0;
+        for id in &packed[8..] {
+            if *id == SEPARATOR {
+                column += 1;
+            } else {
+                game.columns[column].push(Card::from_id(*id));
+            }
+        }
+
+        game
+    }
+
+    /// Creates a key identifying a position regardless of the order
+    /// of cells and columns, as these do not affect the outcome.
+    pub(super) fn canonical_key(game: &Game) -> Vec<u8> {
+        let mut result = Vec::with_capacity(72);
+
+        for foundation in &game.foundations {
+            result.push(foundation.len() as u8);
+        }
+
+        let mut cells: Vec<u8> = game
+            .cells
+            .iter()
+            .map(|x| x.as_ref().map_or(SEPARATOR, card_id))
+            .collect();
+        cells.sort_unstable();
+        result.extend(cells);
+
+        let mut columns: Vec<Vec<u8>> = game
+            .columns
+            .iter()
+            .map(|x| x.iter().map(card_id).collect())
+            .collect();
+        columns.sort_unstable();
+
+        for column in columns {
+            result.extend(column);
+            result.push(SEPARATOR);
+        }
+
+        result
+    }
+
+    /// Estimates the distance of the position from a won game, lower is better.
+    pub(super) fn score(game: &Game) -> u32 {
+        let on_foundations: usize = game.foundations.iter().map(|x| x.len()).sum();
+        let mut score = 3 * (52 - on_foundations as u32);
+
+        // free cells and empty columns allow for larger moves
+        score += game.cells.iter().filter(|x| x.is_some()).count() as u32;
+        score += 2 * game.columns.iter().filter(|x| !x.is_empty()).count() as u32;
+
+        // cards lying on top of a card of lower rank have to be moved
+        // before the lower card can reach its foundation
+        for column in &game.columns {
+            let mut lowest_rank = u8::MAX;
+            for card in column {
+                let rank = card.rank as u8;
+                if rank > lowest_rank {
+                    score += 1;
+                } else {
+                    lowest_rank = rank;
+                }
+            }
+        }
+
+        score
+    }
+}
+
+#[cfg(test)]
+mod test;
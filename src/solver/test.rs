@@ -0,0 +1,136 @@
+/*
+    RSLibreCell - a FreeCell implementation
+    Copyright (C) 2025 and later: tristhaus
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use super::*;
+
+mod helper {
+    use super::*;
+
+    pub fn assert_solution_wins(game: &Game, moves: Vec<Move>) {
+        let mut game = game.clone();
+
+        for mv in moves {
+            assert!(!game.is_won());
+
+            game = make_move(&game, mv).unwrap();
+        }
+
+        assert!(game.is_won());
+    }
+}
+
+#[test]
+fn solve_game_00001_works() {
+    let game = Game::from_id(GameId(1));
+
+    match solve(&game, DEFAULT_BUDGET) {
+        Solution::Solved(moves) => helper::assert_solution_wins(&game, moves),
+        _ => panic!("should have found a solution"),
+    }
+}
+
+#[test]
+fn solve_game_00617_works() {
+    let game = Game::from_id(GameId(617));
+
+    match solve(&game, DEFAULT_BUDGET) {
+        Solution::Solved(moves) => helper::assert_solution_wins(&game, moves),
+        _ => panic!("should have found a solution"),
+    }
+}
+
+#[test]
+fn solve_partially_played_game_works() {
+    let input = concat!(
+        "RustLibreCell                 #42 \n",
+        "\n",
+        " T♣  ..  ..  Q♥ || 2♣  ..  A♥  2♦ \n",
+        "--------------------------------- \n",
+        "  5♠  J♠  K♠  K♦  A♠      5♣  K♣  \n",
+        "  4♦  2♥  7♠  6♣  8♠      4♥  Q♦  \n",
+        "      J♦  Q♠  3♣  3♠          J♣  \n",
+        "      9♠  T♦  8♦  K♥              \n",
+        "      9♦  9♣  7♦  T♥              \n",
+        "      6♥  8♥  6♦  5♦              \n",
+        "      8♣  7♣  Q♣  4♠              \n",
+        "      7♥      J♥  3♦              \n",
+        "      6♠      T♠  2♠              \n",
+        "      5♥      9♥                  \n",
+        "      4♣                          \n",
+        "      3♥                          \n",
+        "                                  \n",
+        "                                  \n",
+        "                                  \n",
+        "                                  \n",
+        "                                  \n",
+        "                                  \n"
+    );
+
+    let game = Game::try_from(input).unwrap();
+
+    match solve(&game, DEFAULT_BUDGET) {
+        Solution::Solved(moves) => helper::assert_solution_wins(&game, moves),
+        _ => panic!("should have found a solution"),
+    }
+}
+
+#[test]
+fn solve_won_game_yields_no_moves() {
+    let input = concat!(
+        "RustLibreCell                 #42 \n",
+        "\n",
+        " ..  ..  ..  .. || K♣  K♠  K♥  K♦ \n",
+        "--------------------------------- \n",
+        "                                  \n",
+    );
+
+    let game = Game::try_from(input).unwrap();
+
+    match solve(&game, DEFAULT_BUDGET) {
+        Solution::Solved(moves) => assert!(moves.is_empty()),
+        _ => panic!("should have found a solution"),
+    }
+}
+
+#[test]
+fn solve_game_without_moves_is_unsolvable() {
+    let input = concat!(
+        "RustLibreCell                 #42 \n",
+        "\n",
+        " K♣  K♦  K♥  K♠ || ..  ..  ..  .. \n",
+        "--------------------------------- \n",
+        "  A♣  A♦  A♥  A♠  2♣  2♦  2♥  2♠  \n",
+        "  3♣  3♦  3♥  3♠  4♣  4♦  4♥  4♠  \n",
+        "  5♣  5♦  5♥  5♠  6♣  6♦  6♥  6♠  \n",
+        "  7♣  7♦  7♥  7♠  8♣  8♦  8♥  8♠  \n",
+        "  9♦  9♥  T♦  T♥  J♦  J♥  Q♦  Q♥  \n",
+        "  Q♣  Q♠  J♣  J♠  T♣  T♠  9♣  9♠  \n",
+        "                                  \n",
+    );
+
+    let game = Game::try_from(input).unwrap();
+
+    assert!(matches!(solve(&game, DEFAULT_BUDGET), Solution::Unsolvable));
+}
+
+#[test]
+fn solve_with_tiny_budget_is_exhausted() {
+    let game = Game::from_id(GameId(1));
+
+    assert!(matches!(solve(&game, 1), Solution::BudgetExhausted));
+}
@@ -0,0 +1,221 @@
+/*
+    RSLibreCell - a FreeCell implementation
+    Copyright (C) 2025 and later: tristhaus
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{game::GameId, repository_error::RepositoryError};
+use stats_repository::{GameRecord, PersistStats};
+
+/// The totals over all recorded games.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Summary {
+    /// The number of games played, *i.e.* won or abandoned after a move.
+    pub played: usize,
+    /// The number of games won.
+    pub won: usize,
+    /// The number of games won in a row, up to and including the most recent game.
+    pub current_streak: usize,
+    /// The largest number of games won in a row.
+    pub longest_streak: usize,
+}
+
+/// The game currently played, which is not yet recorded.
+#[derive(Debug)]
+struct CurrentGame {
+    id: GameId,
+    moves: usize,
+    reverts: usize,
+}
+
+/// Keeps track of the results of all games played.
+///
+/// A game is recorded when it is won, or as lost when another game is started
+/// after at least one move was made.
+#[derive(Debug)]
+pub struct StatsHandler<S>
+where
+    S: PersistStats,
+{
+    records: Vec<GameRecord>,
+    current: Option<CurrentGame>,
+    repository: S,
+    /// Indicates that the results must not be written, as they could not be read,
+    /// and writing would overwrite the user's history.
+    read_only: bool,
+    /// The last error of the repository not yet taken, if any.
+    error: Option<RepositoryError>,
+}
+
+impl<S> StatsHandler<S>
+where
+    S: PersistStats,
+{
+    /// Receives the notification that a game was started,
+    /// with the given number of moves already made, as for a resumed game.
+    ///
+    /// The `elapsed` time spent playing the previous game is recorded if it was abandoned.
+    pub fn receive_notification_game_started(
+        &mut self,
+        id: GameId,
+        moves: usize,
+        elapsed: Duration,
+    ) {
+        self.record_abandoned_game(elapsed);
+
+        self.current = Some(CurrentGame {
+            id,
+            moves,
+            reverts: 0,
+        });
+    }
+
+    /// Receives the notification that a move was made on the current game.
+    pub fn receive_notification_move(&mut self) {
+        if let Some(current) = self.current.as_mut() {
+            current.moves += 1;
+        }
+    }
+
+    /// Receives the notification that a move was reverted on the current game.
+    pub fn receive_notification_revert(&mut self) {
+        if let Some(current) = self.current.as_mut() {
+            current.reverts += 1;
+        }
+    }
+
+    /// Receives the notification that a game was won,
+    /// as indicated by its ID, after the `elapsed` time spent playing it.
+    pub fn receive_notification_game_won(&mut self, id: GameId, elapsed: Duration) {
+        if self.current.as_ref().is_some_and(|x| x.id == id) {
+            let current = self.current.take().unwrap();
+            self.record(current, true, elapsed);
+        }
+    }
+
+    /// Obtains the totals over all recorded games.
+    pub fn summary(&self) -> Summary {
+        let mut won = 0;
+        let mut streak = 0;
+        let mut longest_streak = 0;
+
+        for record in &self.records {
+            if record.won {
+                won += 1;
+                streak += 1;
+                longest_streak = longest_streak.max(streak);
+            } else {
+                streak = 0;
+            }
+        }
+
+        Summary {
+            played: self.records.len(),
+            won,
+            current_streak: streak,
+            longest_streak,
+        }
+    }
+
+    /// Obtains the recorded results of the game with the given ID, the most recent first.
+    pub fn history(&self, id: &GameId) -> Vec<GameRecord> {
+        self.records
+            .iter()
+            .rev()
+            .filter(|x| x.id == *id)
+            .cloned()
+            .collect()
+    }
+
+    /// Obtains the ID of the most recently recorded game, if any.
+    pub fn last_game_id(&self) -> Option<GameId> {
+        self.records.last().map(|x| x.id.clone())
+    }
+
+    /// Takes the last error of the repository, if any.
+    pub fn take_error(&mut self) -> Option<RepositoryError> {
+        self.error.take()
+    }
+}
+
+impl<S> StatsHandler<S>
+where
+    S: PersistStats,
+{
+    /// Constructs a new `StatsHandler` instance.
+    ///
+    /// If the results cannot be read, recording starts afresh,
+    /// but nothing is persisted in order to keep the data on the medium.
+    pub fn new(repository: S) -> StatsHandler<S> {
+        let (records, error) = match repository.read() {
+            Ok(records) => (records, None),
+            Err(error) => (vec![], Some(error)),
+        };
+
+        StatsHandler {
+            records,
+            current: None,
+            repository,
+            read_only: error.is_some(),
+            error,
+        }
+    }
+
+    /// Records the current game as lost after the `elapsed` time, if a move was made.
+    fn record_abandoned_game(&mut self, elapsed: Duration) {
+        if let Some(current) = self.current.take() {
+            if current.moves > 0 {
+                self.record(current, false, elapsed);
+            }
+        }
+    }
+
+    /// Records the result of the given game and persists all results.
+    ///
+    /// The `elapsed` time is the one shown on the board, which excludes pauses.
+    fn record(&mut self, game: CurrentGame, won: bool, elapsed: Duration) {
+        let now = SystemTime::now();
+
+        self.records.push(GameRecord {
+            id: game.id,
+            won,
+            moves: game.moves,
+            reverts: game.reverts,
+            seconds: elapsed.as_secs(),
+            ended: now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
+        });
+
+        self.persist();
+    }
+
+    /// Persists all results using the repository.
+    fn persist(&mut self) {
+        if self.read_only {
+            return;
+        }
+
+        if let Err(error) = self.repository.write(&self.records) {
+            self.error = Some(error);
+        }
+    }
+}
+
+/// Contains logic to persist the results of games.
+pub mod stats_repository;
+
+#[cfg(test)]
+mod test;
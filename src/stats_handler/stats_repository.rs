@@ -0,0 +1,242 @@
+/*
+    RSLibreCell - a FreeCell implementation
+    Copyright (C) 2025 and later: tristhaus
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use std::{
+    fs::File,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use mockall::automock;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game::GameId, journey_handler::journey_repository::DiskJourneyRepo,
+    repository_error::RepositoryError,
+};
+
+/// Defines the result of a single game.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameRecord {
+    /// The ID of the game.
+    pub id: GameId,
+    /// Indicates whether the game was won, as opposed to abandoned.
+    pub won: bool,
+    /// The number of moves made, including redone moves.
+    pub moves: usize,
+    /// The number of moves reverted.
+    pub reverts: usize,
+    /// The time played in seconds, excluding the time spent in modals.
+    pub seconds: u64,
+    /// The time the game ended, in seconds since the Unix epoch.
+    pub ended: u64,
+}
+
+#[automock]
+/// Defines the behavior to persist the results of games.
+pub trait PersistStats {
+    /// Reads all results from the underlying medium, the oldest first.
+    fn read(&self) -> Result<Vec<GameRecord>, RepositoryError>;
+
+    /// Writes all results to the underlying medium, replacing the previous ones.
+    fn write(&self, records: &[GameRecord]) -> Result<(), RepositoryError>;
+}
+
+#[derive(Debug)]
+/// Productive implementation.
+///
+/// Only the instance holding the lock of the journey writes the results,
+/// compare `DiskJourneyRepo`, such that instances do not overwrite each other's results.
+pub struct DiskStatsRepo {
+    /// The full path to the file containing the results, if any.
+    data_path: Option<PathBuf>,
+    /// Indicates that another instance holds the lock of the journey,
+    /// in which case writing is skipped.
+    locked: bool,
+}
+
+impl PersistStats for DiskStatsRepo {
+    fn read(&self) -> Result<Vec<GameRecord>, RepositoryError> {
+        // without a data directory, no results can have been saved
+        match &self.data_path {
+            Some(data_path) => DiskStatsRepo::read_records(data_path),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn write(&self, records: &[GameRecord]) -> Result<(), RepositoryError> {
+        let data_path = self
+            .data_path
+            .as_ref()
+            .ok_or(RepositoryError::NoDataDirectory)?;
+
+        if self.locked {
+            return Ok(());
+        }
+
+        DiskStatsRepo::write_records(data_path, records)
+    }
+}
+
+/// The on-disk form of the result of a game.
+#[derive(Serialize, Deserialize)]
+struct GameRecordData {
+    id: u64,
+    won: bool,
+    moves: usize,
+    reverts: usize,
+    seconds: u64,
+    ended: u64,
+}
+
+impl DiskStatsRepo {
+    /// Creates the repository for the results in the data directory,
+    /// which skips writing if another instance holds the lock of the journey.
+    pub fn new(locked: bool) -> DiskStatsRepo {
+        DiskStatsRepo::with_data_path(DiskStatsRepo::get_data_path(), locked)
+    }
+
+    /// Creates the repository for the results at the given path,
+    /// which skips writing if another instance holds the lock of the journey.
+    pub(crate) fn with_data_path(data_path: Option<PathBuf>, locked: bool) -> DiskStatsRepo {
+        DiskStatsRepo { data_path, locked }
+    }
+
+    /// Gets the relevant full path to the file containing the results,
+    /// which is placed next to the journey data, if there is a data directory.
+    fn get_data_path() -> Option<PathBuf> {
+        let mut data_path = dirs::data_dir()?;
+
+        data_path.push("rslibrecell");
+        data_path.push("stats.json");
+        Some(data_path)
+    }
+
+    /// Reads the results from the file at the path.
+    ///
+    /// If the file is missing or malformed, *e.g.* truncated by a crash,
+    /// the backup kept by `write_records` is used instead, if possible.
+    pub(crate) fn read_records(data_path: &Path) -> Result<Vec<GameRecord>, RepositoryError> {
+        let backup_path = DiskJourneyRepo::with_suffix(data_path, ".bak");
+
+        match DiskStatsRepo::read_records_file(data_path) {
+            Ok(Some(records)) => Ok(records),
+            Ok(None) => Ok(DiskStatsRepo::read_records_file(&backup_path)?.unwrap_or_default()),
+            Err(RepositoryError::Malformed { path }) => {
+                match DiskStatsRepo::read_records_file(&backup_path) {
+                    Ok(Some(records)) => Ok(records),
+                    _ => Err(RepositoryError::Malformed { path }),
+                }
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Writes the results to the file at the path, such that a crash
+    /// leaves either the previous or the new results in place.
+    ///
+    /// Valid previous results are kept as a backup next to the file.
+    pub(crate) fn write_records(
+        data_path: &Path,
+        records: &[GameRecord],
+    ) -> Result<(), RepositoryError> {
+        let mut data_dir = data_path.to_path_buf();
+        data_dir.pop();
+
+        if let Err(err) = std::fs::create_dir_all(&data_dir) {
+            return Err(RepositoryError::Write {
+                path: data_dir,
+                kind: err.kind(),
+            });
+        }
+
+        // a malformed file never replaces the backup
+        if let Ok(previous) = std::fs::read_to_string(data_path) {
+            if DiskStatsRepo::deserialize(&previous).is_some() {
+                let backup_path = DiskJourneyRepo::with_suffix(data_path, ".bak");
+                DiskJourneyRepo::write_atomically(&backup_path, previous.as_bytes())?;
+            }
+        }
+
+        let data = DiskStatsRepo::serialize(records);
+        DiskJourneyRepo::write_atomically(data_path, data.as_bytes())?;
+
+        // persisting the renames is best effort, not all platforms support it
+        if let Ok(dir) = File::open(&data_dir) {
+            let _ = dir.sync_all();
+        }
+
+        Ok(())
+    }
+
+    /// Reads the results from a single file, yielding `None` if it does not exist.
+    fn read_records_file(path: &Path) -> Result<Option<Vec<GameRecord>>, RepositoryError> {
+        match std::fs::read_to_string(path) {
+            Ok(data) => {
+                DiskStatsRepo::deserialize(&data)
+                    .map(Some)
+                    .ok_or(RepositoryError::Malformed {
+                        path: path.to_path_buf(),
+                    })
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(RepositoryError::Read {
+                path: path.to_path_buf(),
+                kind: err.kind(),
+            }),
+        }
+    }
+
+    /// Deserializes results from JSON, yielding `None` if the data is malformed.
+    pub(crate) fn deserialize(data: &str) -> Option<Vec<GameRecord>> {
+        let data: Vec<GameRecordData> = serde_json::from_str(data).ok()?;
+
+        Some(
+            data.into_iter()
+                .map(|x| GameRecord {
+                    id: GameId(x.id),
+                    won: x.won,
+                    moves: x.moves,
+                    reverts: x.reverts,
+                    seconds: x.seconds,
+                    ended: x.ended,
+                })
+                .collect(),
+        )
+    }
+
+    /// Serializes results into JSON.
+    pub(crate) fn serialize(records: &[GameRecord]) -> String {
+        let data: Vec<GameRecordData> = records
+            .iter()
+            .map(|x| GameRecordData {
+                id: x.id.0,
+                won: x.won,
+                moves: x.moves,
+                reverts: x.reverts,
+                seconds: x.seconds,
+                ended: x.ended,
+            })
+            .collect();
+
+        serde_json::to_string(&data).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,163 @@
+/*
+    RSLibreCell - a FreeCell implementation
+    Copyright (C) 2025 and later: tristhaus
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use super::*;
+
+const STATS_JSON: &str = concat!(
+    r#"[{"id":617,"won":true,"moves":87,"reverts":3,"seconds":252,"ended":1792324800},"#,
+    r#"{"id":8589934591,"won":false,"moves":1,"reverts":0,"seconds":5,"ended":1792325000}]"#
+);
+
+fn records() -> Vec<GameRecord> {
+    vec![
+        GameRecord {
+            id: GameId(617),
+            won: true,
+            moves: 87,
+            reverts: 3,
+            seconds: 252,
+            ended: 1_792_324_800,
+        },
+        GameRecord {
+            id: GameId(8_589_934_591),
+            won: false,
+            moves: 1,
+            reverts: 0,
+            seconds: 5,
+            ended: 1_792_325_000,
+        },
+    ]
+}
+
+#[test]
+fn serialize_records() {
+    assert_eq!(STATS_JSON, DiskStatsRepo::serialize(&records()));
+}
+
+#[test]
+fn deserialize_records() {
+    assert_eq!(Some(records()), DiskStatsRepo::deserialize(STATS_JSON));
+}
+
+#[test]
+fn serialize_without_records() {
+    assert_eq!("[]", DiskStatsRepo::serialize(&[]));
+    assert_eq!(Some(vec![]), DiskStatsRepo::deserialize("[]"));
+}
+
+#[test]
+fn deserialize_malformed_data_yields_none() {
+    assert!(DiskStatsRepo::deserialize("").is_none());
+    assert!(DiskStatsRepo::deserialize(r#"[{"id":617}]"#).is_none());
+    assert!(DiskStatsRepo::deserialize(r#"{"records":[]}"#).is_none());
+}
+
+/// Provides the path of the results file in a fresh temporary directory.
+fn data_path(name: &str) -> PathBuf {
+    let data_dir = std::env::temp_dir().join(format!("rslibrecell_stats_test_{}", name));
+    let _ = std::fs::remove_dir_all(&data_dir);
+    data_dir.join("stats.json")
+}
+
+fn clean_up(data_path: &Path) {
+    let mut data_dir = data_path.to_path_buf();
+    data_dir.pop();
+    std::fs::remove_dir_all(data_dir).unwrap();
+}
+
+#[test]
+fn read_records_without_files_yields_no_records() {
+    let data_path = data_path("without_files");
+
+    assert_eq!(Ok(vec![]), DiskStatsRepo::read_records(&data_path));
+}
+
+#[test]
+fn write_records_then_read_records_round_trips() {
+    let data_path = data_path("round_trip");
+
+    DiskStatsRepo::write_records(&data_path, &records()).unwrap();
+
+    assert_eq!(Ok(records()), DiskStatsRepo::read_records(&data_path));
+    assert!(!DiskJourneyRepo::with_suffix(&data_path, ".bak").exists());
+    assert!(!DiskJourneyRepo::with_suffix(&data_path, ".tmp").exists());
+
+    clean_up(&data_path);
+}
+
+#[test]
+fn read_records_with_truncated_file_falls_back_to_backup() {
+    let data_path = data_path("truncated");
+
+    DiskStatsRepo::write_records(&data_path, &records()[..1]).unwrap();
+    DiskStatsRepo::write_records(&data_path, &records()).unwrap();
+
+    let data = std::fs::read_to_string(&data_path).unwrap();
+    std::fs::write(&data_path, &data[..10]).unwrap();
+
+    assert_eq!(
+        Ok(records()[..1].to_vec()),
+        DiskStatsRepo::read_records(&data_path)
+    );
+
+    clean_up(&data_path);
+}
+
+#[test]
+fn read_records_with_malformed_file_and_no_backup_errors() {
+    let data_path = data_path("malformed");
+
+    DiskStatsRepo::write_records(&data_path, &records()).unwrap();
+    std::fs::write(&data_path, "[").unwrap();
+
+    assert_eq!(
+        Err(RepositoryError::Malformed {
+            path: data_path.clone()
+        }),
+        DiskStatsRepo::read_records(&data_path)
+    );
+
+    clean_up(&data_path);
+}
+
+#[test]
+fn locked_repository_reads_but_does_not_write() {
+    let data_path = data_path("locked");
+
+    let first = DiskStatsRepo::with_data_path(Some(data_path.clone()), false);
+    let second = DiskStatsRepo::with_data_path(Some(data_path.clone()), true);
+
+    first.write(&records()).unwrap();
+
+    assert_eq!(Ok(records()), second.read());
+    assert_eq!(Ok(()), second.write(&records()[..1]));
+    assert_eq!(Ok(records()), first.read());
+
+    clean_up(&data_path);
+}
+
+#[test]
+fn without_data_directory_reads_no_records_and_does_not_write() {
+    let repository = DiskStatsRepo::with_data_path(None, false);
+
+    assert_eq!(Ok(vec![]), repository.read());
+    assert_eq!(
+        Err(RepositoryError::NoDataDirectory),
+        repository.write(&records())
+    );
+}
@@ -0,0 +1,267 @@
+/*
+    RSLibreCell - a FreeCell implementation
+    Copyright (C) 2025 and later: tristhaus
+
+    This program is free software: you can redistribute it and/or modify
+    it under the terms of the GNU General Public License as published by
+    the Free Software Foundation, either version 3 of the License, or
+    (at your option) any later version.
+
+    This program is distributed in the hope that it will be useful,
+    but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with this program.  If not, see <https://www.gnu.org/licenses/>.
+*/
+
+use crate::stats_handler::stats_repository::MockPersistStats;
+
+use super::*;
+
+mod helper {
+    use super::*;
+
+    pub(super) fn record(id: u64, won: bool) -> GameRecord {
+        GameRecord {
+            id: GameId(id),
+            won,
+            moves: 10,
+            reverts: 1,
+            seconds: 60,
+            ended: 1_792_324_800,
+        }
+    }
+
+    pub(super) fn repository(records: Vec<GameRecord>) -> MockPersistStats {
+        let mut repository = MockPersistStats::new();
+        repository.expect_read().once().return_const(Ok(records));
+        repository
+    }
+}
+
+#[test]
+fn new_reads_from_repository() {
+    let mut repository = helper::repository(vec![helper::record(1, true)]);
+    repository.expect_write().never().return_const(Ok(()));
+
+    let instance = StatsHandler::new(repository);
+
+    assert_eq!(vec![helper::record(1, true)], instance.records);
+    assert_eq!(Some(GameId(1)), instance.last_game_id());
+}
+
+#[test]
+fn winning_a_game_records_moves_and_reverts() {
+    let mut repository = helper::repository(vec![]);
+    repository
+        .expect_write()
+        .once()
+        .withf(|records| {
+            records.len() == 1
+                && records[0].id == GameId(617)
+                && records[0].won
+                && records[0].moves == 3
+                && records[0].reverts == 1
+                && records[0].seconds == 42
+                && records[0].ended > 0
+        })
+        .return_const(Ok(()));
+
+    let mut instance = StatsHandler::new(repository);
+
+    instance.receive_notification_game_started(GameId(617), 0, Duration::ZERO);
+    instance.receive_notification_move();
+    instance.receive_notification_move();
+    instance.receive_notification_revert();
+    instance.receive_notification_move();
+    instance.receive_notification_game_won(GameId(617), Duration::from_millis(42_900));
+
+    assert_eq!(1, instance.history(&GameId(617)).len());
+    assert!(instance.current.is_none());
+}
+
+#[test]
+fn winning_an_unrelated_game_records_nothing() {
+    let mut repository = helper::repository(vec![]);
+    repository.expect_write().never().return_const(Ok(()));
+
+    let mut instance = StatsHandler::new(repository);
+
+    instance.receive_notification_game_won(GameId(617), Duration::ZERO);
+    instance.receive_notification_game_started(GameId(617), 0, Duration::ZERO);
+    instance.receive_notification_game_won(GameId(618), Duration::ZERO);
+
+    assert!(instance.records.is_empty());
+}
+
+#[test]
+fn starting_another_game_records_loss_after_move() {
+    let mut repository = helper::repository(vec![]);
+    repository
+        .expect_write()
+        .once()
+        .withf(|records| {
+            records.len() == 1
+                && records[0].id == GameId(617)
+                && !records[0].won
+                && records[0].seconds == 7
+        })
+        .return_const(Ok(()));
+
+    let mut instance = StatsHandler::new(repository);
+
+    instance.receive_notification_game_started(GameId(617), 0, Duration::ZERO);
+    instance.receive_notification_move();
+    instance.receive_notification_game_started(GameId(618), 0, Duration::from_secs(7));
+
+    assert_eq!(GameId(618), instance.current.as_ref().unwrap().id);
+}
+
+#[test]
+fn starting_another_game_without_move_records_nothing() {
+    let mut repository = helper::repository(vec![]);
+    repository.expect_write().never().return_const(Ok(()));
+
+    let mut instance = StatsHandler::new(repository);
+
+    instance.receive_notification_game_started(GameId(617), 0, Duration::ZERO);
+    instance.receive_notification_game_started(GameId(618), 0, Duration::ZERO);
+
+    assert!(instance.records.is_empty());
+}
+
+#[test]
+fn resumed_game_counts_moves_already_made() {
+    let mut repository = helper::repository(vec![]);
+    repository
+        .expect_write()
+        .once()
+        .withf(|records| records[0].moves == 6)
+        .return_const(Ok(()));
+
+    let mut instance = StatsHandler::new(repository);
+
+    instance.receive_notification_game_started(GameId(617), 5, Duration::ZERO);
+    instance.receive_notification_move();
+    instance.receive_notification_game_won(GameId(617), Duration::ZERO);
+}
+
+#[test]
+fn summary_counts_streaks() {
+    let mut repository = helper::repository(vec![
+        helper::record(1, true),
+        helper::record(2, true),
+        helper::record(3, true),
+        helper::record(4, false),
+        helper::record(5, true),
+        helper::record(6, true),
+    ]);
+    repository.expect_write().never().return_const(Ok(()));
+
+    let instance = StatsHandler::new(repository);
+
+    assert_eq!(
+        Summary {
+            played: 6,
+            won: 5,
+            current_streak: 2,
+            longest_streak: 3,
+        },
+        instance.summary()
+    );
+}
+
+#[test]
+fn summary_without_records_is_empty() {
+    let mut repository = helper::repository(vec![]);
+    repository.expect_write().never().return_const(Ok(()));
+
+    let instance = StatsHandler::new(repository);
+
+    assert_eq!(
+        Summary {
+            played: 0,
+            won: 0,
+            current_streak: 0,
+            longest_streak: 0,
+        },
+        instance.summary()
+    );
+}
+
+#[test]
+fn history_lists_deal_most_recent_first() {
+    let mut records = vec![
+        helper::record(617, false),
+        helper::record(1, true),
+        helper::record(617, true),
+    ];
+    records[0].moves = 1;
+    let mut repository = helper::repository(records);
+    repository.expect_write().never().return_const(Ok(()));
+
+    let instance = StatsHandler::new(repository);
+
+    let history = instance.history(&GameId(617));
+
+    assert_eq!(2, history.len());
+    assert!(history[0].won);
+    assert_eq!(1, history[1].moves);
+}
+
+#[test]
+fn new_with_failing_read_starts_afresh_without_writing() {
+    let mut repository = MockPersistStats::new();
+    repository
+        .expect_read()
+        .once()
+        .return_const(Err(RepositoryError::NoDataDirectory));
+    repository.expect_write().never().return_const(Ok(()));
+
+    let mut instance = StatsHandler::new(repository);
+
+    assert_eq!(
+        Some(RepositoryError::NoDataDirectory),
+        instance.take_error()
+    );
+    assert_eq!(None, instance.take_error());
+
+    instance.receive_notification_game_started(GameId(617), 0, Duration::ZERO);
+    instance.receive_notification_move();
+    instance.receive_notification_game_won(GameId(617), Duration::ZERO);
+
+    assert_eq!(1, instance.records.len());
+    assert_eq!(None, instance.take_error());
+}
+
+#[test]
+fn failing_write_keeps_error() {
+    let mut repository = helper::repository(vec![]);
+    repository
+        .expect_write()
+        .times(2)
+        .return_const(Err(RepositoryError::NoDataDirectory));
+
+    let mut instance = StatsHandler::new(repository);
+
+    instance.receive_notification_game_started(GameId(617), 0, Duration::ZERO);
+    instance.receive_notification_move();
+    instance.receive_notification_game_won(GameId(617), Duration::ZERO);
+
+    assert_eq!(
+        Some(RepositoryError::NoDataDirectory),
+        instance.take_error()
+    );
+
+    instance.receive_notification_game_started(GameId(618), 0, Duration::ZERO);
+    instance.receive_notification_move();
+    instance.receive_notification_game_won(GameId(618), Duration::ZERO);
+
+    assert_eq!(
+        Some(RepositoryError::NoDataDirectory),
+        instance.take_error()
+    );
+    assert_eq!(2, instance.records.len());
+}